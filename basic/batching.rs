@@ -0,0 +1,453 @@
+/*
+BATCHING - A Size-Bounded Iterator Adapter
+==========================================
+
+WHAT IS THIS?
+- while-let.rs fills a `DataBatch` until `is_full(30)` - but it only checks
+  AFTER adding, and the comment admits it can't "put the item back"
+  (it suggests `peekable()`), so batches overshoot the 30-byte limit
+- This file adds `batched_by(limit, weight_fn)` to every iterator:
+  it yields `Vec<T>` batches whose total weight never exceeds the limit,
+  unless one item is heavier than the limit on its own (it then travels alone)
+- Batches can also be closed by item count or by age, and nothing is pulled
+  from the source until the next batch is asked for
+
+REAL-WORLD ANALOGY:
+- Loading a lift with a 30 kg limit: you look at the next box BEFORE lifting it in;
+  if it doesn't fit, it waits for the next trip (that is the "put it back" slot)
+- A piano heavier than the limit gets a trip to itself, rather than never leaving
+- The lift also leaves when it holds 3 people, or when the first passenger has
+  been waiting too long
+
+FLUSH RULES (checked for every incoming item):
+┌─────────────────────────────┬─────────────────────────────────────────────┐
+│ Situation                   │ What happens                                │
+├─────────────────────────────┼─────────────────────────────────────────────┤
+│ item fits                   │ added; batch closes now if it hits a limit  │
+│ item would exceed weight    │ batch closes, item starts the next batch    │
+│ batch older than max_age    │ batch closes, item starts the next batch    │
+│ item alone exceeds weight   │ yielded as a batch of one                   │
+│ source runs out             │ the partial batch is yielded                │
+└─────────────────────────────┴─────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- An extension trait with a blanket impl: `impl<I: Iterator> Batching for I {}`
+- The adapter keeps a one-item `pending` slot - a hand-made `peekable()`
+- Age is measured with a Clock trait, so the demo can move time by hand
+- Age is only checked when an item arrives: a pull-based iterator cannot
+  interrupt a source that is blocked waiting for its next item
+*/
+
+use std::cell::Cell;
+use std::iter::FusedIterator;
+use std::rc::Rc;
+use std::time::Instant;
+
+// ============================================================================
+// CLOCKS
+// ============================================================================
+
+trait Clock {
+    fn now_ms(&self) -> u64;
+}
+
+struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    fn new() -> Self {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+}
+
+// Shared handle: the demo keeps one copy to advance time, the adapter holds another
+#[derive(Clone, Default)]
+struct ManualClock {
+    now: Rc<Cell<u64>>,
+}
+
+impl ManualClock {
+    fn advance(&self, ms: u64) {
+        self.now.set(self.now.get() + ms);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.now.get()
+    }
+}
+
+// ============================================================================
+// THE ADAPTER
+// ============================================================================
+
+struct Batched<I: Iterator, W, C> {
+    iter: I,
+    pending: Option<I::Item>, // Read from `iter` but didn't fit the previous batch
+    weight: W,
+    max_weight: usize,
+    max_items: usize,
+    max_age: Option<(u64, C)>,
+}
+
+// Plain function pointer, so batched(n) has a nameable return type
+type WeightFn<T> = fn(&T) -> usize;
+
+trait Batching: Iterator + Sized {
+    // Batches whose summed weight stays within `limit`
+    fn batched_by<W>(self, limit: usize, weight: W) -> Batched<Self, W, SystemClock>
+    where
+        W: FnMut(&Self::Item) -> usize,
+    {
+        Batched {
+            iter: self,
+            pending: None,
+            weight,
+            max_weight: limit,
+            max_items: usize::MAX,
+            max_age: None,
+        }
+    }
+
+    // Batches of `n` items (the last one may be shorter)
+    fn batched(self, n: usize) -> Batched<Self, WeightFn<Self::Item>, SystemClock> {
+        fn weightless<T>(_: &T) -> usize {
+            0
+        }
+        self.batched_by(usize::MAX, weightless as WeightFn<Self::Item>)
+            .max_items(n)
+    }
+}
+
+impl<I: Iterator> Batching for I {}
+
+impl<I: Iterator, W, C> Batched<I, W, C> {
+    fn max_items(mut self, n: usize) -> Self {
+        assert!(n > 0, "a batch must be allowed at least one item");
+        self.max_items = n;
+        self
+    }
+
+    // Close a batch once its first item is `ms` old, measured by `clock`
+    fn max_age<C2: Clock>(self, ms: u64, clock: C2) -> Batched<I, W, C2> {
+        Batched {
+            iter: self.iter,
+            pending: self.pending,
+            weight: self.weight,
+            max_weight: self.max_weight,
+            max_items: self.max_items,
+            max_age: Some((ms, clock)),
+        }
+    }
+}
+
+impl<I, W, C> Iterator for Batched<I, W, C>
+where
+    I: Iterator,
+    W: FnMut(&I::Item) -> usize,
+    C: Clock,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let mut batch = Vec::new();
+        let mut total = 0usize;
+        let mut started_at = 0;
+
+        while let Some(item) = self.pending.take().or_else(|| self.iter.next()) {
+            let weight = (self.weight)(&item);
+
+            if !batch.is_empty() {
+                let too_heavy = total.saturating_add(weight) > self.max_weight;
+                let too_old = self
+                    .max_age
+                    .as_ref()
+                    .is_some_and(|(ms, clock)| clock.now_ms() - started_at >= *ms);
+                if too_heavy || too_old {
+                    self.pending = Some(item); // "Put it back" for the next batch
+                    break;
+                }
+            } else if let Some((_, clock)) = &self.max_age {
+                started_at = clock.now_ms();
+            }
+
+            batch.push(item);
+            total = total.saturating_add(weight);
+            // Full (or a single oversized item): no need to look at the next one
+            if total >= self.max_weight || batch.len() >= self.max_items {
+                break;
+            }
+        }
+
+        if batch.is_empty() { None } else { Some(batch) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (low, high) = self.iter.size_hint();
+        // Every batch holds at least one item
+        let low = usize::from(low > 0 || pending > 0);
+        (low, high.and_then(|h| h.checked_add(pending)))
+    }
+}
+
+impl<I, W, C> FusedIterator for Batched<I, W, C>
+where
+    I: FusedIterator,
+    W: FnMut(&I::Item) -> usize,
+    C: Clock,
+{
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+// The loop from while-let.rs, kept only to show the overshoot
+fn old_data_batches(items: &[String], max_size: usize) -> Vec<Vec<String>> {
+    let mut batches = vec![Vec::new()];
+    let mut size = 0;
+    for item in items {
+        if size >= max_size {
+            batches.push(Vec::new());
+            size = 0;
+        }
+        size += item.len();
+        batches.last_mut().unwrap().push(item.clone());
+    }
+    batches
+}
+
+fn bytes(batch: &[String]) -> usize {
+    batch.iter().map(String::len).sum()
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn main() {
+    println!("📦 === BATCHING ===");
+
+    let data_items: Vec<String> = [
+        "item1",
+        "item2_longer",
+        "item3",
+        "item4_very_long_string",
+        "item5",
+        "item6_medium",
+        "item7",
+    ]
+    .map(String::from)
+    .to_vec();
+
+    // ========================================================================
+    // 1. THE while-let.rs BATCHES, WITHOUT OVERSHOOT
+    // ========================================================================
+    println!("\n📏 === 30-BYTE BATCHES ===");
+
+    println!("  old DataBatch loop:");
+    for batch in old_data_batches(&data_items, 30) {
+        println!("    {:>2} bytes {:?}", bytes(&batch), batch);
+    }
+    assert!(
+        old_data_batches(&data_items, 30)
+            .iter()
+            .any(|b| bytes(b) > 30)
+    );
+
+    println!("  batched_by(30, String::len):");
+    let batches: Vec<Vec<String>> = data_items
+        .iter()
+        .cloned()
+        .batched_by(30, String::len)
+        .collect();
+    for batch in &batches {
+        println!("    {:>2} bytes {:?}", bytes(batch), batch);
+    }
+    assert!(batches.iter().all(|b| bytes(b) <= 30));
+    assert_eq!(batches.concat(), data_items); // Nothing lost, nothing reordered
+    assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), [3, 2, 2]);
+
+    // ========================================================================
+    // 2. AN ITEM LARGER THAN THE LIMIT
+    // ========================================================================
+    println!("\n🐘 === OVERSIZED ITEMS TRAVEL ALONE ===");
+
+    let batches: Vec<Vec<String>> = data_items
+        .iter()
+        .cloned()
+        .batched_by(20, String::len)
+        .collect();
+    for batch in &batches {
+        let note = if bytes(batch) > 20 {
+            "  <- alone, over the limit"
+        } else {
+            ""
+        };
+        println!("    {:>2} bytes {:?}{}", bytes(batch), batch, note);
+    }
+    assert!(batches.iter().all(|b| bytes(b) <= 20 || b.len() == 1));
+    assert_eq!(batches[2], ["item4_very_long_string"]);
+
+    // ========================================================================
+    // 3. COUNT-BASED FLUSHING
+    // ========================================================================
+    println!("\n🔢 === BY COUNT ===");
+
+    let by_three: Vec<Vec<i32>> = (1..=8).batched(3).collect();
+    println!("  (1..=8).batched(3)           = {:?}", by_three);
+    assert_eq!(by_three, [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]);
+
+    // Both limits at once: whichever is hit first closes the batch
+    let both: Vec<Vec<String>> = data_items
+        .iter()
+        .cloned()
+        .batched_by(30, String::len)
+        .max_items(2)
+        .collect();
+    println!(
+        "  30 bytes and max 2 items     = {:?}",
+        both.iter().map(Vec::len).collect::<Vec<_>>()
+    );
+    assert!(both.iter().all(|b| b.len() <= 2 && bytes(b) <= 30));
+    assert_eq!(both.len(), 4);
+
+    // ========================================================================
+    // 4. TIME-BASED FLUSHING
+    // ========================================================================
+    println!("\n⏰ === BY AGE ===");
+
+    // Log lines trickle in; a batch is shipped once its oldest line is 100ms old
+    let clock = ManualClock::default();
+    let source_clock = clock.clone();
+    let arrivals = [
+        (0, "boot"),
+        (10, "listen"),
+        (20, "conn 1"),
+        (150, "conn 2"),
+        (10, "conn 3"),
+        (300, "idle"),
+    ];
+    let lines = arrivals.into_iter().map(move |(gap, line)| {
+        source_clock.advance(gap); // Time passes while we wait for the next line
+        line
+    });
+    let batches: Vec<Vec<&str>> = lines
+        .batched_by(1_000, |line| line.len())
+        .max_age(100, clock.clone())
+        .collect();
+    for batch in &batches {
+        println!("    {:?}", batch);
+    }
+    assert_eq!(
+        batches,
+        [
+            vec!["boot", "listen", "conn 1"],
+            vec!["conn 2", "conn 3"],
+            vec!["idle"]
+        ]
+    );
+
+    // The real clock works the same way
+    let quick: Vec<Vec<u32>> = (0..10)
+        .batched(4)
+        .max_age(60_000, SystemClock::new())
+        .collect();
+    assert_eq!(quick.len(), 3);
+
+    // ========================================================================
+    // 5. LAZINESS
+    // ========================================================================
+    println!("\n🦥 === LAZY, EVEN ON INFINITE SOURCES ===");
+
+    let pulled = Cell::new(0);
+    let mut batches = (1u64..)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .batched_by(10, |&n| n as usize);
+    let first = batches.next().unwrap();
+    println!(
+        "  first batch {:?} after pulling {} items",
+        first,
+        pulled.get()
+    );
+    assert_eq!(first, [1, 2, 3, 4]); // 1+2+3+4 = 10: full, so no look-ahead needed
+    assert_eq!(pulled.get(), 4);
+    let second = batches.next().unwrap();
+    println!(
+        "  second batch {:?} after pulling {} items (one held back)",
+        second,
+        pulled.get()
+    );
+    assert_eq!(second, [5]);
+    assert_eq!(pulled.get(), 6); // 6 didn't fit and waits in the pending slot
+    assert_eq!(batches.size_hint().0, 1);
+
+    let mut empty = std::iter::empty::<u8>().batched(3);
+    assert_eq!((empty.size_hint(), empty.next()), ((0, Some(0)), None));
+
+    // ========================================================================
+    // 6. RANDOMISED INVARIANTS
+    // ========================================================================
+    println!("\n🎲 === RANDOMISED CHECK ===");
+
+    let mut rng = XorShift(0x00ba_7c4e);
+    let mut total_batches = 0;
+    for _ in 0..2_000 {
+        let limit = rng.below(50) as usize;
+        let max_items = 1 + rng.below(6) as usize;
+        let len = rng.below(30) as usize;
+        let items: Vec<usize> = (0..len).map(|_| rng.below(40) as usize).collect();
+
+        let batches: Vec<Vec<usize>> = items
+            .iter()
+            .copied()
+            .batched_by(limit, |&w| w)
+            .max_items(max_items)
+            .collect();
+        total_batches += batches.len();
+
+        assert_eq!(batches.concat(), items);
+        for (i, batch) in batches.iter().enumerate() {
+            let sum: usize = batch.iter().sum();
+            assert!(!batch.is_empty() && batch.len() <= max_items);
+            assert!(sum <= limit || batch.len() == 1, "over the limit");
+            // Greedy: a batch only closes when the next item really doesn't fit
+            if let Some(next) = batches.get(i + 1) {
+                assert!(sum >= limit || batch.len() == max_items || sum + next[0] > limit);
+            }
+        }
+    }
+    println!(
+        "  2000 random inputs, {} batches: order kept, limits held, batches full",
+        total_batches
+    );
+
+    println!("\n📋 === BATCHING SUMMARY ===");
+    println!("✅ batched_by(limit, weight) never overshoots, except for one oversized item");
+    println!("✅ A pending slot puts back the item that didn't fit");
+    println!("✅ max_items and max_age close batches early; any limit can trigger");
+    println!("✅ Batches are produced lazily, one next() at a time");
+    println!("✅ A blanket impl adds the adapter to every iterator");
+}
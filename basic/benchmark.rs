@@ -0,0 +1,570 @@
+/*
+MICRO-BENCHMARKS - Measuring Instead of Guessing
+================================================
+
+WHAT IS THIS?
+- iterator.rs and ranges.rs time ONE run of each version with Instant::now()
+  and print "Both should be equally fast"
+- One run is mostly noise: CPU frequency, caches, other processes, and the
+  optimizer (which may delete work whose result is never used)
+- This file runs warm-up iterations, then many measured samples, and reports
+  statistics, comparisons with a confidence estimate, and saved baselines
+
+REAL-WORLD ANALOGY:
+- Timing one lap of a runner tells you little; timing 50 laps after a warm-up
+  tells you their typical pace, how consistent they are, and which laps were odd
+
+HOW A BENCHMARK RUNS:
+1. Warm-up: call the function until `warm_up` time has passed (fills caches,
+   lets the CPU clock up) and estimate how long one call takes
+2. Pick a batch size so one sample takes at least `min_sample_time`
+   (the timer is too coarse to time a single 10ns call)
+3. Take `samples` samples; each records the average time per call in its batch
+4. Summarize: mean, median, std dev, and outliers (Tukey's 1.5 * IQR fences)
+
+STATISTICS:
+┌──────────────────┬──────────────────────────────────────────────────────────────┐
+│     Output       │                        Meaning                               │
+├──────────────────┼──────────────────────────────────────────────────────────────┤
+│ median           │ Typical time per call - robust against outliers              │
+│ std dev          │ How much samples vary                                        │
+│ outliers         │ Samples outside the IQR fences (mild: 1.5x, severe: 3x)      │
+│ confidence       │ Share of 1 000 bootstrap resamples in which A's median beat  │
+│                  │ B's (ties count half)                                        │
+│ regression       │ Slower than the saved baseline by more than the tolerance,   │
+│                  │ with at least 95% bootstrap confidence                       │
+└──────────────────┴──────────────────────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- std::hint::black_box hides values from the optimizer so the work can't be deleted
+- Compare medians, not single runs; report how sure you are, not just who "won"
+- Run with `rustc -O` for realistic numbers - debug builds measure the debug code
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// CONFIGURATION AND MEASUREMENT
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct BenchConfig {
+    warm_up: Duration,
+    samples: usize,
+    min_sample_time: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warm_up: Duration::from_millis(50),
+            samples: 30,
+            min_sample_time: Duration::from_millis(2),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConfigError {
+    NoSamples,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NoSamples => write!(f, "samples must be at least 1"),
+        }
+    }
+}
+
+impl BenchConfig {
+    // Checked before any timing starts, so summarize() always gets samples
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.samples == 0 {
+            return Err(ConfigError::NoSamples);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Measurement {
+    name: String,
+    iterations_per_sample: u64,
+    samples_ns: Vec<f64>, // Average nanoseconds per call, one entry per sample
+}
+
+// Run `f` under the configuration and record per-call timings
+fn bench<R>(
+    name: &str,
+    config: &BenchConfig,
+    mut f: impl FnMut() -> R,
+) -> Result<Measurement, ConfigError> {
+    config.validate()?;
+
+    // 1. Warm-up, counting calls so we know roughly how long one takes
+    let start = Instant::now();
+    let mut warm_calls: u64 = 0;
+    while start.elapsed() < config.warm_up || warm_calls == 0 {
+        black_box(f());
+        warm_calls += 1;
+    }
+    let per_call = start.elapsed().as_nanos() as f64 / warm_calls as f64;
+
+    // 2. Batch size: enough calls that each sample is comfortably above timer resolution
+    let iterations =
+        ((config.min_sample_time.as_nanos() as f64 / per_call.max(1.0)).ceil() as u64).max(1);
+
+    // 3. Measured samples
+    let samples_ns = (0..config.samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(f());
+            }
+            start.elapsed().as_nanos() as f64 / iterations as f64
+        })
+        .collect();
+
+    Ok(Measurement {
+        name: name.to_string(),
+        iterations_per_sample: iterations,
+        samples_ns,
+    })
+}
+
+// ============================================================================
+// STATISTICS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Summary {
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    min: f64,
+    max: f64,
+    mild_outliers: usize,
+    severe_outliers: usize,
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+// Panics on an empty slice: bench() and load_baseline() never produce one
+fn summarize(samples: &[f64]) -> Summary {
+    assert!(!samples.is_empty(), "no samples to summarize");
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let m = mean(&sorted);
+    // Sample standard deviation (n - 1): we estimate the spread of all possible runs
+    let std_dev = if sorted.len() > 1 {
+        (sorted.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (sorted.len() - 1) as f64).sqrt()
+    } else {
+        0.0
+    };
+
+    let (q1, q3) = (quantile(&sorted, 0.25), quantile(&sorted, 0.75));
+    let iqr = q3 - q1;
+    let outside = |k: f64| {
+        sorted
+            .iter()
+            .filter(|&&x| x < q1 - k * iqr || x > q3 + k * iqr)
+            .count()
+    };
+    let severe = outside(3.0);
+
+    Summary {
+        mean: m,
+        median: quantile(&sorted, 0.5),
+        std_dev,
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        mild_outliers: outside(1.5) - severe,
+        severe_outliers: severe,
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2} s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2} ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+fn report(m: &Measurement) {
+    let s = summarize(&m.samples_ns);
+    println!(
+        "  {:<22} median {:>10}  mean {:>10}  ± {:>9}  [{} .. {}]  ({} samples x {} iters, outliers: {} mild, {} severe)",
+        m.name,
+        format_ns(s.median),
+        format_ns(s.mean),
+        format_ns(s.std_dev),
+        format_ns(s.min),
+        format_ns(s.max),
+        m.samples_ns.len(),
+        m.iterations_per_sample,
+        s.mild_outliers,
+        s.severe_outliers
+    );
+}
+
+// ============================================================================
+// COMPARISON (BOOTSTRAP)
+// ============================================================================
+
+// Deterministic PRNG so comparisons are repeatable for the same samples
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_index(&mut self, len: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % len as u64) as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Comparison {
+    ratio: f64,               // median(a) / median(b); < 1 means a is faster
+    confidence_a_faster: f64, // 0.0 ..= 1.0
+}
+
+// Resample both sample sets with replacement many times and count how often
+// A's median comes out below B's (a tie counts as half a win).
+// Near 1.0 or 0.0 = a real difference; near 0.5 = noise.
+fn compare(a: &[f64], b: &[f64]) -> Comparison {
+    const RESAMPLES: usize = 1_000;
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let resample_median = |values: &[f64], rng: &mut XorShift| {
+        let mut resampled: Vec<f64> = (0..values.len())
+            .map(|_| values[rng.next_index(values.len())])
+            .collect();
+        resampled.sort_by(|x, y| x.total_cmp(y));
+        quantile(&resampled, 0.5)
+    };
+
+    let mut a_wins = 0.0;
+    for _ in 0..RESAMPLES {
+        let (median_a, median_b) = (resample_median(a, &mut rng), resample_median(b, &mut rng));
+        if median_a < median_b {
+            a_wins += 1.0;
+        } else if median_a == median_b {
+            a_wins += 0.5;
+        }
+    }
+
+    Comparison {
+        ratio: summarize(a).median / summarize(b).median,
+        confidence_a_faster: a_wins / RESAMPLES as f64,
+    }
+}
+
+fn describe(a: &Measurement, b: &Measurement) -> String {
+    let c = compare(&a.samples_ns, &b.samples_ns);
+    let (faster, slower, ratio, confidence) = if c.ratio <= 1.0 {
+        (&a.name, &b.name, 1.0 / c.ratio, c.confidence_a_faster)
+    } else {
+        (&b.name, &a.name, c.ratio, 1.0 - c.confidence_a_faster)
+    };
+
+    if confidence < 0.95 {
+        format!(
+            "{} vs {}: no significant difference ({:.2}x, {:.0}% confidence)",
+            a.name,
+            b.name,
+            ratio,
+            confidence * 100.0
+        )
+    } else {
+        format!(
+            "{} is {:.2}x faster than {} ({:.1}% confidence)",
+            faster,
+            ratio,
+            slower,
+            confidence * 100.0
+        )
+    }
+}
+
+// ============================================================================
+// BASELINES ON DISK
+// ============================================================================
+
+// One line per benchmark: name<TAB>iterations<TAB>sample,sample,...
+// Keeping the raw samples lets a later run bootstrap against them.
+// Names containing a tab or line break would split the line, so they're refused.
+fn save_baseline(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+    let mut out = String::new();
+    for m in measurements {
+        if m.name.contains(['\t', '\n', '\r']) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("benchmark name {:?} contains a tab or line break", m.name),
+            ));
+        }
+        let samples: Vec<String> = m.samples_ns.iter().map(|s| format!("{:.3}", s)).collect();
+        out.push_str(&format!(
+            "{}\t{}\t{}\n",
+            m.name,
+            m.iterations_per_sample,
+            samples.join(",")
+        ));
+    }
+    fs::write(path, out)
+}
+
+fn load_baseline(path: &Path) -> io::Result<BTreeMap<String, Measurement>> {
+    let text = fs::read_to_string(path)?;
+    let invalid = |line: usize| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("baseline line {} is malformed", line),
+        )
+    };
+
+    let mut baseline = BTreeMap::new();
+    for (index, line) in text.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() != 3 {
+            return Err(invalid(index + 1));
+        }
+        let iterations = parts[1].parse().map_err(|_| invalid(index + 1))?;
+        let samples_ns = parts[2]
+            .split(',')
+            .map(|s| s.parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid(index + 1))?;
+        if samples_ns.is_empty() {
+            return Err(invalid(index + 1));
+        }
+        baseline.insert(
+            parts[0].to_string(),
+            Measurement {
+                name: parts[0].to_string(),
+                iterations_per_sample: iterations,
+                samples_ns,
+            },
+        );
+    }
+    Ok(baseline)
+}
+
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    New,
+    Unchanged,
+    Improved(f64),  // Ratio current / baseline
+    Regressed(f64), // Ratio current / baseline
+}
+
+// Only call it a change when it's both big enough and statistically convincing
+fn check_against(baseline: Option<&Measurement>, current: &Measurement, tolerance: f64) -> Verdict {
+    let Some(old) = baseline else {
+        return Verdict::New;
+    };
+    let c = compare(&current.samples_ns, &old.samples_ns);
+    if c.ratio > 1.0 + tolerance && c.confidence_a_faster <= 0.05 {
+        Verdict::Regressed(c.ratio)
+    } else if c.ratio < 1.0 - tolerance && c.confidence_a_faster >= 0.95 {
+        Verdict::Improved(c.ratio)
+    } else {
+        Verdict::Unchanged
+    }
+}
+
+// ============================================================================
+// THE CODE UNDER TEST (from iterator.rs and ranges.rs)
+// ============================================================================
+
+fn sum_even_squares_loop(data: &[i64]) -> i64 {
+    let mut sum = 0;
+    for &num in data {
+        if num % 2 == 0 {
+            sum += num * num;
+        }
+    }
+    sum
+}
+
+fn sum_even_squares_iter(data: &[i64]) -> i64 {
+    data.iter().filter(|&&x| x % 2 == 0).map(|x| x * x).sum()
+}
+
+fn sum_range(n: i64) -> i64 {
+    (1..=n).sum()
+}
+
+fn sum_collected_vec(n: i64) -> i64 {
+    let v: Vec<i64> = (1..=n).collect();
+    v.iter().sum()
+}
+
+fn main() {
+    println!("⏱️  === STATISTICAL MICRO-BENCHMARKS ===");
+
+    // ========================================================================
+    // 1. STATISTICS ON KNOWN DATA
+    // ========================================================================
+    println!("\n📊 === STATISTICS ON KNOWN DATA ===");
+
+    let known = [10.0, 11.0, 10.5, 10.2, 10.8, 10.1, 10.4, 45.0];
+    let s = summarize(&known);
+    println!("  {:?}", known);
+    println!("  {:?}", s);
+    assert_eq!(s.median, 10.45);
+    assert_eq!(s.severe_outliers, 1); // The 45.0
+    assert_eq!(s.mild_outliers, 0);
+    assert_eq!(format_ns(1_500.0), "1.50 µs");
+
+    // Bootstrap sanity checks: clear difference vs pure noise
+    let fast: Vec<f64> = (0..30).map(|i| 100.0 + (i % 5) as f64).collect();
+    let slow: Vec<f64> = (0..30).map(|i| 150.0 + (i % 5) as f64).collect();
+    assert!(compare(&fast, &slow).confidence_a_faster > 0.99);
+    assert!(compare(&slow, &fast).confidence_a_faster < 0.01);
+    let same = compare(&fast, &fast);
+    assert!((0.3..0.7).contains(&same.confidence_a_faster));
+    println!(
+        "  Bootstrap: fast vs slow {:.2}, fast vs itself {:.2}",
+        compare(&fast, &slow).confidence_a_faster,
+        same.confidence_a_faster
+    );
+
+    // ========================================================================
+    // 2. FOR LOOP vs ITERATOR (iterator.rs)
+    // ========================================================================
+    println!("\n🔁 === FOR LOOP vs ITERATOR ===");
+
+    let config = BenchConfig::default();
+    let data: Vec<i64> = (1..=1_000).collect();
+    assert_eq!(sum_even_squares_loop(&data), sum_even_squares_iter(&data));
+
+    // black_box(&data) stops the compiler from computing the answer at compile time
+    let loop_m = bench("for loop", &config, || {
+        sum_even_squares_loop(black_box(&data))
+    })
+    .unwrap();
+    let iter_m = bench("iterator", &config, || {
+        sum_even_squares_iter(black_box(&data))
+    })
+    .unwrap();
+    report(&loop_m);
+    report(&iter_m);
+    println!("  {}", describe(&loop_m, &iter_m));
+
+    // A config with no samples is refused before anything is timed
+    let empty = BenchConfig {
+        samples: 0,
+        ..config
+    };
+    let error = bench("nothing", &empty, || 0).unwrap_err();
+    println!("  Rejected config: {}", error);
+    assert_eq!(error, ConfigError::NoSamples);
+
+    // ========================================================================
+    // 3. RANGE vs COLLECTED VEC (ranges.rs)
+    // ========================================================================
+    println!("\n📏 === RANGE vs COLLECTED VEC ===");
+
+    let range_m = bench("range sum", &config, || sum_range(black_box(100_000))).unwrap();
+    let vec_m = bench("collect then sum", &config, || {
+        sum_collected_vec(black_box(100_000))
+    })
+    .unwrap();
+    report(&range_m);
+    report(&vec_m);
+    // Allocating and filling 800 KB should lose to not allocating at all - but on
+    // a busy machine anything can happen, so this is reported, not asserted
+    println!("  {}", describe(&range_m, &vec_m));
+
+    // ========================================================================
+    // 4. BASELINES AND REGRESSION DETECTION
+    // ========================================================================
+    println!("\n💾 === BASELINES AND REGRESSIONS ===");
+
+    let path = std::env::temp_dir().join("benchmark_baseline.tsv");
+    save_baseline(&path, &[range_m.clone(), vec_m.clone()]).unwrap();
+    let baseline = load_baseline(&path).unwrap();
+    println!("  Saved {} baselines to {}", baseline.len(), path.display());
+    assert_eq!(baseline["range sum"].samples_ns.len(), config.samples);
+
+    // Simulate the next run: same code, plus a deliberately slowed-down version
+    let rerun = bench("range sum", &config, || sum_range(black_box(100_000))).unwrap();
+    let slowed = bench("collect then sum", &config, || {
+        sum_collected_vec(black_box(100_000)) + sum_collected_vec(black_box(100_000))
+    })
+    .unwrap();
+    let brand_new = bench("brand new", &config, || sum_range(black_box(10))).unwrap();
+
+    for m in [&rerun, &slowed, &brand_new] {
+        let verdict = check_against(baseline.get(&m.name), m, 0.10);
+        let icon = match verdict {
+            Verdict::Regressed(_) => "🔴",
+            Verdict::Improved(_) => "🟢",
+            Verdict::Unchanged | Verdict::New => "⚪",
+        };
+        println!("  {} {:<18} {:?}", icon, m.name, verdict);
+    }
+    assert_eq!(check_against(None, &brand_new, 0.10), Verdict::New);
+
+    // The verdicts above depend on the machine; synthetic samples pin the logic down
+    let synthetic = |name: &str, base: f64| Measurement {
+        name: name.to_string(),
+        iterations_per_sample: 1,
+        samples_ns: (0..30).map(|i| base + (i % 5) as f64).collect(),
+    };
+    let old = synthetic("job", 100.0);
+    assert!(matches!(
+        check_against(Some(&old), &synthetic("job", 150.0), 0.10),
+        Verdict::Regressed(_)
+    ));
+    assert!(matches!(
+        check_against(Some(&old), &synthetic("job", 50.0), 0.10),
+        Verdict::Improved(_)
+    ));
+    assert_eq!(
+        check_against(Some(&old), &synthetic("job", 103.0), 0.10),
+        Verdict::Unchanged // Convincing, but within the 10% tolerance
+    );
+
+    // A tab in a name would break the file format
+    let error = save_baseline(&path, &[synthetic("bad\tname", 1.0)]).unwrap_err();
+    println!("  Rejected: {}", error);
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+    // A corrupted baseline file is an error, not a panic
+    fs::write(&path, "range sum\tnot-a-number\t1,2,3\n").unwrap();
+    println!(
+        "  Corrupted baseline: {}",
+        load_baseline(&path).unwrap_err()
+    );
+    fs::remove_file(&path).ok();
+
+    println!("\n📋 === BENCHMARK SUMMARY ===");
+    println!("✅ Warm up first, then time batches of calls, many times");
+    println!("✅ black_box keeps the optimizer from deleting the work");
+    println!("✅ Report median, spread and outliers - not one number");
+    println!("✅ Bootstrap resampling says how sure a comparison is");
+    println!("✅ Baselines on disk turn benchmarks into regression checks");
+}
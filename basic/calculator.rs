@@ -0,0 +1,928 @@
+/*
+CALCULATOR - Lexer, Precedence Climbing Parser and Evaluator
+============================================================
+
+WHAT IS THIS?
+- while-let.rs builds a `TokenStream` for "5 + 3 * 2" by hand and only prints
+  the tokens - it never produces 11
+- This file goes all the way: source text -> tokens (with spans) -> syntax tree
+  -> value, with parentheses, unary minus, floats and variables
+- Every error remembers WHERE it happened, so it can draw a caret under the culprit
+
+REAL-WORLD ANALOGY:
+- Reading a sentence: first you split it into words (lexer), then you work out
+  which words belong together (parser), then you work out what it means (evaluator)
+- "5 + 3 * 2" is like "tea with milk and sugar": grammar, not word order,
+  decides what groups with what
+
+PRECEDENCE (higher binds tighter):
+┌──────┬───────────┬───────────────┬─────────────────────────────────┐
+│ Prec │ Operators │ Associativity │ Example                         │
+├──────┼───────────┼───────────────┼─────────────────────────────────┤
+│ 1    │ + -       │ left          │ 8 - 3 - 2   = (8 - 3) - 2 = 3   │
+│ 2    │ * / %     │ left          │ 5 + 3 * 2   = 5 + (3 * 2) = 11  │
+│ 3    │ unary -   │ prefix        │ -2 * 3      = (-2) * 3    = -6  │
+│ 4    │ ^         │ right         │ 2 ^ 3 ^ 2   = 2 ^ (3 ^ 2) = 512 │
+└──────┴───────────┴───────────────┴─────────────────────────────────┘
+  (-2 ^ 2 is -(2 ^ 2) = -4, as in mathematics)
+
+KEY CONCEPTS:
+- A Span is a byte range into the source; tokens, AST nodes and errors all carry one
+- Precedence climbing: parse_expr(min_prec) keeps absorbing operators that bind
+  at least as tightly as min_prec; right associativity just reuses the same prec
+- Integers use checked arithmetic so overflow is an error, not a wrap-around;
+  mixing in a float switches to f64, where an infinite result is an overflow
+- Nesting and tree height are capped at MAX_DEPTH, so hostile input is an
+  error instead of a stack overflow
+
+USAGE:
+    ./run_basic.sh calculator.rs                      # Demo
+    rustc calculator.rs && ./calculator "2 ^ (1 + 2)" # Evaluate one expression
+    ./calculator "x * 2" x=4.5                        # With variables
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+
+// ============================================================================
+// SPANS AND ERRORS
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    start: usize, // Byte offsets, end exclusive
+    end: usize,
+}
+
+impl Span {
+    fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ErrorKind {
+    UnexpectedChar(char),
+    InvalidNumber(String),
+    UnexpectedToken {
+        found: String,
+        expected: &'static str,
+    },
+    UnclosedParen,
+    UnknownVariable(String),
+    DivisionByZero,
+    Overflow,
+    NotARealNumber,
+    TooDeep(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CalcError {
+    kind: ErrorKind,
+    span: Span,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c),
+            ErrorKind::InvalidNumber(text) => write!(f, "invalid number '{}'", text),
+            ErrorKind::UnexpectedToken { found, expected } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            ErrorKind::UnclosedParen => write!(f, "this '(' is never closed"),
+            ErrorKind::UnknownVariable(name) => write!(f, "unknown variable '{}'", name),
+            ErrorKind::DivisionByZero => write!(f, "division by zero"),
+            ErrorKind::Overflow => write!(f, "arithmetic overflow"),
+            ErrorKind::NotARealNumber => write!(f, "result is not a real number"),
+            ErrorKind::TooDeep(limit) => write!(f, "nesting deeper than {} levels", limit),
+        }
+    }
+}
+
+impl CalcError {
+    fn new(kind: ErrorKind, span: Span) -> Self {
+        CalcError { kind, span }
+    }
+
+    // 1-based, counted in characters so it lines up with what the user typed
+    fn column(&self, source: &str) -> usize {
+        source[..self.span.start].chars().count() + 1
+    }
+
+    // The source line with carets under the offending span
+    fn render(&self, source: &str) -> String {
+        let width = source[self.span.start..self.span.end]
+            .chars()
+            .count()
+            .max(1);
+        format!(
+            "{}\n{}{} {}",
+            source,
+            " ".repeat(self.column(source) - 1),
+            "^".repeat(width),
+            self.kind
+        )
+    }
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.span.start)
+    }
+}
+
+// ============================================================================
+// LEXER
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Int(i64),
+    Float(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LParen,
+    RParen,
+    Eof,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Int(n) => write!(f, "number {}", n),
+            TokenKind::Float(x) => write!(f, "number {}", x),
+            TokenKind::Ident(name) => write!(f, "'{}'", name),
+            TokenKind::Eof => write!(f, "end of input"),
+            op => write!(f, "'{}'", op.symbol()),
+        }
+    }
+}
+
+impl TokenKind {
+    fn symbol(&self) -> &'static str {
+        match self {
+            TokenKind::Plus => "+",
+            TokenKind::Minus => "-",
+            TokenKind::Star => "*",
+            TokenKind::Slash => "/",
+            TokenKind::Percent => "%",
+            TokenKind::Caret => "^",
+            TokenKind::LParen => "(",
+            TokenKind::RParen => ")",
+            _ => "",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+// Always ends with an Eof token whose span sits just past the input
+fn tokenize(source: &str) -> Result<Vec<Token>, CalcError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        let c = source[pos..]
+            .chars()
+            .next()
+            .expect("pos is on a char boundary");
+
+        let kind = match c {
+            ' ' | '\t' | '\n' | '\r' => {
+                pos += 1;
+                continue;
+            }
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '%' => TokenKind::Percent,
+            '^' => TokenKind::Caret,
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '0'..='9' => {
+                pos = scan_number(bytes, pos);
+                let text = &source[start..pos];
+                let span = Span::new(start, pos);
+                let invalid = || CalcError::new(ErrorKind::InvalidNumber(text.to_string()), span);
+                let kind = if text.contains(['.', 'e', 'E']) {
+                    TokenKind::Float(text.parse().map_err(|_| invalid())?)
+                } else {
+                    TokenKind::Int(text.parse().map_err(|_| invalid())?)
+                };
+                tokens.push(Token { kind, span });
+                continue;
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                while pos < bytes.len()
+                    && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_')
+                {
+                    pos += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ident(source[start..pos].to_string()),
+                    span: Span::new(start, pos),
+                });
+                continue;
+            }
+            other => {
+                let span = Span::new(start, start + other.len_utf8());
+                return Err(CalcError::new(ErrorKind::UnexpectedChar(other), span));
+            }
+        };
+        pos += 1;
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, pos),
+        });
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span: Span::new(source.len(), source.len()),
+    });
+    Ok(tokens)
+}
+
+// digits [ '.' digits ] [ ('e' | 'E') ['+' | '-'] digits ] - returns the end offset
+fn scan_number(bytes: &[u8], mut pos: usize) -> usize {
+    let digits = |mut p: usize| {
+        while p < bytes.len() && bytes[p].is_ascii_digit() {
+            p += 1;
+        }
+        p
+    };
+    pos = digits(pos);
+    if bytes.get(pos) == Some(&b'.') && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) {
+        pos = digits(pos + 1);
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(pos + 1), Some(b'+' | b'-')));
+        if bytes.get(pos + 1 + sign).is_some_and(u8::is_ascii_digit) {
+            pos = digits(pos + 1 + sign);
+        }
+    }
+    pos
+}
+
+// ============================================================================
+// SYNTAX TREE
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+impl BinOp {
+    fn from_token(kind: &TokenKind) -> Option<BinOp> {
+        match kind {
+            TokenKind::Plus => Some(BinOp::Add),
+            TokenKind::Minus => Some(BinOp::Sub),
+            TokenKind::Star => Some(BinOp::Mul),
+            TokenKind::Slash => Some(BinOp::Div),
+            TokenKind::Percent => Some(BinOp::Rem),
+            TokenKind::Caret => Some(BinOp::Pow),
+            _ => None,
+        }
+    }
+
+    // (precedence, right associative?)
+    fn binding(self) -> (u8, bool) {
+        match self {
+            BinOp::Add | BinOp::Sub => (1, false),
+            BinOp::Mul | BinOp::Div | BinOp::Rem => (2, false),
+            BinOp::Pow => (4, true),
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+            BinOp::Rem => "%",
+            BinOp::Pow => "^",
+        }
+    }
+}
+
+const UNARY_MINUS_PREC: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Int(i64),
+    Float(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            // Keep the ".0" so a float never prints like an integer
+            Value::Float(x) if x.is_finite() && x.fract() == 0.0 => write!(f, "{:.1}", x),
+            Value::Float(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExprKind {
+    Number(Value),
+    Var(String),
+    Neg(Box<Expr>),
+    Binary {
+        op: BinOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Expr {
+    kind: ExprKind,
+    span: Span,    // Covers the whole sub-expression
+    height: usize, // 0 for a leaf; the parser keeps it within MAX_DEPTH
+}
+
+// Fully parenthesised, so the grouping the parser chose is visible
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ExprKind::Number(value) => write!(f, "{}", value),
+            ExprKind::Var(name) => write!(f, "{}", name),
+            ExprKind::Neg(operand) => write!(f, "(-{})", operand),
+            ExprKind::Binary { op, lhs, rhs } => write!(f, "({} {} {})", lhs, op.symbol(), rhs),
+        }
+    }
+}
+
+impl Expr {
+    // Indented tree, one node per line
+    fn tree(&self) -> String {
+        let mut out = String::new();
+        self.write_tree(&mut out, "", "");
+        out
+    }
+
+    fn write_tree(&self, out: &mut String, first: &str, rest: &str) {
+        let (label, children): (String, Vec<&Expr>) = match &self.kind {
+            ExprKind::Number(value) => (value.to_string(), vec![]),
+            ExprKind::Var(name) => (format!("var {}", name), vec![]),
+            ExprKind::Neg(operand) => ("neg".to_string(), vec![operand]),
+            ExprKind::Binary { op, lhs, rhs } => (op.symbol().to_string(), vec![lhs, rhs]),
+        };
+        out.push_str(&format!("{}{}\n", first, label));
+        for (i, child) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let (branch, indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            child.write_tree(
+                out,
+                &format!("{}{}", rest, branch),
+                &format!("{}{}", rest, indent),
+            );
+        }
+    }
+}
+
+// ============================================================================
+// PARSER
+// ============================================================================
+
+// Bounds both parser recursion and tree height, so "((((..." and "1+1+1+..."
+// can't overflow the stack in parse(), eval() or Drop
+const MAX_DEPTH: usize = 128;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self, expected: &'static str) -> CalcError {
+        let token = self.peek();
+        let found = token.kind.to_string();
+        CalcError::new(ErrorKind::UnexpectedToken { found, expected }, token.span)
+    }
+
+    // Every recursive parse_expr() call goes through here
+    fn nested(&mut self, min_prec: u8) -> Result<Expr, CalcError> {
+        if self.depth == MAX_DEPTH {
+            return Err(CalcError::new(
+                ErrorKind::TooDeep(MAX_DEPTH),
+                self.peek().span,
+            ));
+        }
+        self.depth += 1;
+        let result = self.parse_expr(min_prec);
+        self.depth -= 1;
+        result
+    }
+
+    // Left-associative chains grow the tree without recursing, so check height too
+    fn node(kind: ExprKind, span: Span, height: usize) -> Result<Expr, CalcError> {
+        if height > MAX_DEPTH {
+            return Err(CalcError::new(ErrorKind::TooDeep(MAX_DEPTH), span));
+        }
+        Ok(Expr { kind, span, height })
+    }
+
+    // expr := unary (binop expr)*  - but only operators binding at least min_prec
+    fn parse_expr(&mut self, min_prec: u8) -> Result<Expr, CalcError> {
+        let mut lhs = self.parse_unary()?;
+
+        while let Some(op) = BinOp::from_token(&self.peek().kind) {
+            let (prec, right_assoc) = op.binding();
+            if prec < min_prec {
+                break;
+            }
+            self.advance();
+            // Left associative: the right side may only hold tighter operators
+            let next_min = if right_assoc { prec } else { prec + 1 };
+            let rhs = self.nested(next_min)?;
+            let span = lhs.span.to(rhs.span);
+            let height = lhs.height.max(rhs.height) + 1;
+            let kind = ExprKind::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+            lhs = Self::node(kind, span, height)?;
+        }
+        Ok(lhs)
+    }
+
+    // Unary minus binds looser than ^ but tighter than * and /
+    fn parse_unary(&mut self) -> Result<Expr, CalcError> {
+        if self.peek().kind == TokenKind::Minus {
+            let minus = self.advance();
+            let operand = self.nested(UNARY_MINUS_PREC + 1)?;
+            let span = minus.span.to(operand.span);
+            let height = operand.height + 1;
+            return Self::node(ExprKind::Neg(Box::new(operand)), span, height);
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, CalcError> {
+        let token = self.peek().clone();
+        let kind = match token.kind {
+            TokenKind::Int(n) => ExprKind::Number(Value::Int(n)),
+            TokenKind::Float(x) => ExprKind::Number(Value::Float(x)),
+            TokenKind::Ident(name) => ExprKind::Var(name),
+            TokenKind::LParen => {
+                self.advance();
+                let inner = self.nested(1)?;
+                return match self.peek().kind {
+                    TokenKind::RParen => {
+                        let close = self.advance();
+                        Ok(Expr {
+                            span: token.span.to(close.span),
+                            ..inner
+                        })
+                    }
+                    // Point at the '(' that was left open, not at the end of input
+                    TokenKind::Eof => Err(CalcError::new(ErrorKind::UnclosedParen, token.span)),
+                    _ => Err(self.unexpected("an operator or ')'")),
+                };
+            }
+            _ => return Err(self.unexpected("a number, variable or '('")),
+        };
+        self.advance();
+        Ok(Expr {
+            kind,
+            span: token.span,
+            height: 0,
+        })
+    }
+}
+
+fn parse(source: &str) -> Result<Expr, CalcError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+        depth: 0,
+    };
+    let expr = parser.parse_expr(1)?;
+    if parser.peek().kind != TokenKind::Eof {
+        return Err(parser.unexpected("an operator or end of input"));
+    }
+    Ok(expr)
+}
+
+// ============================================================================
+// EVALUATOR
+// ============================================================================
+
+type Env = HashMap<String, Value>;
+
+fn eval(expr: &Expr, env: &Env) -> Result<Value, CalcError> {
+    let err = |kind| CalcError::new(kind, expr.span);
+    match &expr.kind {
+        ExprKind::Number(value) => Ok(*value),
+        ExprKind::Var(name) => env
+            .get(name)
+            .copied()
+            .ok_or_else(|| err(ErrorKind::UnknownVariable(name.clone()))),
+        ExprKind::Neg(operand) => match eval(operand, env)? {
+            Value::Int(n) => n
+                .checked_neg()
+                .map(Value::Int)
+                .ok_or(err(ErrorKind::Overflow)),
+            Value::Float(x) => Ok(Value::Float(-x)),
+        },
+        ExprKind::Binary { op, lhs, rhs } => {
+            let a = eval(lhs, env)?;
+            let b = eval(rhs, env)?;
+            let is_zero = match b {
+                Value::Int(n) => n == 0,
+                Value::Float(x) => x == 0.0,
+            };
+            if matches!(op, BinOp::Div | BinOp::Rem) && is_zero {
+                // Blame the divisor, which is what has to change
+                return Err(CalcError::new(ErrorKind::DivisionByZero, rhs.span));
+            }
+            let result = match (a, b) {
+                (Value::Int(a), Value::Int(b)) => int_op(*op, a, b),
+                (a, b) => Some(Value::Float(float_op(*op, as_f64(a), as_f64(b)))),
+            };
+            match result {
+                Some(Value::Float(x)) if x.is_nan() => Err(err(ErrorKind::NotARealNumber)),
+                Some(Value::Float(x)) if x.is_infinite() => Err(err(ErrorKind::Overflow)),
+                Some(value) => Ok(value),
+                None => Err(err(ErrorKind::Overflow)),
+            }
+        }
+    }
+}
+
+fn as_f64(value: Value) -> f64 {
+    match value {
+        Value::Int(n) => n as f64,
+        Value::Float(x) => x,
+    }
+}
+
+// None means overflow; divisors are already known to be non-zero
+fn int_op(op: BinOp, a: i64, b: i64) -> Option<Value> {
+    match op {
+        BinOp::Add => a.checked_add(b).map(Value::Int),
+        BinOp::Sub => a.checked_sub(b).map(Value::Int),
+        BinOp::Mul => a.checked_mul(b).map(Value::Int),
+        // 7 / 2 is 3.5, not 3: only exact quotients stay integers
+        BinOp::Div if a.checked_rem(b)? != 0 => Some(Value::Float(a as f64 / b as f64)),
+        BinOp::Div => a.checked_div(b).map(Value::Int),
+        BinOp::Rem => a.checked_rem(b).map(Value::Int),
+        BinOp::Pow if b < 0 => Some(Value::Float((a as f64).powf(b as f64))),
+        // 0, 1 and -1 stay small for any exponent, even one beyond u32
+        BinOp::Pow => match a {
+            0 | 1 => Some(Value::Int(if b == 0 { 1 } else { a })),
+            -1 => Some(Value::Int(if b % 2 == 0 { 1 } else { -1 })),
+            _ => a.checked_pow(u32::try_from(b).ok()?).map(Value::Int),
+        },
+    }
+}
+
+fn float_op(op: BinOp, a: f64, b: f64) -> f64 {
+    match op {
+        BinOp::Add => a + b,
+        BinOp::Sub => a - b,
+        BinOp::Mul => a * b,
+        BinOp::Div => a / b,
+        BinOp::Rem => a % b,
+        BinOp::Pow => a.powf(b),
+    }
+}
+
+fn evaluate(source: &str, env: &Env) -> Result<Value, CalcError> {
+    eval(&parse(source)?, env)
+}
+
+// ============================================================================
+// RANDOM EXPRESSIONS (for the round-trip check)
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn random_expr(rng: &mut XorShift, depth: u32) -> Expr {
+    let leaf = depth == 0 || rng.below(3) == 0;
+    let kind = if leaf {
+        match rng.below(3) {
+            0 => ExprKind::Number(Value::Int(rng.below(1000) as i64)),
+            1 => ExprKind::Number(Value::Float(rng.below(400) as f64 / 4.0)),
+            _ => ExprKind::Var(["x", "y", "rate"][rng.below(3) as usize].to_string()),
+        }
+    } else if rng.below(5) == 0 {
+        ExprKind::Neg(Box::new(random_expr(rng, depth - 1)))
+    } else {
+        let ops = [
+            BinOp::Add,
+            BinOp::Sub,
+            BinOp::Mul,
+            BinOp::Div,
+            BinOp::Rem,
+            BinOp::Pow,
+        ];
+        ExprKind::Binary {
+            op: ops[rng.below(6) as usize],
+            lhs: Box::new(random_expr(rng, depth - 1)),
+            rhs: Box::new(random_expr(rng, depth - 1)),
+        }
+    };
+    let height = match &kind {
+        ExprKind::Number(_) | ExprKind::Var(_) => 0,
+        ExprKind::Neg(operand) => operand.height + 1,
+        ExprKind::Binary { lhs, rhs, .. } => lhs.height.max(rhs.height) + 1,
+    };
+    Expr {
+        kind,
+        span: Span::new(0, 0),
+        height,
+    }
+}
+
+fn run_cli(args: &[String]) -> Result<(), String> {
+    let mut env = Env::new();
+    for binding in &args[1..] {
+        let (name, value) = binding
+            .split_once('=')
+            .ok_or_else(|| format!("expected name=value, got '{}'", binding))?;
+        let value = evaluate(value, &env).map_err(|e| format!("{}: {}", name, e))?;
+        env.insert(name.to_string(), value);
+    }
+    let value = evaluate(&args[0], &env).map_err(|e| e.render(&args[0]))?;
+    println!("{}", value);
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(e) = run_cli(&args) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    println!("🧮 === CALCULATOR ===");
+    let no_vars = Env::new();
+
+    // ========================================================================
+    // 1. THE while-let.rs TOKEN STREAM, ACTUALLY COMPUTED
+    // ========================================================================
+    println!("\n🔤 === \"5 + 3 * 2\" FROM SOURCE TEXT ===");
+
+    let source = "5 + 3 * 2";
+    for token in tokenize(source).unwrap() {
+        println!(
+            "  {:<12} bytes {}..{}",
+            format!("{:?}", token.kind),
+            token.span.start,
+            token.span.end
+        );
+    }
+    let ast = parse(source).unwrap();
+    print!("{}", ast.tree());
+    let value = eval(&ast, &no_vars).unwrap();
+    println!("  {} = {}", ast, value);
+    assert_eq!(value, Value::Int(11));
+
+    // ========================================================================
+    // 2. PRECEDENCE, ASSOCIATIVITY AND UNARY MINUS
+    // ========================================================================
+    println!("\n📐 === PRECEDENCE AND ASSOCIATIVITY ===");
+
+    let cases = [
+        ("8 - 3 - 2", "((8 - 3) - 2)", Value::Int(3)),
+        ("2 ^ 3 ^ 2", "(2 ^ (3 ^ 2))", Value::Int(512)),
+        ("-2 ^ 2", "(-(2 ^ 2))", Value::Int(-4)),
+        ("-2 * 3", "((-2) * 3)", Value::Int(-6)),
+        ("2 ^ -1", "(2 ^ (-1))", Value::Float(0.5)),
+        ("(5 + 3) * 2", "((5 + 3) * 2)", Value::Int(16)),
+        ("--4", "(-(-4))", Value::Int(4)),
+        ("17 % 5 * 2", "((17 % 5) * 2)", Value::Int(4)),
+        ("7 / 2", "(7 / 2)", Value::Float(3.5)),
+        ("1.5e2 + 0.25", "(150.0 + 0.25)", Value::Float(150.25)),
+    ];
+    for (source, grouped, expected) in cases {
+        let ast = parse(source).unwrap();
+        let value = eval(&ast, &no_vars).unwrap();
+        println!(
+            "  {:<14} parses as {:<16} = {}",
+            source,
+            ast.to_string(),
+            value
+        );
+        assert_eq!(ast.to_string(), grouped);
+        assert_eq!(value, expected);
+    }
+
+    // ========================================================================
+    // 3. VARIABLES
+    // ========================================================================
+    println!("\n📦 === VARIABLES ===");
+
+    let mut env = Env::new();
+    env.insert("price".to_string(), Value::Float(19.99));
+    env.insert("qty".to_string(), Value::Int(3));
+    env.insert("tax_rate".to_string(), Value::Float(0.25));
+    let source = "price * qty * (1 + tax_rate)";
+    let total = evaluate(source, &env).unwrap();
+    println!("  {} = {}", source, total);
+    match total {
+        Value::Float(x) => assert!((x - 74.9625).abs() < 1e-9),
+        Value::Int(_) => panic!("float variable should make the result a float"),
+    }
+    assert_eq!(evaluate("qty * qty - 1", &env), Ok(Value::Int(8)));
+
+    // ========================================================================
+    // 4. ERRORS THAT POINT AT THE PROBLEM
+    // ========================================================================
+    println!("\n🎯 === ERRORS WITH COLUMNS ===");
+
+    let bad: [(&str, ErrorKind, usize); 10] = [
+        (
+            "2 + * 3",
+            ErrorKind::UnexpectedToken {
+                found: "'*'".into(),
+                expected: "a number, variable or '('",
+            },
+            5,
+        ),
+        ("(1 + 2", ErrorKind::UnclosedParen, 1),
+        (
+            "(1 + 2))",
+            ErrorKind::UnexpectedToken {
+                found: "')'".into(),
+                expected: "an operator or end of input",
+            },
+            8,
+        ),
+        (
+            "1 2",
+            ErrorKind::UnexpectedToken {
+                found: "number 2".into(),
+                expected: "an operator or end of input",
+            },
+            3,
+        ),
+        ("2 $ 3", ErrorKind::UnexpectedChar('$'), 3),
+        ("10 / (5 - 5)", ErrorKind::DivisionByZero, 6),
+        ("rate * 2", ErrorKind::UnknownVariable("rate".into()), 1),
+        ("9223372036854775807 + 1", ErrorKind::Overflow, 1),
+        ("1 + 2 ^ 64", ErrorKind::Overflow, 5),
+        (
+            "99999999999999999999 - 1",
+            ErrorKind::InvalidNumber("99999999999999999999".into()),
+            1,
+        ),
+    ];
+    for (source, expected_kind, expected_column) in bad {
+        let error = evaluate(source, &no_vars).unwrap_err();
+        println!("  column {}:", error.column(source));
+        for line in error.render(source).lines() {
+            println!("    {}", line);
+        }
+        assert_eq!(error.kind, expected_kind, "{}", source);
+        assert_eq!(error.column(source), expected_column, "{}", source);
+    }
+
+    // Floats overflow to infinity; that is reported too, as is a complex result
+    let error = evaluate("1e308 * 10", &no_vars).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Overflow);
+    let error = evaluate("(0 - 8) ^ 0.5", &no_vars).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::NotARealNumber);
+    println!(
+        "  1e308 * 10 -> {}; (0 - 8) ^ 0.5 -> {}",
+        ErrorKind::Overflow,
+        error.kind
+    );
+
+    // A multi-byte character still gets exactly one caret
+    let source = "1 + π";
+    let error = evaluate(source, &no_vars).unwrap_err();
+    assert_eq!(error.span, Span::new(4, 6));
+    assert!(
+        error
+            .render(source)
+            .ends_with("\n    ^ unexpected character 'π'")
+    );
+
+    // Deep nesting and long chains are refused before they can overflow the stack
+    let deep_parens = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+    let long_chain = vec!["1"; 100_000].join(" + ");
+    let long_power = vec!["2"; 100_000].join(" ^ ");
+    let many_minus = format!("{}1", "-".repeat(100_000));
+    for source in [&deep_parens, &long_chain, &long_power, &many_minus] {
+        let error = evaluate(source, &no_vars).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::TooDeep(MAX_DEPTH));
+    }
+    println!("  100 000 nested parens: {}", ErrorKind::TooDeep(MAX_DEPTH));
+    let sum = vec!["1"; MAX_DEPTH + 1].join(" + ");
+    assert_eq!(
+        evaluate(&sum, &no_vars),
+        Ok(Value::Int(MAX_DEPTH as i64 + 1))
+    );
+
+    // 0, 1 and -1 don't overflow however large the exponent
+    assert_eq!(evaluate("1 ^ 9999999999", &no_vars), Ok(Value::Int(1)));
+    assert_eq!(evaluate("0 ^ 9999999999", &no_vars), Ok(Value::Int(0)));
+    assert_eq!(evaluate("0 ^ 0", &no_vars), Ok(Value::Int(1)));
+    assert_eq!(evaluate("(-1) ^ 9999999999", &no_vars), Ok(Value::Int(-1)));
+    assert_eq!(evaluate("(-1) ^ 9999999998", &no_vars), Ok(Value::Int(1)));
+    assert_eq!(
+        evaluate("2 ^ 9999999999", &no_vars).unwrap_err().kind,
+        ErrorKind::Overflow
+    );
+
+    // ========================================================================
+    // 5. PRETTY-PRINT ROUND TRIP
+    // ========================================================================
+    println!("\n🔁 === PRINT -> PARSE ROUND TRIP ===");
+
+    let mut rng = XorShift(0x00c0_ffee);
+    let mut env = Env::new();
+    env.insert("x".to_string(), Value::Int(7));
+    env.insert("y".to_string(), Value::Float(-2.5));
+    env.insert("rate".to_string(), Value::Float(0.125));
+    let mut evaluated = 0;
+    for i in 0..500 {
+        let original = random_expr(&mut rng, 5);
+        let printed = original.to_string();
+        let reparsed =
+            parse(&printed).unwrap_or_else(|e| panic!("{}\n{}", printed, e.render(&printed)));
+        assert_eq!(reparsed.to_string(), printed);
+        // Same tree means same answer (or the same kind of error)
+        let a = eval(&original, &env).map_err(|e| e.kind);
+        let b = eval(&reparsed, &env).map_err(|e| e.kind);
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+        evaluated += usize::from(a.is_ok());
+        if i < 3 {
+            println!("  {} = {:?}", printed, a);
+        }
+    }
+    println!(
+        "  500 random trees printed, reparsed and re-evaluated ({} gave values)",
+        evaluated
+    );
+
+    println!("\n📋 === CALCULATOR SUMMARY ===");
+    println!("✅ The lexer turns text into tokens that remember their byte span");
+    println!("✅ Precedence climbing handles left/right associativity and unary minus");
+    println!("✅ Integers use checked math; floats report infinity and NaN as errors");
+    println!("✅ Errors carry a span, so they can underline the exact culprit");
+    println!("✅ The AST prints fully parenthesised or as a tree, and reparses identically");
+}
@@ -0,0 +1,718 @@
+/*
+CHAT MESSAGES - From an if-let Demo Enum to a Real Message Model
+================================================================
+
+WHAT IS THIS?
+- if-let.rs has `enum Message { Text, Image {url,width,height}, Video {url,duration}, Audio(url,duration) }`
+  and only prints the variants
+- A chat app needs more around that enum: who sent it, when, what it replies to,
+  what it said before it was edited, and whether it was deleted
+- Each variant also has its own rules: images can't be 100k pixels wide,
+  videos can't run for a day
+- A ConversationStore holds the messages and can page, search, follow threads
+  and serialize everything to JSON
+
+REAL-WORLD ANALOGY:
+- The enum is the content of a letter
+- The Message is the envelope: sender, postmark, "re: your letter of the 3rd"
+- The store is the mailbox with a filing system: newest on top, searchable,
+  and a stamp saying "withdrawn by sender" instead of silently vanishing letters
+
+VALIDATION RULES:
+┌──────────┬──────────────────────────────────────────────┐
+│ Variant  │ Rule                                         │
+├──────────┼──────────────────────────────────────────────┤
+│ Text     │ 1..=2000 characters, not just whitespace     │
+│ Image    │ width and height 1..=8192, https:// url      │
+│ Video    │ 1..=600 seconds, https:// url                │
+│ Audio    │ 1..=3600 seconds, https:// url               │
+└──────────┴──────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- Validation lives on the body enum, so every path (post AND edit) goes through it
+- Edits keep the previous body in a history list; deletes leave a tombstone
+- Paging uses the message id as a cursor: "give me 20 messages before id 340"
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+
+// ============================================================================
+// MESSAGE BODY AND ITS VALIDATION
+// ============================================================================
+
+const MAX_TEXT_CHARS: usize = 2000;
+const MAX_IMAGE_SIDE: u32 = 8192;
+const MAX_VIDEO_SECS: u32 = 600;
+const MAX_AUDIO_SECS: u32 = 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+enum MessageBody {
+    Text(String),
+    Image {
+        url: String,
+        width: u32,
+        height: u32,
+    },
+    Video {
+        url: String,
+        duration: u32,
+    },
+    Audio(String, u32), // url, duration
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ValidationError {
+    EmptyText,
+    TextTooLong {
+        chars: usize,
+    },
+    BadUrl(String),
+    BadDimensions {
+        width: u32,
+        height: u32,
+    },
+    BadDuration {
+        kind: &'static str,
+        secs: u32,
+        max: u32,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::EmptyText => write!(f, "text message is empty"),
+            ValidationError::TextTooLong { chars } => write!(
+                f,
+                "text is {} characters, the limit is {}",
+                chars, MAX_TEXT_CHARS
+            ),
+            ValidationError::BadUrl(url) => {
+                write!(f, "media url '{}' must start with https://", url)
+            }
+            ValidationError::BadDimensions { width, height } => write!(
+                f,
+                "image is {}x{}, each side must be 1..={}",
+                width, height, MAX_IMAGE_SIDE
+            ),
+            ValidationError::BadDuration { kind, secs, max } => {
+                write!(f, "{} is {}s long, must be 1..={}s", kind, secs, max)
+            }
+        }
+    }
+}
+
+impl MessageBody {
+    fn kind(&self) -> &'static str {
+        match self {
+            MessageBody::Text(_) => "text",
+            MessageBody::Image { .. } => "image",
+            MessageBody::Video { .. } => "video",
+            MessageBody::Audio(..) => "audio",
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        fn check_url(url: &str) -> Result<(), ValidationError> {
+            match url.strip_prefix("https://") {
+                Some(rest) if !rest.is_empty() => Ok(()),
+                _ => Err(ValidationError::BadUrl(url.to_string())),
+            }
+        }
+        fn check_duration(kind: &'static str, secs: u32, max: u32) -> Result<(), ValidationError> {
+            if (1..=max).contains(&secs) {
+                Ok(())
+            } else {
+                Err(ValidationError::BadDuration { kind, secs, max })
+            }
+        }
+
+        match self {
+            MessageBody::Text(text) => {
+                let chars = text.chars().count();
+                if text.trim().is_empty() {
+                    Err(ValidationError::EmptyText)
+                } else if chars > MAX_TEXT_CHARS {
+                    Err(ValidationError::TextTooLong { chars })
+                } else {
+                    Ok(())
+                }
+            }
+            MessageBody::Image { url, width, height } => {
+                check_url(url)?;
+                let side = 1..=MAX_IMAGE_SIDE;
+                if side.contains(width) && side.contains(height) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::BadDimensions {
+                        width: *width,
+                        height: *height,
+                    })
+                }
+            }
+            MessageBody::Video { url, duration } => {
+                check_url(url)?;
+                check_duration("video", *duration, MAX_VIDEO_SECS)
+            }
+            MessageBody::Audio(url, duration) => {
+                check_url(url)?;
+                check_duration("audio", *duration, MAX_AUDIO_SECS)
+            }
+        }
+    }
+
+    // What a search should look at
+    fn searchable_text(&self) -> &str {
+        match self {
+            MessageBody::Text(text) => text,
+            MessageBody::Image { url, .. }
+            | MessageBody::Video { url, .. }
+            | MessageBody::Audio(url, _) => url,
+        }
+    }
+}
+
+// Same wording style as the if-let.rs prints
+impl fmt::Display for MessageBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageBody::Text(text) => write!(f, "{}", text),
+            MessageBody::Image { url, width, height } => {
+                write!(f, "🖼️  {} ({}x{})", url, width, height)
+            }
+            MessageBody::Video { url, duration } => write!(f, "🎬 {} ({}s)", url, duration),
+            MessageBody::Audio(url, duration) => write!(f, "🎵 {} ({}s)", url, duration),
+        }
+    }
+}
+
+// ============================================================================
+// MESSAGE ENVELOPE
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Edit {
+    at: i64,
+    previous: MessageBody,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Message {
+    id: u64,
+    sender: String,
+    sent_at: i64, // Unix seconds
+    reply_to: Option<u64>,
+    body: MessageBody,
+    edits: Vec<Edit>, // Oldest first
+    deleted_at: Option<i64>,
+}
+
+impl Message {
+    fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    fn edited_at(&self) -> Option<i64> {
+        self.edits.last().map(|e| e.at)
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} {}", self.id, self.sender)?;
+        if let Some(parent) = self.reply_to {
+            write!(f, " ↪#{}", parent)?;
+        }
+        if self.is_deleted() {
+            return write!(f, ": [message deleted]");
+        }
+        write!(f, ": {}", self.body)?;
+        if !self.edits.is_empty() {
+            write!(f, " (edited)")?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// CONVERSATION STORE
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum ChatError {
+    Invalid(ValidationError),
+    UnknownMessage(u64),
+    NotSender {
+        id: u64,
+        who: String,
+    },
+    AlreadyDeleted(u64),
+    KindChange {
+        id: u64,
+        from: &'static str,
+        to: &'static str,
+    },
+    TimeTravel {
+        id: u64,
+        at: i64,
+    },
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::Invalid(e) => write!(f, "invalid message: {}", e),
+            ChatError::UnknownMessage(id) => write!(f, "message #{} does not exist", id),
+            ChatError::NotSender { id, who } => {
+                write!(f, "{} cannot change message #{}: not the sender", who, id)
+            }
+            ChatError::AlreadyDeleted(id) => write!(f, "message #{} was deleted", id),
+            ChatError::KindChange { id, from, to } => write!(
+                f,
+                "message #{} is {}, an edit cannot turn it into {}",
+                id, from, to
+            ),
+            ChatError::TimeTravel { id, at } => {
+                write!(
+                    f,
+                    "change to message #{} at {} is older than the message or its last edit",
+                    id, at
+                )
+            }
+        }
+    }
+}
+
+impl From<ValidationError> for ChatError {
+    fn from(e: ValidationError) -> Self {
+        ChatError::Invalid(e)
+    }
+}
+
+struct Page<'a> {
+    messages: Vec<&'a Message>, // Oldest first, ready to render top to bottom
+    before: Option<u64>,        // Cursor for the next (older) page, if any
+}
+
+#[derive(Default)]
+struct ConversationStore {
+    messages: Vec<Message>, // Ordered by id, which is assignment order
+    index: HashMap<u64, usize>,
+    next_id: u64,
+}
+
+impl ConversationStore {
+    fn new() -> Self {
+        ConversationStore {
+            next_id: 1,
+            ..Default::default()
+        }
+    }
+
+    fn get(&self, id: u64) -> Option<&Message> {
+        self.index.get(&id).map(|&i| &self.messages[i])
+    }
+
+    fn get_mut(&mut self, id: u64) -> Result<&mut Message, ChatError> {
+        let i = *self.index.get(&id).ok_or(ChatError::UnknownMessage(id))?;
+        Ok(&mut self.messages[i])
+    }
+
+    fn post(
+        &mut self,
+        sender: &str,
+        sent_at: i64,
+        body: MessageBody,
+        reply_to: Option<u64>,
+    ) -> Result<u64, ChatError> {
+        body.validate()?;
+        if let Some(parent) = reply_to {
+            match self.get(parent) {
+                None => return Err(ChatError::UnknownMessage(parent)),
+                Some(m) if m.is_deleted() => return Err(ChatError::AlreadyDeleted(parent)),
+                Some(_) => {}
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.index.insert(id, self.messages.len());
+        self.messages.push(Message {
+            id,
+            sender: sender.to_string(),
+            sent_at,
+            reply_to,
+            body,
+            edits: Vec::new(),
+            deleted_at: None,
+        });
+        Ok(id)
+    }
+
+    // Shared checks for edit and delete: exists, right sender, not deleted, sane time
+    fn editable(&mut self, id: u64, who: &str, at: i64) -> Result<&mut Message, ChatError> {
+        let message = self.get_mut(id)?;
+        if message.sender != who {
+            return Err(ChatError::NotSender {
+                id,
+                who: who.to_string(),
+            });
+        }
+        if message.is_deleted() {
+            return Err(ChatError::AlreadyDeleted(id));
+        }
+        // Changes only move forward: not before sending, nor before the last edit
+        if at < message.edited_at().unwrap_or(message.sent_at) {
+            return Err(ChatError::TimeTravel { id, at });
+        }
+        Ok(message)
+    }
+
+    fn edit(&mut self, id: u64, who: &str, at: i64, body: MessageBody) -> Result<(), ChatError> {
+        body.validate()?;
+        let message = self.editable(id, who, at)?;
+        if message.body.kind() != body.kind() {
+            return Err(ChatError::KindChange {
+                id,
+                from: message.body.kind(),
+                to: body.kind(),
+            });
+        }
+        let previous = std::mem::replace(&mut message.body, body);
+        message.edits.push(Edit { at, previous });
+        Ok(())
+    }
+
+    // Soft delete: replies keep pointing at a tombstone instead of at nothing
+    fn delete(&mut self, id: u64, who: &str, at: i64) -> Result<(), ChatError> {
+        self.editable(id, who, at)?.deleted_at = Some(at);
+        Ok(())
+    }
+
+    // Newest `limit` messages older than `before` (or the newest overall)
+    fn page(&self, before: Option<u64>, limit: usize) -> Page<'_> {
+        let end = match before {
+            Some(id) => self.messages.partition_point(|m| m.id < id),
+            None => self.messages.len(),
+        };
+        let start = end.saturating_sub(limit);
+        Page {
+            messages: self.messages[start..end].iter().collect(),
+            before: (start > 0).then(|| self.messages[start].id),
+        }
+    }
+
+    // Case-insensitive, newest first; deleted messages are not searchable
+    fn search(&self, query: &str) -> Vec<&Message> {
+        let query = query.to_lowercase();
+        self.messages
+            .iter()
+            .rev()
+            .filter(|m| !m.is_deleted())
+            .filter(|m| {
+                m.body.searchable_text().to_lowercase().contains(&query)
+                    || m.sender.to_lowercase() == query
+            })
+            .collect()
+    }
+
+    // The root message and every reply below it, in posting order
+    fn thread(&self, root: u64) -> Vec<&Message> {
+        let mut children: HashMap<u64, Vec<u64>> = HashMap::new();
+        for m in &self.messages {
+            if let Some(parent) = m.reply_to {
+                children.entry(parent).or_default().push(m.id);
+            }
+        }
+        let mut ids = Vec::new();
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            if self.index.contains_key(&id) {
+                ids.push(id);
+                // Push in reverse so the earliest reply is visited first
+                stack.extend(children.get(&id).into_iter().flatten().rev());
+            }
+        }
+        ids.sort_unstable();
+        ids.iter().filter_map(|&id| self.get(id)).collect()
+    }
+
+    fn to_json(&self) -> String {
+        let items: Vec<String> = self.messages.iter().map(message_json).collect();
+        format!("{{\"messages\":[{}]}}", items.join(","))
+    }
+}
+
+// ============================================================================
+// JSON
+// ============================================================================
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_opt<T: ToString>(value: Option<T>) -> String {
+    value.map_or("null".to_string(), |v| v.to_string())
+}
+
+fn body_json(body: &MessageBody) -> String {
+    let fields = match body {
+        MessageBody::Text(text) => format!("\"text\":{}", json_string(text)),
+        MessageBody::Image { url, width, height } => format!(
+            "\"url\":{},\"width\":{},\"height\":{}",
+            json_string(url),
+            width,
+            height
+        ),
+        MessageBody::Video { url, duration } | MessageBody::Audio(url, duration) => {
+            format!("\"url\":{},\"duration\":{}", json_string(url), duration)
+        }
+    };
+    format!("{{\"type\":\"{}\",{}}}", body.kind(), fields)
+}
+
+fn message_json(m: &Message) -> String {
+    // A deleted message keeps its envelope but not its content or history
+    let (body, edits) = if m.is_deleted() {
+        ("null".to_string(), "[]".to_string())
+    } else {
+        let edits: Vec<String> = m
+            .edits
+            .iter()
+            .map(|e| {
+                format!(
+                    "{{\"at\":{},\"previous\":{}}}",
+                    e.at,
+                    body_json(&e.previous)
+                )
+            })
+            .collect();
+        (body_json(&m.body), format!("[{}]", edits.join(",")))
+    };
+    format!(
+        "{{\"id\":{},\"sender\":{},\"sent_at\":{},\"reply_to\":{},\"edited_at\":{},\"deleted_at\":{},\"body\":{},\"edits\":{}}}",
+        m.id,
+        json_string(&m.sender),
+        m.sent_at,
+        json_opt(m.reply_to),
+        json_opt(m.edited_at()),
+        json_opt(m.deleted_at),
+        body,
+        edits
+    )
+}
+
+fn text(s: &str) -> MessageBody {
+    MessageBody::Text(s.to_string())
+}
+
+fn main() {
+    println!("💬 === CHAT MESSAGES ===");
+
+    let mut chat = ConversationStore::new();
+    let t0 = 1_700_000_000;
+
+    // ========================================================================
+    // 1. POSTING THE if-let.rs VARIANTS
+    // ========================================================================
+    println!("\n📨 === POSTING ===");
+
+    let hello = chat.post("ana", t0, text("Hello, world!"), None).unwrap();
+    let photo = chat
+        .post(
+            "ben",
+            t0 + 5,
+            MessageBody::Image {
+                url: "https://cdn.example.com/photo.jpg".to_string(),
+                width: 1920,
+                height: 1080,
+            },
+            Some(hello),
+        )
+        .unwrap();
+    chat.post(
+        "ana",
+        t0 + 9,
+        MessageBody::Video {
+            url: "https://cdn.example.com/clip.mp4".to_string(),
+            duration: 120,
+        },
+        None,
+    )
+    .unwrap();
+    chat.post(
+        "cho",
+        t0 + 12,
+        MessageBody::Audio("https://cdn.example.com/song.mp3".to_string(), 180),
+        None,
+    )
+    .unwrap();
+    let nice = chat
+        .post("ana", t0 + 20, text("Nice photo!"), Some(photo))
+        .unwrap();
+    chat.post("ben", t0 + 25, text("Thanks - taken in Lisbon"), Some(nice))
+        .unwrap();
+    for m in &chat.messages {
+        println!("  {}", m);
+    }
+
+    // ========================================================================
+    // 2. PER-VARIANT VALIDATION
+    // ========================================================================
+    println!("\n🛂 === VALIDATION ===");
+
+    let rejected = [
+        text("   "),
+        text(&"x".repeat(2001)),
+        MessageBody::Image {
+            url: "https://cdn.example.com/huge.png".to_string(),
+            width: 100_000,
+            height: 10,
+        },
+        MessageBody::Video {
+            url: "https://cdn.example.com/movie.mp4".to_string(),
+            duration: 7_200,
+        },
+        MessageBody::Audio("ftp://old.example.com/a.mp3".to_string(), 30),
+        MessageBody::Audio("https://cdn.example.com/silence.mp3".to_string(), 0),
+    ];
+    let count_before = chat.messages.len();
+    for body in rejected {
+        let kind = body.kind();
+        let err = chat.post("dev", t0 + 30, body, None).unwrap_err();
+        println!("  ❌ {:<5} {}", kind, err);
+    }
+    assert_eq!(chat.messages.len(), count_before);
+    assert_eq!(
+        chat.post("dev", t0, text("hi"), Some(999)),
+        Err(ChatError::UnknownMessage(999))
+    );
+
+    // ========================================================================
+    // 3. EDITS AND DELETES
+    // ========================================================================
+    println!("\n✏️  === EDIT AND DELETE HISTORY ===");
+
+    chat.edit(hello, "ana", t0 + 60, text("Hello, everyone!"))
+        .unwrap();
+    chat.edit(hello, "ana", t0 + 90, text("Hello, everyone! 👋"))
+        .unwrap();
+    let edited = chat.get(hello).unwrap();
+    println!("  {}", edited);
+    for e in &edited.edits {
+        println!("    at +{}s it said: {}", e.at - t0, e.previous);
+    }
+    assert_eq!(edited.edits.len(), 2);
+    assert_eq!(edited.edits[0].previous, text("Hello, world!"));
+
+    let failures = [
+        chat.edit(hello, "ben", t0 + 100, text("hijacked")),
+        chat.edit(photo, "ben", t0 + 100, text("now it's text")),
+        chat.edit(hello, "ana", t0 - 1, text("before it was sent")),
+        chat.edit(hello, "ana", t0 + 100, text("")),
+        chat.edit(hello, "ana", t0 + 70, text("before the last edit")),
+    ];
+    for f in &failures {
+        println!("  ❌ {}", f.as_ref().unwrap_err());
+    }
+    assert!(matches!(failures[0], Err(ChatError::NotSender { .. })));
+    assert!(matches!(failures[1], Err(ChatError::KindChange { .. })));
+    assert!(matches!(failures[4], Err(ChatError::TimeTravel { .. })));
+    assert_eq!(chat.get(hello).unwrap().edited_at(), Some(t0 + 90));
+
+    chat.delete(photo, "ben", t0 + 200).unwrap();
+    println!("  {}", chat.get(photo).unwrap());
+    assert_eq!(
+        chat.delete(photo, "ben", t0 + 201),
+        Err(ChatError::AlreadyDeleted(photo))
+    );
+    assert_eq!(
+        chat.post("cho", t0 + 210, text("what photo?"), Some(photo)),
+        Err(ChatError::AlreadyDeleted(photo))
+    );
+
+    // ========================================================================
+    // 4. THREADS
+    // ========================================================================
+    println!("\n🧵 === THREAD FROM #{} ===", hello);
+    let thread = chat.thread(hello);
+    for m in &thread {
+        println!("  {}", m);
+    }
+    // The deleted photo stays in the thread as a tombstone, so its reply isn't orphaned
+    let ids: Vec<u64> = thread.iter().map(|m| m.id).collect();
+    assert_eq!(ids, [hello, photo, nice, nice + 1]);
+
+    // ========================================================================
+    // 5. PAGING AND SEARCH
+    // ========================================================================
+    println!("\n📜 === PAGING (4 per page, newest first) ===");
+
+    for i in 0..5 {
+        chat.post(
+            "bot",
+            t0 + 300 + i,
+            text(&format!("status update {}", i + 1)),
+            None,
+        )
+        .unwrap();
+    }
+    let mut cursor = None;
+    let mut pages = 0;
+    let mut seen = Vec::new();
+    loop {
+        let page = chat.page(cursor, 4);
+        pages += 1;
+        let ids: Vec<u64> = page.messages.iter().map(|m| m.id).collect();
+        println!("  page {}: {:?}", pages, ids);
+        seen.splice(0..0, ids);
+        match page.before {
+            Some(before) => cursor = Some(before),
+            None => break,
+        }
+    }
+    assert_eq!(seen, (1..=chat.messages.len() as u64).collect::<Vec<_>>());
+    assert_eq!(pages, 3);
+
+    println!("\n🔎 === SEARCH ===");
+    for query in ["PHOTO", "cdn.example.com", "status", "ben"] {
+        let hits: Vec<u64> = chat.search(query).iter().map(|m| m.id).collect();
+        println!("  {:?} -> {:?}", query, hits);
+    }
+    assert_eq!(chat.search("photo").len(), 1); // The deleted image's url is not searchable
+    assert_eq!(chat.search("status").len(), 5);
+
+    // ========================================================================
+    // 6. JSON
+    // ========================================================================
+    println!("\n🧾 === JSON ===");
+
+    let json = chat.to_json();
+    println!("  {}…", &json[..json.char_indices().nth(220).unwrap().0]);
+    assert!(json.starts_with("{\"messages\":[{\"id\":1,\"sender\":\"ana\""));
+    assert!(json.contains("\"previous\":{\"type\":\"text\",\"text\":\"Hello, world!\"}"));
+    assert!(json.contains("\"id\":2,\"sender\":\"ben\",\"sent_at\":1700000005,\"reply_to\":1,\"edited_at\":null,\"deleted_at\":1700000200,\"body\":null"));
+    assert_eq!(json_string("say \"hi\"\n"), "\"say \\\"hi\\\"\\n\"");
+
+    println!("\n📋 === CHAT MESSAGES SUMMARY ===");
+    println!("✅ The if-let.rs variants now live inside a Message envelope");
+    println!("✅ Each variant validates its own limits, on post AND on edit");
+    println!("✅ Edits keep history; deletes leave a tombstone so threads stay intact");
+    println!("✅ Id cursors make paging stable while new messages arrive");
+    println!("✅ Search skips deleted messages; JSON drops their content");
+}
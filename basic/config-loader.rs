@@ -0,0 +1,591 @@
+/*
+CONFIG LOADER - Layered Sources and Typed Getters
+=================================================
+
+WHAT IS THIS?
+- if-let.rs builds a HashMap with "database_url", "port" and "debug"
+  and then digs each value out with nested `if let Some(..) / if let Ok(..)`
+- Real programs read the same settings from several places:
+  built-in defaults, a config file, environment variables, command-line flags
+- This file merges those layers with a fixed precedence, remembers where
+  every value came from, and parses values with `get::<u16>("port")`
+
+REAL-WORLD ANALOGY:
+- Defaults are the printed form, the file is what you filled in last year,
+  environment variables are sticky notes on top, and the command line is
+  what you shout across the room right now - the loudest, latest voice wins
+- When a value is wrong, the error tells you WHICH sticky note to fix
+
+PRECEDENCE (highest wins, regardless of load order):
+┌──────┬─────────────────┬───────────────────────────────┬──────────────────────────┐
+│ Rank │ Layer           │ Example                       │ Key it sets              │
+├──────┼─────────────────┼───────────────────────────────┼──────────────────────────┤
+│ 3    │ command line    │ --server.port=9000            │ server.port              │
+│ 2    │ environment     │ APP_SERVER__PORT=9000         │ server.port ("__" = ".") │
+│ 1    │ file            │ [server]⏎port = 9000          │ server.port              │
+│ 0    │ defaults        │ ("server.port", "8080")       │ server.port              │
+└──────┴─────────────────┴───────────────────────────────┴──────────────────────────┘
+
+KEY CONCEPTS:
+- Every value carries an Origin; errors and `dump` print it
+- Typed getters go through a FromConfig trait so bool accepts yes/no/on/off
+  and durations accept "30s" or "5m"
+- A lower layer loaded later never overwrites a higher one - it is kept as "shadowed"
+
+USAGE:
+    ./run_basic.sh config-loader.rs                          # Demo
+    rustc config-loader.rs && ./config-loader dump           # Defaults + APP_* env
+    APP_PORT=9000 ./config-loader --config=app.conf dump     # Add a file, show origins
+    ./config-loader --port=99999                             # Error names the argument
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::Duration;
+
+// ============================================================================
+// ORIGINS AND ERRORS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Origin {
+    Default,
+    File { name: String, line: usize },
+    Env { var: String },
+    CommandLine { arg: String },
+}
+
+impl Origin {
+    fn rank(&self) -> u8 {
+        match self {
+            Origin::Default => 0,
+            Origin::File { .. } => 1,
+            Origin::Env { .. } => 2,
+            Origin::CommandLine { .. } => 3,
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "default"),
+            Origin::File { name, line } => write!(f, "{}:{}", name, line),
+            Origin::Env { var } => write!(f, "env {}", var),
+            Origin::CommandLine { arg } => write!(f, "argument '{}'", arg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConfigError {
+    Parse {
+        source: String,
+        line: usize,
+        message: String,
+    },
+    Io {
+        path: String,
+        message: String,
+    },
+    BadArgument(String),
+    Missing {
+        key: String,
+    },
+    Invalid {
+        key: String,
+        value: String,
+        origin: Origin,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Parse {
+                source,
+                line,
+                message,
+            } => {
+                write!(f, "{}:{}: {}", source, line, message)
+            }
+            ConfigError::Io { path, message } => write!(f, "cannot read '{}': {}", path, message),
+            ConfigError::BadArgument(arg) => {
+                write!(f, "unexpected argument '{}' (expected --key=value)", arg)
+            }
+            ConfigError::Missing { key } => write!(f, "missing required setting '{}'", key),
+            ConfigError::Invalid {
+                key,
+                value,
+                origin,
+                expected,
+            } => write!(
+                f,
+                "setting '{}' = '{}' (from {}) is not a valid {}",
+                key, value, origin, expected
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// TYPED VALUES
+// ============================================================================
+
+trait FromConfig: Sized {
+    const EXPECTED: &'static str;
+    fn from_config(raw: &str) -> Option<Self>;
+}
+
+macro_rules! from_config_via_parse {
+    ($($ty:ty => $expected:expr),* $(,)?) => {
+        $(impl FromConfig for $ty {
+            const EXPECTED: &'static str = $expected;
+            fn from_config(raw: &str) -> Option<Self> {
+                raw.parse().ok()
+            }
+        })*
+    };
+}
+
+from_config_via_parse! {
+    u8 => "number 0-255",
+    u16 => "number 0-65535",
+    u32 => "non-negative number",
+    u64 => "non-negative number",
+    usize => "non-negative number",
+    i32 => "number",
+    i64 => "number",
+    f64 => "decimal number",
+    String => "string",
+}
+
+impl FromConfig for bool {
+    const EXPECTED: &'static str = "boolean (true/false, yes/no, on/off, 1/0)";
+
+    fn from_config(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Some(true),
+            "false" | "no" | "off" | "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl FromConfig for Duration {
+    const EXPECTED: &'static str = "duration (e.g. 250ms, 30s, 5m, 2h)";
+
+    fn from_config(raw: &str) -> Option<Self> {
+        let split = raw.find(|c: char| !c.is_ascii_digit())?;
+        let (number, unit) = raw.split_at(split);
+        let n: u64 = number.parse().ok()?;
+        match unit {
+            "ms" => Some(Duration::from_millis(n)),
+            "s" => Some(Duration::from_secs(n)),
+            "m" => Some(Duration::from_secs(n.checked_mul(60)?)),
+            "h" => Some(Duration::from_secs(n.checked_mul(3600)?)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// CONFIG
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: String,
+    origin: Origin,
+    shadowed: Vec<(String, Origin)>, // Lower-precedence values this one hides
+}
+
+#[derive(Default)]
+struct Config {
+    entries: BTreeMap<String, Entry>,
+}
+
+fn valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !key.starts_with('.')
+        && !key.ends_with('.')
+}
+
+// Strip one pair of matching quotes: "a b" or 'a b' -> a b
+fn unquote(value: &str) -> &str {
+    for q in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(q).and_then(|v| v.strip_suffix(q)) {
+            return inner;
+        }
+    }
+    value
+}
+
+impl Config {
+    fn new() -> Self {
+        Config::default()
+    }
+
+    fn set(&mut self, key: &str, value: &str, origin: Origin) {
+        let key = key.to_ascii_lowercase();
+        match self.entries.get_mut(&key) {
+            None => {
+                self.entries.insert(
+                    key,
+                    Entry {
+                        value: value.to_string(),
+                        origin,
+                        shadowed: Vec::new(),
+                    },
+                );
+            }
+            Some(entry) if origin.rank() >= entry.origin.rank() => {
+                let old_value = std::mem::replace(&mut entry.value, value.to_string());
+                let old_origin = std::mem::replace(&mut entry.origin, origin);
+                entry.shadowed.push((old_value, old_origin));
+            }
+            Some(entry) => entry.shadowed.push((value.to_string(), origin)),
+        }
+    }
+
+    fn load_defaults(&mut self, defaults: &[(&str, &str)]) {
+        for (key, value) in defaults {
+            self.set(key, value, Origin::Default);
+        }
+    }
+
+    // key = value lines, optionally grouped under [section] headers
+    fn load_str(&mut self, name: &str, text: &str) -> Result<(), ConfigError> {
+        let error = |line: usize, message: String| ConfigError::Parse {
+            source: name.to_string(),
+            line,
+            message,
+        };
+        let mut section = String::new();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut parsed = Vec::new();
+
+        for (i, raw_line) in text.lines().enumerate() {
+            let line_no = i + 1;
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(line_no, "section header is missing ']'".to_string()))?
+                    .trim();
+                if !valid_key(header) {
+                    return Err(error(line_no, format!("invalid section name '{}'", header)));
+                }
+                section = format!("{}.", header.to_ascii_lowercase());
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                error(line_no, format!("expected 'key = value', found '{}'", line))
+            })?;
+            let key = key.trim();
+            if !valid_key(key) {
+                return Err(error(line_no, format!("invalid key '{}'", key)));
+            }
+            let full_key = format!("{}{}", section, key.to_ascii_lowercase());
+            if let Some(first) = seen.insert(full_key.clone(), line_no) {
+                return Err(error(
+                    line_no,
+                    format!("duplicate key '{}' (first set on line {})", full_key, first),
+                ));
+            }
+            parsed.push((full_key, unquote(value.trim()).to_string(), line_no));
+        }
+
+        // Only apply a file once all of it parsed, so a typo can't half-load it
+        for (key, value, line) in parsed {
+            let origin = Origin::File {
+                name: name.to_string(),
+                line,
+            };
+            self.set(&key, &value, origin);
+        }
+        Ok(())
+    }
+
+    fn load_file(&mut self, path: &str) -> Result<(), ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+        self.load_str(path, &text)
+    }
+
+    // APP_DATABASE_URL -> database_url, APP_SERVER__PORT -> server.port
+    fn load_env(&mut self, prefix: &str, vars: impl IntoIterator<Item = (String, String)>) {
+        for (var, value) in vars {
+            if let Some(rest) = var.strip_prefix(prefix) {
+                let key = rest.to_ascii_lowercase().replace("__", ".");
+                if valid_key(&key) {
+                    self.set(&key, &value, Origin::Env { var });
+                }
+            }
+        }
+    }
+
+    // --key=value sets a value, a bare --flag means "true"; other args are returned
+    fn load_args<I, S>(&mut self, args: I) -> Result<Vec<String>, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut positional = Vec::new();
+        for arg in args {
+            let arg = arg.as_ref();
+            let Some(setting) = arg.strip_prefix("--") else {
+                positional.push(arg.to_string());
+                continue;
+            };
+            let (key, value) = setting.split_once('=').unwrap_or((setting, "true"));
+            if !valid_key(key) {
+                return Err(ConfigError::BadArgument(arg.to_string()));
+            }
+            let origin = Origin::CommandLine {
+                arg: arg.to_string(),
+            };
+            self.set(key, value, origin);
+        }
+        Ok(positional)
+    }
+
+    fn entry(&self, key: &str) -> Option<&Entry> {
+        self.entries.get(&key.to_ascii_lowercase())
+    }
+
+    fn get<T: FromConfig>(&self, key: &str) -> Result<T, ConfigError> {
+        let entry = self.entry(key).ok_or_else(|| ConfigError::Missing {
+            key: key.to_string(),
+        })?;
+        T::from_config(entry.value.trim()).ok_or_else(|| ConfigError::Invalid {
+            key: key.to_string(),
+            value: entry.value.clone(),
+            origin: entry.origin.clone(),
+            expected: T::EXPECTED,
+        })
+    }
+
+    // Missing is fine (use the fallback); present-but-wrong is still an error
+    fn get_or<T: FromConfig>(&self, key: &str, fallback: T) -> Result<T, ConfigError> {
+        match self.get(key) {
+            Err(ConfigError::Missing { .. }) => Ok(fallback),
+            other => other,
+        }
+    }
+
+    fn dump(&self) -> String {
+        let width = self.entries.keys().map(String::len).max().unwrap_or(0);
+        let mut out = String::new();
+        for (key, entry) in &self.entries {
+            out.push_str(&format!(
+                "{:<width$} = {:<28} # {}",
+                key,
+                entry.value,
+                entry.origin,
+                width = width
+            ));
+            if !entry.shadowed.is_empty() {
+                let hidden: Vec<String> = entry
+                    .shadowed
+                    .iter()
+                    .map(|(value, origin)| format!("'{}' from {}", value, origin))
+                    .collect();
+                out.push_str(&format!(" (overrides {})", hidden.join(", ")));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+const DEFAULTS: &[(&str, &str)] = &[
+    ("database_url", "postgres://localhost/dev"),
+    ("port", "8080"),
+    ("debug", "false"),
+    ("server.host", "127.0.0.1"),
+    ("server.timeout", "30s"),
+    ("server.workers", "4"),
+];
+
+// Real command line and environment: `./config-loader --config=app.conf dump`
+fn run_cli(args: Vec<String>) -> Result<(), ConfigError> {
+    let mut config = Config::new();
+    config.load_defaults(DEFAULTS);
+    // vars() panics on a non-UTF-8 variable anywhere in the environment; skip those instead
+    let env = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)));
+    config.load_env("APP_", env);
+    let positional = config.load_args(&args)?;
+    if let Ok(path) = config.get::<String>("config") {
+        config.load_file(&path)?;
+    }
+
+    if positional.iter().any(|a| a == "dump") {
+        print!("{}", config.dump());
+    } else {
+        let port: u16 = config.get("port")?;
+        let debug: bool = config.get("debug")?;
+        println!("Would listen on port {} (debug: {})", port, debug);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(e) = run_cli(args) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    println!("⚙️  === CONFIG LOADER ===");
+
+    let file = "\
+# app.conf
+database_url = postgres://localhost/mydb
+port = 8080
+debug = true
+
+[server]
+host = \"0.0.0.0\"
+timeout = 45s
+workers = 8
+";
+    let env = [
+        ("APP_PORT", "9090"),
+        ("APP_SERVER__WORKERS", "eight"),
+        ("HOME", "/home/ana"), // No APP_ prefix: ignored
+    ]
+    .map(|(k, v)| (k.to_string(), v.to_string()));
+    let args = ["--debug=off", "--server.host=10.0.0.5", "--verbose", "dump"];
+
+    // ========================================================================
+    // 1. LOADING THE LAYERS (deliberately out of order)
+    // ========================================================================
+    println!("\n📚 === LOADING LAYERS ===");
+
+    let mut config = Config::new();
+    let positional = config.load_args(args).unwrap(); // Highest layer first...
+    config.load_env("APP_", env);
+    config.load_str("app.conf", file).unwrap();
+    config.load_defaults(DEFAULTS); // ...lowest last - precedence still holds
+    println!("  Positional arguments: {:?}", positional);
+    assert_eq!(positional, ["dump"]);
+
+    // ========================================================================
+    // 2. TYPED GETTERS (replacing the nested if-lets)
+    // ========================================================================
+    println!("\n🔢 === TYPED GETTERS ===");
+
+    let db_url: String = config.get("database_url").unwrap();
+    let port: u16 = config.get("port").unwrap();
+    let debug: bool = config.get("debug").unwrap();
+    let timeout: Duration = config.get("server.timeout").unwrap();
+    let verbose: bool = config.get("verbose").unwrap();
+    println!("  🗄️  Database URL: {}", db_url);
+    println!("  🌐 Port: {}", port);
+    println!("  🐛 Debug: {}", debug);
+    println!("  ⏱️  Timeout: {:?}", timeout);
+    println!("  📣 Verbose: {}", verbose);
+    assert_eq!(db_url, "postgres://localhost/mydb"); // file beats default
+    assert_eq!(port, 9090); // env beats file
+    assert!(!debug); // command line beats file
+    assert_eq!(timeout, Duration::from_secs(45));
+    assert_eq!(config.get::<String>("SERVER.HOST").unwrap(), "10.0.0.5"); // keys ignore case
+    assert_eq!(
+        config.get_or("server.max_body", 1_048_576u32).unwrap(),
+        1_048_576
+    );
+
+    // ========================================================================
+    // 3. ERRORS NAME THE KEY AND THE SOURCE
+    // ========================================================================
+    println!("\n🚨 === ERRORS ===");
+
+    let workers = config.get::<u32>("server.workers");
+    let missing = config.get::<String>("api_key");
+    let wrong_type = config.get::<u8>("port");
+    let not_optional = config.get_or("server.workers", 1u32);
+    println!("  ❌ {}", workers.as_ref().unwrap_err());
+    println!("  ❌ {}", wrong_type.as_ref().unwrap_err());
+    println!("  ❌ {}", not_optional.as_ref().unwrap_err());
+    println!("  ❌ {}", missing.as_ref().unwrap_err());
+    assert!(matches!(
+        workers,
+        Err(ConfigError::Invalid { origin: Origin::Env { ref var }, .. }) if var == "APP_SERVER__WORKERS"
+    ));
+    assert_eq!(
+        missing,
+        Err(ConfigError::Missing {
+            key: "api_key".to_string()
+        })
+    );
+    assert!(not_optional.is_err()); // get_or only covers a MISSING value
+
+    let bad_files = [
+        ("bad1.conf", "port = 1\nthis line has no equals sign"),
+        ("bad2.conf", "[server\nport = 1"),
+        ("bad3.conf", "[db]\nurl = a\n\n[db]\nurl = b"),
+        ("bad4.conf", "bad key = 1"),
+    ];
+    for (name, text) in bad_files {
+        let mut scratch = Config::new();
+        let err = scratch.load_str(name, text).unwrap_err();
+        println!("  ❌ {}", err);
+        assert!(scratch.entries.is_empty()); // Nothing applied from a broken file
+    }
+    assert!(matches!(
+        Config::new().load_str("x", "a = 1\nb").unwrap_err(),
+        ConfigError::Parse { line: 2, .. }
+    ));
+    assert_eq!(
+        Config::new().load_args(["--=oops"]),
+        Err(ConfigError::BadArgument("--=oops".to_string()))
+    );
+
+    // ========================================================================
+    // 4. VALUE PARSING RULES
+    // ========================================================================
+    println!("\n🔤 === VALUE PARSING ===");
+
+    for raw in ["yes", "OFF", "1", "maybe"] {
+        println!("  bool {:<6} -> {:?}", raw, bool::from_config(raw));
+    }
+    for raw in ["250ms", "5m", "2h", "10", "3 days"] {
+        println!("  duration {:<6} -> {:?}", raw, Duration::from_config(raw));
+    }
+    assert_eq!(Duration::from_config("5m"), Some(Duration::from_secs(300)));
+    assert_eq!(Duration::from_config("10"), None); // Unit is required
+    assert_eq!(unquote("'single'"), "single");
+    assert_eq!(unquote("\"unbalanced"), "\"unbalanced");
+
+    // ========================================================================
+    // 5. DUMP - EFFECTIVE VALUES AND WHERE THEY CAME FROM
+    // ========================================================================
+    println!("\n🧾 === DUMP ===");
+    print!("{}", config.dump());
+    let dump = config.dump();
+    assert!(dump.contains("port"));
+    assert!(dump.contains("# env APP_PORT (overrides '8080' from app.conf:3"));
+    assert!(dump.contains("# argument '--verbose'"));
+
+    println!("\n📋 === CONFIG LOADER SUMMARY ===");
+    println!("✅ defaults < file < environment < command line, whatever the load order");
+    println!("✅ INI sections become dotted keys: [server] port -> server.port");
+    println!("✅ get::<T>() parses through FromConfig (bool, numbers, durations)");
+    println!("✅ Errors name the key, the value and the exact file line / env var / argument");
+    println!("✅ dump shows every effective value and what it overrides");
+}
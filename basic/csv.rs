@@ -0,0 +1,761 @@
+/*
+CSV - An RFC 4180 Reader and Writer
+===================================
+
+WHAT IS THIS?
+- option-combinator.rs `parse_user_input` does `input.split(',')` and wants exactly 3 parts
+- That breaks on real CSV: "Doe, Jane" is ONE field, quotes can be escaped as "",
+  a quoted field can even contain a line break, and Windows files end lines in CRLF
+- And when it fails it just says `None` - not WHERE or WHY
+- This file is a small streaming CSV reader/writer that follows RFC 4180,
+  reports errors with line and column, and maps rows to structs by header name
+
+REAL-WORLD ANALOGY:
+- split(',') is reading a form by cutting it wherever you see a comma
+- A CSV parser is reading it like a person: "this comma is inside quotes, keep going"
+
+RFC 4180 IN ONE TABLE:
+┌──────────────────────────────┬──────────────────────────┬──────────────────────┐
+│ Raw text                     │ Fields                   │ Rule                 │
+├──────────────────────────────┼──────────────────────────┼──────────────────────┤
+│ a,b,c                        │ [a] [b] [c]              │ comma separates      │
+│ "Doe, Jane",30               │ [Doe, Jane] [30]         │ quotes protect ','   │
+│ "say ""hi""",x               │ [say "hi"] [x]           │ "" is one quote      │
+│ "two⏎lines",x                │ [two⏎lines] [x]          │ quotes protect ⏎     │
+│ a,,c                         │ [a] [] [c]               │ empty field          │
+│ ab"c                         │ error                    │ quote inside bare    │
+│ "ab"c                        │ error                    │ text after quote     │
+└──────────────────────────────┴──────────────────────────┴──────────────────────┘
+
+KEY CONCEPTS:
+- The reader is an Iterator over Result<Record, CsvError> - one record in memory at a time
+- A record can span several physical lines, so records carry the line they started on
+- FromRow / ToRow traits map between rows and structs; columns are found by header name,
+  so column order in the file does not matter
+*/
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::str::FromStr;
+
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+#[derive(Debug)]
+enum CsvError {
+    Io(io::Error),
+    StrayQuote {
+        line: usize,
+        col: usize,
+    },
+    TextAfterQuote {
+        line: usize,
+        col: usize,
+    },
+    UnterminatedQuote {
+        line: usize,
+        col: usize,
+    },
+    FieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    MissingColumn {
+        name: String,
+    },
+    InvalidValue {
+        line: usize,
+        column: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(f, "I/O error: {}", e),
+            CsvError::StrayQuote { line, col } => write!(
+                f,
+                "line {}, column {}: quote inside an unquoted field (quote the whole field and double the quote)",
+                line, col
+            ),
+            CsvError::TextAfterQuote { line, col } => write!(
+                f,
+                "line {}, column {}: expected ',' or end of line after closing quote",
+                line, col
+            ),
+            CsvError::UnterminatedQuote { line, col } => write!(
+                f,
+                "line {}, column {}: quoted field is never closed",
+                line, col
+            ),
+            CsvError::FieldCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+            CsvError::MissingColumn { name } => write!(f, "no column named '{}'", name),
+            CsvError::InvalidValue {
+                line,
+                column,
+                value,
+                reason,
+            } => write!(
+                f,
+                "line {}, column '{}': invalid value '{}': {}",
+                line, column, value, reason
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+// ============================================================================
+// READER
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    line: usize, // Physical line the record starts on
+    fields: Vec<String>,
+}
+
+struct CsvReader<R> {
+    input: R,
+    line: usize,
+    expected_fields: Option<usize>,
+    flexible: bool, // Allow records with differing field counts
+    done: bool,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    fn new(input: R) -> Self {
+        CsvReader {
+            input,
+            line: 0,
+            expected_fields: None,
+            flexible: false,
+            done: false,
+        }
+    }
+
+    fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    // Reads one physical line, split into (content, terminator). None at end of input.
+    fn next_line(&mut self) -> Result<Option<(String, String)>, CsvError> {
+        let mut buf = String::new();
+        if self.input.read_line(&mut buf)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        let content_len = buf
+            .strip_suffix("\r\n")
+            .or_else(|| buf.strip_suffix('\n'))
+            .unwrap_or(&buf)
+            .len();
+        let terminator = buf.split_off(content_len);
+        Ok(Some((buf, terminator)))
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, CsvError> {
+        // Skip blank lines between records
+        let (mut content, mut terminator) = loop {
+            match self.next_line()? {
+                None => return Ok(None),
+                Some((content, _)) if content.is_empty() => continue,
+                Some(line) => break line,
+            }
+        };
+
+        let start_line = self.line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut after_quote = false; // Just closed a quoted field
+        let mut quote_at = (0, 0); // Where the open quote was, for error messages
+
+        loop {
+            let mut chars = content.chars().enumerate().peekable();
+            while let Some((i, c)) = chars.next() {
+                let col = i + 1;
+                if in_quotes {
+                    if c == '"' {
+                        if chars.next_if(|&(_, next)| next == '"').is_some() {
+                            field.push('"');
+                        } else {
+                            in_quotes = false;
+                            after_quote = true;
+                        }
+                    } else {
+                        field.push(c);
+                    }
+                } else if c == ',' {
+                    fields.push(std::mem::take(&mut field));
+                    after_quote = false;
+                } else if after_quote {
+                    return Err(CsvError::TextAfterQuote {
+                        line: self.line,
+                        col,
+                    });
+                } else if c == '"' {
+                    if !field.is_empty() {
+                        return Err(CsvError::StrayQuote {
+                            line: self.line,
+                            col,
+                        });
+                    }
+                    in_quotes = true;
+                    quote_at = (self.line, col);
+                } else {
+                    field.push(c);
+                }
+            }
+
+            if !in_quotes {
+                break;
+            }
+            // Still inside quotes: the line break belongs to the field
+            field.push_str(&terminator);
+            match self.next_line()? {
+                Some((next_content, next_terminator)) if !terminator.is_empty() => {
+                    content = next_content;
+                    terminator = next_terminator;
+                }
+                _ => {
+                    return Err(CsvError::UnterminatedQuote {
+                        line: quote_at.0,
+                        col: quote_at.1,
+                    });
+                }
+            }
+        }
+        fields.push(field);
+
+        match self.expected_fields {
+            Some(expected) if !self.flexible && expected != fields.len() => {
+                Err(CsvError::FieldCount {
+                    line: start_line,
+                    expected,
+                    found: fields.len(),
+                })
+            }
+            _ => {
+                self.expected_fields.get_or_insert(fields.len());
+                Ok(Some(Record {
+                    line: start_line,
+                    fields,
+                }))
+            }
+        }
+    }
+
+    // Treat the first record as the header row
+    fn headers(&mut self) -> Result<Headers, CsvError> {
+        match self.read_record()? {
+            Some(record) => Ok(Headers::new(record.fields)),
+            None => Ok(Headers::new(Vec::new())),
+        }
+    }
+
+    // Read the header row, then map every following record to T
+    fn deserialize<T: FromRow>(
+        mut self,
+    ) -> Result<impl Iterator<Item = Result<T, CsvError>>, CsvError> {
+        let headers = self.headers()?;
+        Ok(self.map(move |record| T::from_row(&Row::new(&headers, &record?))))
+    }
+}
+
+impl<R: BufRead> Iterator for CsvReader<R> {
+    type Item = Result<Record, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        // A bad record only costs that record; I/O errors and EOF end the stream
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = matches!(e, CsvError::Io(_) | CsvError::UnterminatedQuote { .. });
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// HEADERS, ROWS AND THE FromRow TRAIT
+// ============================================================================
+
+struct Headers {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Headers {
+    fn new(names: Vec<String>) -> Self {
+        // Trim and ignore case so " Email" and "email" find the same column
+        let index = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.trim().to_lowercase(), i))
+            .collect();
+        Headers { names, index }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.index.get(&name.trim().to_lowercase()).copied()
+    }
+}
+
+struct Row<'a> {
+    headers: &'a Headers,
+    record: &'a Record,
+}
+
+impl<'a> Row<'a> {
+    fn new(headers: &'a Headers, record: &'a Record) -> Self {
+        Row { headers, record }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a str> {
+        let i = self.headers.position(name)?;
+        self.record.fields.get(i).map(String::as_str)
+    }
+
+    fn require(&self, name: &str) -> Result<&'a str, CsvError> {
+        self.get(name).ok_or_else(|| CsvError::MissingColumn {
+            name: name.to_string(),
+        })
+    }
+
+    // Trimmed value, or None if the column is absent or blank
+    fn optional(&self, name: &str) -> Option<&'a str> {
+        self.get(name).map(str::trim).filter(|v| !v.is_empty())
+    }
+
+    fn parse<T>(&self, name: &str) -> Result<T, CsvError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let raw = self.require(name)?;
+        raw.trim()
+            .parse()
+            .map_err(|e: T::Err| CsvError::InvalidValue {
+                line: self.record.line,
+                column: name.to_string(),
+                value: raw.to_string(),
+                reason: e.to_string(),
+            })
+    }
+
+    fn invalid(&self, name: &str, reason: &str) -> CsvError {
+        CsvError::InvalidValue {
+            line: self.record.line,
+            column: name.to_string(),
+            value: self.get(name).unwrap_or_default().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+trait FromRow: Sized {
+    fn from_row(row: &Row) -> Result<Self, CsvError>;
+}
+
+trait ToRow {
+    fn headers() -> Vec<&'static str>;
+    fn to_row(&self) -> Vec<String>;
+}
+
+// ============================================================================
+// WRITER
+// ============================================================================
+
+struct CsvWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> CsvWriter<W> {
+    fn new(output: W) -> Self {
+        CsvWriter { output }
+    }
+
+    fn write_record<I, S>(&mut self, fields: I) -> Result<(), CsvError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields: Vec<String> = fields.into_iter().map(|f| quote(f.as_ref())).collect();
+        // A lone empty field would be a blank line, which readers skip
+        let line = match fields.as_slice() {
+            [only] if only.is_empty() => "\"\"".to_string(),
+            _ => fields.join(","),
+        };
+        self.output.write_all(line.as_bytes())?;
+        self.output.write_all(b"\r\n")?; // RFC 4180 line ending
+        Ok(())
+    }
+
+    fn serialize_all<'a, T: ToRow + 'a>(
+        &mut self,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> Result<(), CsvError> {
+        self.write_record(T::headers())?;
+        for item in items {
+            self.write_record(item.to_row())?;
+        }
+        Ok(())
+    }
+
+    fn into_inner(self) -> W {
+        self.output
+    }
+}
+
+// Quote only when needed, doubling any quotes inside
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// ============================================================================
+// Person - THE STRUCT FROM option-combinator.rs
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+    email: Option<String>,
+}
+
+impl FromRow for Person {
+    fn from_row(row: &Row) -> Result<Self, CsvError> {
+        let name = row.require("name")?.trim().to_string();
+        if name.is_empty() {
+            return Err(row.invalid("name", "name must not be empty"));
+        }
+        Ok(Person {
+            name,
+            age: row.parse("age")?,
+            email: row.optional("email").map(str::to_string),
+        })
+    }
+}
+
+impl ToRow for Person {
+    fn headers() -> Vec<&'static str> {
+        vec!["name", "age", "email"]
+    }
+
+    fn to_row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.age.to_string(),
+            self.email.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+// The replacement for parse_user_input: same input, but Result with a reason
+fn parse_user_input(input: &str) -> Result<Person, CsvError> {
+    let headers = Headers::new(Person::headers().into_iter().map(String::from).collect());
+    let record = CsvReader::new(input.as_bytes())
+        .next()
+        .unwrap_or(Ok(Record {
+            line: 1,
+            fields: Vec::new(),
+        }))?;
+    if record.fields.len() != headers.names.len() {
+        return Err(CsvError::FieldCount {
+            line: record.line,
+            expected: headers.names.len(),
+            found: record.fields.len(),
+        });
+    }
+    Person::from_row(&Row::new(&headers, &record))
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+fn read_all(text: &str) -> Vec<Result<Record, CsvError>> {
+    CsvReader::new(text.as_bytes()).collect()
+}
+
+fn fields_of(text: &str) -> Vec<Vec<String>> {
+    read_all(text)
+        .into_iter()
+        .map(|r| r.expect("valid CSV").fields)
+        .collect()
+}
+
+// Small deterministic PRNG for the round-trip property check
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+fn main() {
+    println!("📄 === CSV (RFC 4180) ===");
+
+    // ========================================================================
+    // 1. WHY split(',') IS NOT ENOUGH
+    // ========================================================================
+    println!("\n✂️  === split(',') vs A REAL PARSER ===");
+
+    let tricky = "\"Doe, Jane\",30,\"she said \"\"hi\"\"\"";
+    println!("Input: {}", tricky);
+    println!(
+        "  split(',') -> {:?}",
+        tricky.split(',').collect::<Vec<_>>()
+    );
+    println!("  CsvReader  -> {:?}", fields_of(tricky)[0]);
+    assert_eq!(fields_of(tricky)[0], ["Doe, Jane", "30", "she said \"hi\""]);
+
+    // ========================================================================
+    // 2. THE RFC 4180 RULES
+    // ========================================================================
+    println!("\n📐 === RFC 4180 RULES ===");
+
+    let cases: [(&str, Vec<Vec<&str>>); 6] = [
+        ("a,b,c", vec![vec!["a", "b", "c"]]),
+        (
+            "a,,c\r\n1,2,3\r\n",
+            vec![vec!["a", "", "c"], vec!["1", "2", "3"]],
+        ),
+        ("\"two\nlines\",x", vec![vec!["two\nlines", "x"]]),
+        ("\"crlf\r\nkept\",x\r\n", vec![vec!["crlf\r\nkept", "x"]]),
+        ("a,b\n\n\nc,d", vec![vec!["a", "b"], vec!["c", "d"]]), // blank lines skipped
+        ("x,\"\"", vec![vec!["x", ""]]),
+    ];
+    for (input, expected) in &cases {
+        let parsed = fields_of(input);
+        println!("  {:<24} -> {:?}", format!("{:?}", input), parsed);
+        assert_eq!(&parsed, expected);
+    }
+
+    // ========================================================================
+    // 3. ERRORS WITH LINE AND COLUMN
+    // ========================================================================
+    println!("\n🚨 === ERRORS WITH LINE AND COLUMN ===");
+
+    let bad_inputs = [
+        "name,age\nJa\"ne,30",
+        "name,age\n\"Jane\"x,30",
+        "name,age\nJane,30,extra",
+        "name,note\nJane,\"never closed\nstill going",
+    ];
+    for input in bad_inputs {
+        for result in read_all(input) {
+            if let Err(e) = result {
+                println!("  ❌ {}", e);
+            }
+        }
+    }
+    assert!(matches!(
+        read_all(bad_inputs[0])[1],
+        Err(CsvError::StrayQuote { line: 2, col: 3 })
+    ));
+    assert!(matches!(
+        read_all(bad_inputs[1])[1],
+        Err(CsvError::TextAfterQuote { line: 2, col: 7 })
+    ));
+    assert!(matches!(
+        read_all(bad_inputs[2])[1],
+        Err(CsvError::FieldCount {
+            line: 2,
+            expected: 2,
+            found: 3
+        })
+    ));
+    assert!(matches!(
+        read_all(bad_inputs[3])[1],
+        Err(CsvError::UnterminatedQuote { line: 2, col: 6 })
+    ));
+
+    // One bad record does not stop the stream
+    let mixed: Vec<_> = read_all("a,b\n1,2\n3\"x,4\n5,6");
+    assert_eq!(mixed.len(), 4);
+    assert!(mixed[2].is_err() && mixed[3].is_ok());
+    let lenient: Vec<_> = CsvReader::new("a,b\n1,2,3\n4".as_bytes())
+        .flexible(true)
+        .collect();
+    assert!(lenient.iter().all(Result::is_ok));
+    println!("  ✅ Bad records are reported and skipped; .flexible(true) allows ragged rows");
+
+    // ========================================================================
+    // 4. ROWS TO STRUCTS BY HEADER NAME
+    // ========================================================================
+    println!("\n👤 === ROWS → Person (FromRow) ===");
+
+    // Columns in a different order than the struct, with a multi-line record
+    let people_csv = "\
+Email,Name,Age\r\n\
+alice@example.com,Alice,30\r\n\
+,Bob,25\r\n\
+charlie@example.com,\"Brown, Charlie\",thirty\r\n\
+\"dana@example.com\",\"Dana\r\n(nickname: D)\",41\r\n";
+
+    let people = CsvReader::new(people_csv.as_bytes())
+        .deserialize::<Person>()
+        .unwrap();
+    let mut loaded = Vec::new();
+    for result in people {
+        match result {
+            Ok(person) => {
+                println!("  ✅ {:?}", person);
+                loaded.push(person);
+            }
+            Err(e) => println!("  ❌ {}", e),
+        }
+    }
+    assert_eq!(loaded.len(), 3);
+    assert_eq!(loaded[1].email, None);
+    assert_eq!(loaded[2].name, "Dana\r\n(nickname: D)");
+
+    let missing = CsvReader::new("name,years\nAlice,30".as_bytes())
+        .deserialize::<Person>()
+        .unwrap()
+        .next()
+        .unwrap();
+    println!("  ❌ {}", missing.as_ref().unwrap_err());
+    assert!(matches!(missing, Err(CsvError::MissingColumn { ref name }) if name == "age"));
+
+    // ========================================================================
+    // 5. parse_user_input, WITH REASONS
+    // ========================================================================
+    println!("\n🔁 === parse_user_input: Option → Result ===");
+
+    let inputs = [
+        "Alice, 30, alice@example.com",
+        "Bob, 25, ",
+        "\"Doe, Jane\", 28, jane@example.com",
+        "Charlie, twelve, charlie@example.com",
+        "Invalid input",
+    ];
+    for input in inputs {
+        match parse_user_input(input) {
+            Ok(person) => println!("  {:<40} -> ✅ {:?}", input, person),
+            Err(e) => println!("  {:<40} -> ❌ {}", input, e),
+        }
+    }
+    assert_eq!(parse_user_input(inputs[2]).unwrap().name, "Doe, Jane");
+    assert_eq!(parse_user_input(inputs[1]).unwrap().email, None);
+    assert!(matches!(
+        parse_user_input(inputs[3]),
+        Err(CsvError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        parse_user_input(inputs[4]),
+        Err(CsvError::FieldCount { found: 1, .. })
+    ));
+
+    // ========================================================================
+    // 6. WRITING AND STREAMING THROUGH A FILE
+    // ========================================================================
+    println!("\n💾 === WRITE, THEN STREAM BACK FROM DISK ===");
+
+    let path = std::env::temp_dir().join("rust-learning-people.csv");
+    {
+        let file = BufWriter::new(File::create(&path).unwrap());
+        let mut writer = CsvWriter::new(file);
+        writer.serialize_all(&loaded).unwrap();
+        writer.into_inner().flush().unwrap();
+    }
+    let written = std::fs::read_to_string(&path).unwrap();
+    print!("{}", written.replace("\r\n", "⏎\n"));
+
+    let reader = CsvReader::new(BufReader::new(File::open(&path).unwrap()));
+    let reloaded: Vec<Person> = reader
+        .deserialize::<Person>()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(reloaded, loaded);
+    std::fs::remove_file(&path).ok();
+    println!(
+        "  ✅ {} people survived the round trip through disk",
+        reloaded.len()
+    );
+
+    // ========================================================================
+    // 7. ROUND-TRIP PROPERTY: read(write(x)) == x
+    // ========================================================================
+    println!("\n🎲 === ROUND-TRIP PROPERTY ===");
+
+    let alphabet: Vec<char> = "ab ,\"\r\n'é;".chars().collect();
+    let mut rng = XorShift(0x05ee_dc5f);
+    let mut records = Vec::new();
+    for _ in 0..500 {
+        let width = 1 + rng.below(5);
+        let record: Vec<String> = (0..width)
+            .map(|_| {
+                (0..rng.below(8))
+                    .map(|_| alphabet[rng.below(alphabet.len())])
+                    .collect()
+            })
+            .collect();
+        records.push(record);
+    }
+    let mut writer = CsvWriter::new(Vec::new());
+    for record in &records {
+        writer.write_record(record).unwrap();
+    }
+    let bytes = writer.into_inner();
+    let text = String::from_utf8(bytes).unwrap();
+    let back: Vec<Vec<String>> = CsvReader::new(text.as_bytes())
+        .flexible(true)
+        .map(|r| r.unwrap().fields)
+        .collect();
+    assert_eq!(back, records);
+    println!(
+        "  ✅ {} random records with commas, quotes and line breaks round-tripped",
+        records.len()
+    );
+
+    println!("\n📋 === CSV SUMMARY ===");
+    println!("✅ Quotes protect commas and line breaks; \"\" is an escaped quote");
+    println!("✅ CsvReader streams records as an Iterator<Item = Result<Record, CsvError>>");
+    println!("✅ Errors say line and column, and one bad record doesn't end the stream");
+    println!("✅ FromRow finds columns by header name, in any order and any case");
+    println!("✅ CsvWriter quotes only when needed and ends lines with CRLF");
+}
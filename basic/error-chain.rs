@@ -0,0 +1,588 @@
+/*
+ERROR CHAIN - Causes, Context and Retry Classification
+======================================================
+
+WHAT IS THIS?
+- if-let.rs has `enum ProcessingError { InvalidInput(String), NetworkError(String), DatabaseError(String) }`
+- It has no Display, doesn't implement std::error::Error, and the String throws away
+  the real cause ("Connection failed" - which host? timeout or refused?)
+- This file builds a small error framework around it:
+  the original cause is kept and reachable through `source()`, every layer can add
+  a "while doing X" context frame on the way up through `?`, and each error knows
+  whether trying again could help
+
+REAL-WORLD ANALOGY:
+- A package comes back "undeliverable". Useful returns carry a stack of stickers:
+    "returned by sorting centre B"  ← context
+    "returned by local courier"      ← context
+    "recipient address: no such street" ← root cause
+- And the sticker colour says "try again tomorrow" (retryable) or "don't bother" (permanent)
+
+CHAIN RENDERING:
+┌───────────────────────────────────────────────────────────────────┐
+│ error: database operation failed                                  │
+│   while: processing "42:db_fail" (error-chain.rs:429)             │
+│   while: saving record 42 (error-chain.rs:428)                    │
+│   caused by: INSERT INTO records failed                           │
+│   caused by: deadlock detected                                    │
+│   retry: yes (transient)                                          │
+└───────────────────────────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- `source()` links errors into a chain: outer → inner → root cause
+- `?` calls `From::from`, so low-level errors convert into ProcessingError automatically
+- A `.context(..)` extension on Result adds frames, recording the caller's file:line
+- Retry classification looks down the chain for the first error that has an opinion
+*/
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::panic::Location;
+
+// ============================================================================
+// LOW-LEVEL ERRORS (what libraries would give us)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NetworkErrorKind {
+    Timeout,
+    ConnectionRefused,
+    HostNotFound,
+}
+
+#[derive(Debug)]
+struct NetworkError {
+    kind: NetworkErrorKind,
+    host: String,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            NetworkErrorKind::Timeout => "timed out",
+            NetworkErrorKind::ConnectionRefused => "refused the connection",
+            NetworkErrorKind::HostNotFound => "could not be resolved",
+        };
+        write!(f, "{} {}", self.host, what)
+    }
+}
+
+impl Error for NetworkError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DbFailure {
+    Deadlock,
+    ConnectionLost,
+    UniqueViolation,
+}
+
+impl fmt::Display for DbFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DbFailure::Deadlock => "deadlock detected",
+            DbFailure::ConnectionLost => "connection to server lost",
+            DbFailure::UniqueViolation => "duplicate key value violates unique constraint",
+        })
+    }
+}
+
+impl Error for DbFailure {}
+
+// A database error that itself wraps a lower-level failure
+#[derive(Debug)]
+struct DatabaseError {
+    statement: String,
+    failure: DbFailure,
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed", self.statement)
+    }
+}
+
+impl Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.failure)
+    }
+}
+
+// ============================================================================
+// THE FRAMEWORK
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ErrorKind {
+    InvalidInput,
+    Network,
+    Database,
+    Io,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Retry {
+    Retryable,
+    Permanent,
+}
+
+#[derive(Debug)]
+struct Frame {
+    message: String,
+    location: &'static Location<'static>,
+}
+
+type BoxError = Box<dyn Error + Send + Sync + 'static>;
+
+#[derive(Debug)]
+struct ProcessingError {
+    kind: ErrorKind,
+    message: String,
+    source: Option<BoxError>,
+    frames: Vec<Frame>,   // Innermost first, in the order they were added
+    retry: Option<Retry>, // Explicit override; None = ask the cause chain
+}
+
+impl ProcessingError {
+    fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        ProcessingError {
+            kind,
+            message: message.into(),
+            source: None,
+            frames: Vec::new(),
+            retry: None,
+        }
+    }
+
+    fn with_source(mut self, source: impl Into<BoxError>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    fn retry(mut self, retry: Retry) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    #[track_caller]
+    fn push_frame(mut self, message: String) -> Self {
+        self.frames.push(Frame {
+            message,
+            location: Location::caller(),
+        });
+        self
+    }
+
+    // self, then its source, then that error's source, ...
+    fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        std::iter::successors(Some(self as &(dyn Error + 'static)), |&e| e.source())
+    }
+
+    fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.chain().last().expect("chain always contains self")
+    }
+
+    // The first error of type E anywhere in the chain
+    fn find_cause<E: Error + 'static>(&self) -> Option<&E> {
+        self.chain().find_map(|e| e.downcast_ref::<E>())
+    }
+
+    fn classification(&self) -> Retry {
+        self.retry
+            .or_else(|| self.chain().skip(1).find_map(classify))
+            .unwrap_or(Retry::Permanent) // Unknown failures are not retried blindly
+    }
+
+    fn is_retryable(&self) -> bool {
+        self.classification() == Retry::Retryable
+    }
+
+    // Multi-line report for logs: message, context frames (outermost first), causes
+    fn report(&self) -> String {
+        let mut out = format!("error: {}", self.message);
+        for frame in self.frames.iter().rev() {
+            out.push_str(&format!(
+                "\n  while: {} ({}:{})",
+                frame.message,
+                frame.location.file().rsplit('/').next().unwrap_or_default(),
+                frame.location.line()
+            ));
+        }
+        for cause in self.chain().skip(1) {
+            out.push_str(&format!("\n  caused by: {}", cause));
+        }
+        out.push_str(match self.classification() {
+            Retry::Retryable => "\n  retry: yes (transient)",
+            Retry::Permanent => "\n  retry: no (permanent)",
+        });
+        out
+    }
+}
+
+// Known error types and what they say about retrying. None = "no opinion, keep looking".
+fn classify(error: &(dyn Error + 'static)) -> Option<Retry> {
+    if let Some(e) = error.downcast_ref::<NetworkError>() {
+        return Some(match e.kind {
+            NetworkErrorKind::Timeout | NetworkErrorKind::ConnectionRefused => Retry::Retryable,
+            NetworkErrorKind::HostNotFound => Retry::Permanent,
+        });
+    }
+    if let Some(e) = error.downcast_ref::<DbFailure>() {
+        return Some(match e {
+            DbFailure::Deadlock | DbFailure::ConnectionLost => Retry::Retryable,
+            DbFailure::UniqueViolation => Retry::Permanent,
+        });
+    }
+    if let Some(e) = error.downcast_ref::<io::Error>() {
+        return Some(match e.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock => {
+                Retry::Retryable
+            }
+            _ => Retry::Permanent,
+        });
+    }
+    if error.is::<ParseIntError>() {
+        return Some(Retry::Permanent);
+    }
+    None
+}
+
+// Display is the one-line message; `report()` is the full story
+impl fmt::Display for ProcessingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.frames.last() {
+            Some(frame) => write!(f, "{}: {}", frame.message, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ProcessingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+// `?` conversions from the low-level errors
+impl From<NetworkError> for ProcessingError {
+    fn from(e: NetworkError) -> Self {
+        ProcessingError::new(ErrorKind::Network, "network request failed").with_source(e)
+    }
+}
+
+impl From<DatabaseError> for ProcessingError {
+    fn from(e: DatabaseError) -> Self {
+        ProcessingError::new(ErrorKind::Database, "database operation failed").with_source(e)
+    }
+}
+
+impl From<ParseIntError> for ProcessingError {
+    fn from(e: ParseIntError) -> Self {
+        ProcessingError::new(ErrorKind::InvalidInput, "input is not valid").with_source(e)
+    }
+}
+
+impl From<io::Error> for ProcessingError {
+    fn from(e: io::Error) -> Self {
+        ProcessingError::new(ErrorKind::Io, "I/O failed").with_source(e)
+    }
+}
+
+// ============================================================================
+// .context() ON ANY Result
+// ============================================================================
+
+trait Context<T> {
+    fn context(self, message: &str) -> Result<T, ProcessingError>;
+    fn with_context<F: FnOnce() -> String>(self, message: F) -> Result<T, ProcessingError>;
+}
+
+impl<T, E: Into<ProcessingError>> Context<T> for Result<T, E> {
+    #[track_caller]
+    fn context(self, message: &str) -> Result<T, ProcessingError> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into().push_frame(message.to_string())),
+        }
+    }
+
+    // The closure only runs on the error path, so formatting is free on success
+    #[track_caller]
+    fn with_context<F: FnOnce() -> String>(self, message: F) -> Result<T, ProcessingError> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into().push_frame(message())),
+        }
+    }
+}
+
+// ============================================================================
+// RETRY
+// ============================================================================
+
+// Calls `op` until it succeeds, fails permanently, or runs out of attempts
+fn with_retry<T>(
+    max_attempts: u32,
+    mut op: impl FnMut(u32) -> Result<T, ProcessingError>,
+) -> Result<T, ProcessingError> {
+    let mut attempt = 1;
+    loop {
+        match op(attempt) {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_retryable() && attempt < max_attempts => {
+                println!(
+                    "    attempt {} failed ({}), retrying",
+                    attempt,
+                    e.root_cause()
+                );
+                attempt += 1;
+            }
+            Err(e) if attempt > 1 => {
+                return Err(e.push_frame(format!("giving up after {} attempts", attempt)));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// ============================================================================
+// process_data, REWRITTEN
+// ============================================================================
+
+struct Record {
+    id: u32,
+    payload: String,
+}
+
+// "42:payload" or just "payload" (id 0)
+fn parse_input(input: &str) -> Result<Record, ProcessingError> {
+    if input.trim().is_empty() {
+        return Err(
+            ProcessingError::new(ErrorKind::InvalidInput, "empty input").retry(Retry::Permanent)
+        );
+    }
+    match input.split_once(':') {
+        Some((id, payload)) => Ok(Record {
+            id: id
+                .trim()
+                .parse()
+                .with_context(|| format!("reading record id {:?}", id))?,
+            payload: payload.to_string(),
+        }),
+        None => Ok(Record {
+            id: 0,
+            payload: input.to_string(),
+        }),
+    }
+}
+
+fn fetch_enrichment(record: &Record) -> Result<String, NetworkError> {
+    let failure = if record.payload.contains("network_fail") {
+        Some(NetworkErrorKind::Timeout)
+    } else if record.payload.contains("network_refused") {
+        Some(NetworkErrorKind::ConnectionRefused)
+    } else if record.payload.contains("dns_fail") {
+        Some(NetworkErrorKind::HostNotFound)
+    } else {
+        None
+    };
+    match failure {
+        Some(kind) => Err(NetworkError {
+            kind,
+            host: "enrich.example.com:443".to_string(),
+        }),
+        None => Ok(format!("{} (enriched)", record.payload)),
+    }
+}
+
+fn save(record: &Record) -> Result<(), DatabaseError> {
+    let failure = if record.payload.contains("db_fail") {
+        Some(DbFailure::Deadlock)
+    } else if record.payload.contains("db_lost") {
+        Some(DbFailure::ConnectionLost)
+    } else if record.payload.contains("duplicate") {
+        Some(DbFailure::UniqueViolation)
+    } else {
+        None
+    };
+    match failure {
+        Some(failure) => Err(DatabaseError {
+            statement: "INSERT INTO records".to_string(),
+            failure,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn process_data(input: &str) -> Result<String, ProcessingError> {
+    let record = parse_input(input).with_context(|| format!("processing {:?}", input))?;
+    let enriched = fetch_enrichment(&record)
+        .with_context(|| format!("enriching record {}", record.id))
+        .with_context(|| format!("processing {:?}", input))?;
+    save(&record)
+        .with_context(|| format!("saving record {}", record.id))
+        .with_context(|| format!("processing {:?}", input))?;
+    Ok(format!("Processed: {}", enriched))
+}
+
+fn main() {
+    println!("🔗 === ERROR CHAIN ===");
+
+    // ========================================================================
+    // 1. THE if-let.rs INPUTS, THROUGH THE NEW process_data
+    // ========================================================================
+    println!("\n🧪 === process_data ===");
+
+    let test_inputs = [
+        "valid_data",
+        "",
+        "network_fail_test",
+        "db_fail_test",
+        "x7:data",
+        "5:duplicate",
+        "dns_fail",
+    ];
+    for input in test_inputs {
+        match process_data(input) {
+            Ok(result) => println!("  ✅ Success: {}", result),
+            // The if-let.rs style still works, now on kind()
+            Err(error) => {
+                let icon = match error.kind() {
+                    ErrorKind::InvalidInput => "❌",
+                    ErrorKind::Network => "🌐",
+                    ErrorKind::Database => "🗄️ ",
+                    ErrorKind::Io => "💾",
+                };
+                println!("  {} {} [retryable: {}]", icon, error, error.is_retryable());
+            }
+        }
+    }
+
+    // ========================================================================
+    // 2. FULL REPORTS FOR LOGS
+    // ========================================================================
+    println!("\n📜 === FULL CHAIN REPORTS ===");
+
+    for input in ["42:db_fail", "x7:data"] {
+        let err = process_data(input).unwrap_err();
+        println!("{}\n", err.report());
+    }
+
+    let db_err = process_data("42:db_fail").unwrap_err();
+    let messages: Vec<String> = db_err.chain().map(|e| e.to_string()).collect();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[2], "deadlock detected");
+    assert_eq!(db_err.root_cause().to_string(), "deadlock detected");
+    assert_eq!(db_err.frames.len(), 2);
+    assert_eq!(db_err.frames[0].message, "saving record 42");
+    assert!(
+        db_err
+            .report()
+            .contains("while: processing \"42:db_fail\" (error-chain.rs:")
+    );
+    assert_eq!(
+        db_err.to_string(),
+        "processing \"42:db_fail\": database operation failed"
+    );
+
+    // ========================================================================
+    // 3. DOWNCASTING THROUGH THE CHAIN
+    // ========================================================================
+    println!("🔍 === FINDING A SPECIFIC CAUSE ===");
+
+    let net_err = process_data("7:network_fail").unwrap_err();
+    if let Some(network) = net_err.find_cause::<NetworkError>() {
+        println!(
+            "  Network cause: host={} kind={:?}",
+            network.host, network.kind
+        );
+        assert_eq!(network.kind, NetworkErrorKind::Timeout);
+    }
+    assert!(net_err.find_cause::<DatabaseError>().is_none());
+    assert!(db_err.find_cause::<DbFailure>().is_some());
+    let parse_err = process_data("x7:data").unwrap_err();
+    assert!(parse_err.find_cause::<ParseIntError>().is_some());
+
+    // ========================================================================
+    // 4. RETRY CLASSIFICATION
+    // ========================================================================
+    println!("\n🔁 === RETRYABLE vs PERMANENT ===");
+
+    let cases = [
+        ("7:network_fail", true),    // timeout
+        ("dns_fail", false),         // host not found
+        ("8:network_refused", true), // refused
+        ("42:db_fail", true),        // deadlock
+        ("3:db_lost", true),         // connection lost
+        ("5:duplicate", false),      // unique violation
+        ("x7:data", false),          // bad input
+        ("", false),                 // explicit Permanent
+    ];
+    for (input, expected) in cases {
+        let err = process_data(input).unwrap_err();
+        println!(
+            "  {:<20} {:?}",
+            format!("{:?}", input),
+            err.classification()
+        );
+        assert_eq!(err.is_retryable(), expected, "{}", input);
+    }
+    let io_err: ProcessingError = io::Error::new(io::ErrorKind::TimedOut, "read timed out").into();
+    assert!(io_err.is_retryable());
+    let missing_file =
+        std::fs::read_to_string("/definitely/not/here.toml").context("loading settings");
+    let missing_file = missing_file.unwrap_err();
+    println!(
+        "  {:<20} {:?} ({})",
+        "missing file",
+        missing_file.classification(),
+        missing_file
+    );
+    assert_eq!(missing_file.kind(), ErrorKind::Io);
+    assert!(!missing_file.is_retryable());
+    let unknown = ProcessingError::new(ErrorKind::Io, "mystery").with_source("something odd");
+    assert!(!unknown.is_retryable()); // No opinion anywhere => permanent
+
+    // ========================================================================
+    // 5. RETRYING ONLY WHAT CAN SUCCEED
+    // ========================================================================
+    println!("\n⏳ === with_retry ===");
+
+    println!("  Flaky database (deadlocks twice):");
+    let result = with_retry(5, |attempt| {
+        let payload = if attempt < 3 { "db_fail" } else { "ok" };
+        process_data(&format!("9:{}", payload))
+    });
+    println!("  -> {:?}", result);
+    assert_eq!(result.unwrap(), "Processed: ok (enriched)");
+
+    println!("  Permanent failure (not retried):");
+    let mut calls = 0;
+    let result = with_retry(5, |_| {
+        calls += 1;
+        process_data("5:duplicate")
+    });
+    assert_eq!(calls, 1);
+    println!("  -> {} (calls: {})", result.unwrap_err(), calls);
+
+    println!("  Always timing out:");
+    let result = with_retry(3, |_| process_data("1:network_fail"));
+    let err = result.unwrap_err();
+    println!("{}", err.report());
+    assert_eq!(
+        err.frames.last().unwrap().message,
+        "giving up after 3 attempts"
+    );
+
+    println!("\n📋 === ERROR CHAIN SUMMARY ===");
+    println!("✅ ProcessingError implements Display and std::error::Error");
+    println!("✅ The original cause is kept and reachable through source()");
+    println!("✅ .context() / .with_context() add frames with file:line as errors bubble up");
+    println!("✅ Retry classification reads the chain: timeouts yes, bad input no");
+    println!("✅ report() renders the whole story for logs");
+}
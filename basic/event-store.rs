@@ -0,0 +1,751 @@
+/*
+EVENT STORE - Replaying Events into Session Analytics
+=====================================================
+
+WHAT IS THIS?
+- while-let.rs pops its `Event` list newest-first, only prints each event,
+  and gives up for good after the second error
+- Event sourcing keeps the raw events as the source of truth and rebuilds
+  any view from them by REPLAYING them in timestamp order
+- The view built here is per-user session analytics: how long each login
+  lasted, what was bought during it, and how much each user spent
+- Events that make no sense (a logout with no login...) are reported, not fatal
+
+REAL-WORLD ANALOGY:
+- A bank statement: the balance is not stored on its own - it is what you get
+  by adding up every transaction from the start
+- Adding up 10 years of transactions every time is slow, so the bank prints a
+  "balance brought forward" (snapshot) and only adds up what came after it
+
+REPLAY:
+┌───────────────────┬──────────────────────────────┬─────────────────────────────┐
+│ Event             │ Open session?                │ Effect                      │
+├───────────────────┼──────────────────────────────┼─────────────────────────────┤
+│ UserLogin         │ no                           │ open a session              │
+│ UserLogin         │ yes                          │ anomaly, reopen the session │
+│ Purchase          │ yes                          │ add to session + user       │
+│ Purchase          │ no                           │ anomaly, add to user only   │
+│ UserLogout        │ yes                          │ close, record the duration  │
+│ UserLogout        │ no                           │ anomaly                     │
+│ Error             │ -                            │ count; a burst is flagged   │
+└───────────────────┴──────────────────────────────┴─────────────────────────────┘
+
+KEY CONCEPTS:
+- The store is append-only; events may ARRIVE out of order, but replay sorts
+  them by (timestamp, arrival sequence), so ties keep their arrival order
+- Money is kept in integer cents so revenue totals add up exactly
+- A Snapshot is the analytics state plus the last (timestamp, seq) it included;
+  replaying from it must give exactly what a full replay gives
+- An event that arrives late and sorts BEFORE a snapshot's watermark makes that
+  snapshot stale; replay_from refuses it instead of returning wrong numbers
+*/
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+// ============================================================================
+// EVENTS AND THE STORE
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    UserLogin {
+        user_id: u32,
+        timestamp: u64,
+    },
+    UserLogout {
+        user_id: u32,
+        timestamp: u64,
+    },
+    Purchase {
+        user_id: u32,
+        cents: i64,
+        timestamp: u64,
+    },
+    Error {
+        message: String,
+        timestamp: u64,
+    },
+}
+
+impl Event {
+    fn timestamp(&self) -> u64 {
+        match self {
+            Event::UserLogin { timestamp, .. }
+            | Event::UserLogout { timestamp, .. }
+            | Event::Purchase { timestamp, .. }
+            | Event::Error { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
+fn money(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    format!("{}${}.{:02}", sign, cents.abs() / 100, cents.abs() % 100)
+}
+
+// Position in replay order: timestamp first, then arrival order
+type Key = (u64, u64);
+
+#[derive(Default)]
+struct EventStore {
+    events: Vec<Event>, // Index = arrival sequence number
+}
+
+impl EventStore {
+    fn append(&mut self, event: Event) -> u64 {
+        self.events.push(event);
+        self.events.len() as u64 - 1
+    }
+
+    fn ordered(&self) -> Vec<(Key, &Event)> {
+        self.ordered_from(0)
+    }
+
+    // Events with seq >= `first`, in replay order
+    fn ordered_from(&self, first: usize) -> Vec<(Key, &Event)> {
+        let mut ordered: Vec<(Key, &Event)> = self.events[first..]
+            .iter()
+            .enumerate()
+            .map(|(offset, event)| ((event.timestamp(), (first + offset) as u64), event))
+            .collect();
+        ordered.sort_by_key(|(key, _)| *key);
+        ordered
+    }
+
+    fn replay(&self) -> Analytics {
+        let mut analytics = Analytics::default();
+        for (key, event) in self.ordered() {
+            analytics.apply(key, event);
+        }
+        analytics
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        let state = self.replay();
+        Snapshot {
+            watermark: state.last_key,
+            events_seen: self.events.len(),
+            state,
+        }
+    }
+
+    // Start from the snapshot and apply only what arrived after it - only those
+    // events are sorted, so the cost depends on the tail, not the whole store
+    fn replay_from(&self, snapshot: &Snapshot) -> Result<(Analytics, usize), ReplayError> {
+        if snapshot.events_seen > self.events.len() {
+            return Err(ReplayError::ForeignSnapshot {
+                events_seen: snapshot.events_seen,
+                stored: self.events.len(),
+            });
+        }
+        let arrived_since =
+            (snapshot.events_seen..self.events.len()).map(|seq| (seq, &self.events[seq]));
+        for (seq, event) in arrived_since {
+            let key = (event.timestamp(), seq as u64);
+            if let Some(watermark) = snapshot.watermark
+                && key < watermark
+            {
+                return Err(ReplayError::StaleSnapshot {
+                    seq: seq as u64,
+                    timestamp: event.timestamp(),
+                    watermark: watermark.0,
+                });
+            }
+        }
+
+        // Nothing new sorts before the watermark (checked above), and everything
+        // before events_seen is already in the snapshot
+        let mut analytics = snapshot.state.clone();
+        let tail = self.ordered_from(snapshot.events_seen);
+        for &(key, event) in &tail {
+            analytics.apply(key, event);
+        }
+        Ok((analytics, tail.len()))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    state: Analytics,
+    watermark: Option<Key>, // None: taken from an empty store
+    events_seen: usize,
+}
+
+#[derive(Debug, PartialEq)]
+enum ReplayError {
+    StaleSnapshot {
+        seq: u64,
+        timestamp: u64,
+        watermark: u64,
+    },
+    ForeignSnapshot {
+        events_seen: usize,
+        stored: usize,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::StaleSnapshot {
+                seq,
+                timestamp,
+                watermark,
+            } => write!(
+                f,
+                "event #{} (t={}) arrived after a snapshot taken at t={}; replay from the start",
+                seq, timestamp, watermark
+            ),
+            ReplayError::ForeignSnapshot {
+                events_seen,
+                stored,
+            } => write!(
+                f,
+                "snapshot covers {} events but the store only has {}; it belongs to another store",
+                events_seen, stored
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// THE PROJECTION
+// ============================================================================
+
+const ERROR_BURST: usize = 3; // This many errors...
+const ERROR_WINDOW: u64 = 10; // ...within this many time units is a burst
+
+#[derive(Debug, Clone, PartialEq)]
+enum Anomaly {
+    LogoutWithoutLogin {
+        user_id: u32,
+        at: u64,
+    },
+    DoubleLogin {
+        user_id: u32,
+        at: u64,
+        open_since: u64,
+    },
+    PurchaseOutsideSession {
+        user_id: u32,
+        at: u64,
+        cents: i64,
+    },
+    ErrorBurst {
+        from: u64,
+        to: u64,
+        count: usize,
+    },
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::LogoutWithoutLogin { user_id, at } => {
+                write!(
+                    f,
+                    "t={}: user {} logged out without logging in",
+                    at, user_id
+                )
+            }
+            Anomaly::DoubleLogin {
+                user_id,
+                at,
+                open_since,
+            } => write!(
+                f,
+                "t={}: user {} logged in again (session open since t={})",
+                at, user_id, open_since
+            ),
+            Anomaly::PurchaseOutsideSession { user_id, at, cents } => write!(
+                f,
+                "t={}: user {} bought {} while logged out",
+                at,
+                user_id,
+                money(*cents)
+            ),
+            Anomaly::ErrorBurst { from, to, count } => {
+                write!(f, "t={}..{}: {} errors in a burst", from, to, count)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct OpenSession {
+    login_at: u64,
+    purchases: u32,
+    cents: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Session {
+    user_id: u32,
+    login_at: u64,
+    logout_at: u64,
+    purchases: u32,
+    cents: i64,
+}
+
+impl Session {
+    fn duration(&self) -> u64 {
+        self.logout_at - self.login_at
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct UserTotals {
+    sessions: u32,
+    time_logged_in: u64,
+    purchases: u32,
+    cents: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Analytics {
+    open: BTreeMap<u32, OpenSession>,
+    sessions: Vec<Session>, // Closed sessions, in logout order
+    users: BTreeMap<u32, UserTotals>,
+    errors: Vec<(u64, String)>,
+    recent_errors: VecDeque<u64>,
+    in_burst: bool,
+    anomalies: Vec<Anomaly>,
+    last_key: Option<Key>,
+}
+
+impl Analytics {
+    fn apply(&mut self, key: Key, event: &Event) {
+        debug_assert!(
+            self.last_key.is_none_or(|last| key > last),
+            "replay out of order"
+        );
+        self.last_key = Some(key);
+
+        match *event {
+            Event::UserLogin { user_id, timestamp } => {
+                if let Some(previous) = self.open.get(&user_id) {
+                    self.anomalies.push(Anomaly::DoubleLogin {
+                        user_id,
+                        at: timestamp,
+                        open_since: previous.login_at,
+                    });
+                    // Close the dangling session where the new one begins
+                    self.close(user_id, timestamp);
+                }
+                self.open.insert(
+                    user_id,
+                    OpenSession {
+                        login_at: timestamp,
+                        purchases: 0,
+                        cents: 0,
+                    },
+                );
+            }
+            Event::UserLogout { user_id, timestamp } => {
+                if self.open.contains_key(&user_id) {
+                    self.close(user_id, timestamp);
+                } else {
+                    self.anomalies.push(Anomaly::LogoutWithoutLogin {
+                        user_id,
+                        at: timestamp,
+                    });
+                }
+            }
+            Event::Purchase {
+                user_id,
+                cents,
+                timestamp,
+            } => {
+                let totals = self.users.entry(user_id).or_default();
+                totals.purchases += 1;
+                totals.cents += cents;
+                match self.open.get_mut(&user_id) {
+                    Some(session) => {
+                        session.purchases += 1;
+                        session.cents += cents;
+                    }
+                    None => self.anomalies.push(Anomaly::PurchaseOutsideSession {
+                        user_id,
+                        at: timestamp,
+                        cents,
+                    }),
+                }
+            }
+            Event::Error {
+                ref message,
+                timestamp,
+            } => {
+                self.errors.push((timestamp, message.clone()));
+                self.recent_errors.push_back(timestamp);
+                while self
+                    .recent_errors
+                    .front()
+                    .is_some_and(|&t| timestamp - t >= ERROR_WINDOW)
+                {
+                    self.recent_errors.pop_front();
+                }
+                // Report a burst once, when it crosses the threshold
+                let bursting = self.recent_errors.len() >= ERROR_BURST;
+                if bursting && !self.in_burst {
+                    self.anomalies.push(Anomaly::ErrorBurst {
+                        from: self.recent_errors[0],
+                        to: timestamp,
+                        count: self.recent_errors.len(),
+                    });
+                }
+                self.in_burst = bursting;
+            }
+        }
+    }
+
+    fn close(&mut self, user_id: u32, at: u64) {
+        let open = self.open.remove(&user_id).expect("caller checked");
+        let session = Session {
+            user_id,
+            login_at: open.login_at,
+            logout_at: at,
+            purchases: open.purchases,
+            cents: open.cents,
+        };
+        let totals = self.users.entry(user_id).or_default();
+        totals.sessions += 1;
+        totals.time_logged_in += session.duration();
+        self.sessions.push(session);
+    }
+
+    fn print(&self) {
+        for s in &self.sessions {
+            println!(
+                "  👤 user {} t={}..{} ({} units): {} purchases, {}",
+                s.user_id,
+                s.login_at,
+                s.logout_at,
+                s.duration(),
+                s.purchases,
+                money(s.cents)
+            );
+        }
+        for (user_id, open) in &self.open {
+            println!(
+                "  🟢 user {} still logged in since t={}",
+                user_id, open.login_at
+            );
+        }
+        for (user_id, totals) in &self.users {
+            println!(
+                "  💰 user {}: {} sessions, {} units online, {} purchases, revenue {}",
+                user_id,
+                totals.sessions,
+                totals.time_logged_in,
+                totals.purchases,
+                money(totals.cents)
+            );
+        }
+        for anomaly in &self.anomalies {
+            println!("  ⚠️  {}", anomaly);
+        }
+    }
+}
+
+// ============================================================================
+// GENERATED TRAFFIC
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+// Well-behaved sessions for several users, delivered slightly out of order
+fn generate(rng: &mut XorShift, users: u32, sessions_each: u32) -> Vec<Event> {
+    let mut events = Vec::new();
+    for user_id in 1..=users {
+        let mut t = rng.below(50);
+        for _ in 0..sessions_each {
+            events.push(Event::UserLogin {
+                user_id,
+                timestamp: t,
+            });
+            for _ in 0..rng.below(4) {
+                t += 1 + rng.below(20);
+                let cents = 100 + rng.below(9_900) as i64;
+                events.push(Event::Purchase {
+                    user_id,
+                    cents,
+                    timestamp: t,
+                });
+            }
+            t += 1 + rng.below(30);
+            events.push(Event::UserLogout {
+                user_id,
+                timestamp: t,
+            });
+            t += 1 + rng.below(100);
+        }
+    }
+    // Delivery order is roughly, not exactly, by time
+    let mut jittered: Vec<(u64, Event)> = events
+        .into_iter()
+        .map(|e| (e.timestamp() + rng.below(40), e))
+        .collect();
+    jittered.sort_by_key(|(arrival, _)| *arrival);
+    jittered.into_iter().map(|(_, e)| e).collect()
+}
+
+fn main() {
+    println!("🗃️  === EVENT STORE ===");
+
+    // ========================================================================
+    // 1. THE while-let.rs EVENTS, REPLAYED IN ORDER
+    // ========================================================================
+    println!("\n⏩ === while-let.rs EVENTS, OLDEST FIRST ===");
+
+    let mut store = EventStore::default();
+    // Arrival order is scrambled on purpose: replay must not care
+    for event in [
+        Event::Error {
+            message: "Database unreachable".to_string(),
+            timestamp: 1005,
+        },
+        Event::UserLogin {
+            user_id: 1,
+            timestamp: 1000,
+        },
+        Event::Purchase {
+            user_id: 2,
+            cents: 4999,
+            timestamp: 1003,
+        },
+        Event::UserLogout {
+            user_id: 1,
+            timestamp: 1004,
+        },
+        Event::Error {
+            message: "Connection timeout".to_string(),
+            timestamp: 1002,
+        },
+        Event::Purchase {
+            user_id: 1,
+            cents: 2999,
+            timestamp: 1001,
+        },
+        Event::Error {
+            message: "Disk full".to_string(),
+            timestamp: 1006,
+        },
+        Event::UserLogin {
+            user_id: 2,
+            timestamp: 1007,
+        },
+    ] {
+        store.append(event);
+    }
+    let timestamps: Vec<u64> = store.ordered().iter().map(|(key, _)| key.0).collect();
+    println!("  replay order: {:?}", timestamps);
+    assert!(timestamps.is_sorted());
+
+    let analytics = store.replay();
+    analytics.print();
+    println!(
+        "  errors seen: {} (processing never stops)",
+        analytics.errors.len()
+    );
+    assert_eq!(analytics.sessions.len(), 1);
+    assert_eq!(analytics.sessions[0].duration(), 4);
+    assert_eq!(analytics.sessions[0].cents, 2999);
+    assert_eq!(analytics.users[&2].cents, 4999);
+    assert_eq!(analytics.errors.len(), 3);
+    assert_eq!(
+        analytics.anomalies,
+        [
+            Anomaly::PurchaseOutsideSession {
+                user_id: 2,
+                at: 1003,
+                cents: 4999
+            },
+            Anomaly::ErrorBurst {
+                from: 1002,
+                to: 1006,
+                count: 3
+            },
+        ]
+    );
+    assert!(analytics.open.contains_key(&2));
+
+    // ========================================================================
+    // 2. ANOMALIES
+    // ========================================================================
+    println!("\n🔍 === ANOMALY DETECTION ===");
+
+    let mut store = EventStore::default();
+    for event in [
+        Event::UserLogout {
+            user_id: 7,
+            timestamp: 5,
+        },
+        Event::UserLogin {
+            user_id: 8,
+            timestamp: 10,
+        },
+        Event::Purchase {
+            user_id: 8,
+            cents: 1_250,
+            timestamp: 12,
+        },
+        Event::UserLogin {
+            user_id: 8,
+            timestamp: 20,
+        }, // Never logged out
+        Event::Purchase {
+            user_id: 8,
+            cents: 750,
+            timestamp: 21,
+        },
+        Event::UserLogout {
+            user_id: 8,
+            timestamp: 30,
+        },
+        Event::UserLogout {
+            user_id: 8,
+            timestamp: 31,
+        }, // Second logout
+    ] {
+        store.append(event);
+    }
+    let analytics = store.replay();
+    analytics.print();
+    assert_eq!(analytics.anomalies.len(), 3);
+    assert!(matches!(
+        analytics.anomalies[1],
+        Anomaly::DoubleLogin {
+            user_id: 8,
+            at: 20,
+            open_since: 10
+        }
+    ));
+    // The dangling session was closed at the second login, so nothing is lost
+    assert_eq!(
+        analytics
+            .sessions
+            .iter()
+            .map(|s| s.cents)
+            .collect::<Vec<_>>(),
+        [1_250, 750]
+    );
+    assert_eq!(analytics.users[&8].time_logged_in, 20);
+
+    // ========================================================================
+    // 3. SNAPSHOTS
+    // ========================================================================
+    println!("\n📸 === SNAPSHOT + REPLAY ===");
+
+    let mut rng = XorShift(0x0e7e_9151);
+    let traffic = generate(&mut rng, 5, 40);
+    let (early, late) = traffic.split_at(traffic.len() / 2);
+    // Every 40th early event is stuck in transit and only shows up at the very end
+    let stuck: Vec<&Event> = early.iter().skip(39).step_by(40).collect();
+    let delivered = early.iter().enumerate().filter(|(i, _)| i % 40 != 39);
+    let mut store = EventStore::default();
+    for (_, event) in delivered {
+        store.append(event.clone());
+    }
+    let snapshot = store.snapshot();
+    println!(
+        "  snapshot after {} events, watermark t={}",
+        snapshot.events_seen,
+        snapshot.watermark.unwrap().0
+    );
+
+    // Only events that sort after the watermark may follow a snapshot
+    let watermark = snapshot.watermark.unwrap().0;
+    let (after, mut before): (Vec<&Event>, Vec<&Event>) =
+        late.iter().partition(|e| e.timestamp() >= watermark);
+    before.extend(stuck);
+    for &event in &after {
+        store.append(event.clone());
+    }
+    let full = store.replay();
+    let (from_snapshot, applied) = store.replay_from(&snapshot).unwrap();
+    println!(
+        "  full replay: {} events; from snapshot: {} events",
+        store.events.len(),
+        applied
+    );
+    assert_eq!(from_snapshot, full);
+    assert_eq!(applied, after.len());
+
+    let revenue: i64 = full.users.values().map(|u| u.cents).sum();
+    let purchases: i64 = store
+        .events
+        .iter()
+        .filter_map(|e| match e {
+            Event::Purchase { cents, .. } => Some(*cents),
+            _ => None,
+        })
+        .sum();
+    assert_eq!(revenue, purchases);
+    println!(
+        "  {} sessions closed, total revenue {} (matches the raw purchase events)",
+        full.sessions.len(),
+        money(revenue)
+    );
+
+    // ========================================================================
+    // 4. A LATE EVENT MAKES THE SNAPSHOT STALE
+    // ========================================================================
+    println!("\n🐢 === LATE ARRIVALS ===");
+
+    let late_event = before.first().expect("some events straddle the watermark");
+    store.append((*late_event).clone());
+    let error = store.replay_from(&snapshot).unwrap_err();
+    println!("  ❌ {}", error);
+    assert!(matches!(error, ReplayError::StaleSnapshot { .. }));
+
+    // Rebuilding from scratch is always correct
+    for &event in &before[1..] {
+        store.append(event.clone());
+    }
+    let rebuilt = store.replay();
+    let empty = EventStore::default().snapshot();
+    assert_eq!(store.replay_from(&empty).unwrap().0, rebuilt);
+    assert_eq!(rebuilt.sessions.len(), 5 * 40); // Every generated session accounted for
+    assert!(rebuilt.anomalies.is_empty());
+    println!(
+        "  ✅ full replay with {} late events: {} sessions, no anomalies",
+        before.len(),
+        rebuilt.sessions.len()
+    );
+
+    // ...and a fresh snapshot can be used again for whatever comes next
+    let fresh = store.snapshot();
+    let next = fresh.watermark.unwrap().0 + 1;
+    store.append(Event::UserLogin {
+        user_id: 9,
+        timestamp: next,
+    });
+    let (latest, applied) = store.replay_from(&fresh).unwrap();
+    assert_eq!((applied, latest.open.len()), (1, 1));
+
+    // A snapshot from a bigger store can't belong to this one
+    let error = EventStore::default().replay_from(&fresh).unwrap_err();
+    println!("  ❌ {}", error);
+    assert!(matches!(error, ReplayError::ForeignSnapshot { .. }));
+
+    println!("\n📋 === EVENT STORE SUMMARY ===");
+    println!("✅ Events are stored as they arrive and replayed by (timestamp, seq)");
+    println!("✅ Sessions track duration, purchases and revenue in exact cents");
+    println!("✅ Odd sequences become anomalies instead of stopping the replay");
+    println!("✅ Replaying from a snapshot equals a full replay");
+    println!("✅ A late event behind the watermark is detected, not silently lost");
+}
@@ -0,0 +1,724 @@
+/*
+FAKE DATA - Seeded, Repeatable Test Fixtures Without External Crates
+====================================================================
+
+WHAT IS THIS?
+- ranges.rs builds test users as `User { id, name: format!("User{}", id), age: 20 + id * 3 }`
+- Other files hard-code people, students and grocery items by hand
+- This file generates realistic-looking records from a seed: the same seed
+  always produces exactly the same records, so fixtures are reproducible
+
+REAL-WORLD ANALOGY:
+- Like a deck of cards shuffled by a machine with a dial:
+  set the dial to 42 and you get the same "random" order every time
+
+BUILDING BLOCKS:
+┌──────────────┬──────────────────────────────────────────────────────────────┐
+│    Piece     │                           Role                               │
+├──────────────┼──────────────────────────────────────────────────────────────┤
+│ Rng          │ SplitMix64 PRNG - tiny, fast, fully determined by the seed   │
+│ FieldGen     │ Trait: "make one value" - names, emails, ages, prices, times │
+│ Schema       │ Ordered list of (column name, Box<dyn FieldGen>)             │
+│ Record       │ One generated row: Vec<(column, Value)>                      │
+│ FromRecord   │ Trait to turn a Record into your own struct                  │
+│ to_csv/json  │ Render the same records as CSV or JSON text                  │
+└──────────────┴──────────────────────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- Trait objects (Box<dyn FieldGen>) let each column use a different generator
+- Every field draws from one shared Rng in column order, so adding a column
+  changes later values - use a new seed per schema version if that matters
+- Schema::field() validates each generator, so an inverted range or an empty
+  choice list is an error up front instead of a panic mid-generation
+- Not cryptographically secure - never use it for passwords or tokens
+*/
+
+use std::fmt;
+
+// ============================================================================
+// PRNG
+// ============================================================================
+
+// SplitMix64: one u64 of state, passes common statistical test suites
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in low..=high without modulo bias (rejection sampling)
+    fn range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        let span = high - low;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+        let size = span + 1;
+        let zone = u64::MAX - (u64::MAX % size);
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return low + x % size;
+            }
+        }
+    }
+
+    // Signed version of range(); the span is taken in u64 so i64::MIN..=i64::MAX works
+    fn range_i64(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        let offset = self.range(0, high.wrapping_sub(low) as u64);
+        low.wrapping_add(offset as i64)
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        assert!(!items.is_empty(), "pick from an empty slice");
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+// ============================================================================
+// VALUES AND FIELD GENERATORS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(i64),
+    Money(i64), // Cents - avoids floating point rounding in prices
+    Text(String),
+    Timestamp(i64), // Seconds since the Unix epoch, UTC
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Money(cents) => {
+                let sign = if *cents < 0 { "-" } else { "" };
+                let abs = cents.unsigned_abs();
+                write!(f, "{}{}.{:02}", sign, abs / 100, abs % 100)
+            }
+            Value::Text(s) => write!(f, "{}", s),
+            Value::Timestamp(secs) => write!(f, "{}", format_iso8601(*secs)),
+        }
+    }
+}
+
+// Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+fn format_iso8601(secs: i64) -> String {
+    let days = secs.div_euclid(86_400);
+    let rem = secs.rem_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+// The extension point: anything that can produce a Value from the Rng
+trait FieldGen {
+    fn generate(&self, rng: &mut Rng, row: usize) -> Value;
+
+    // Checked once when the column is added, so generate() never sees bad bounds
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn ordered(low: i64, high: i64) -> Result<(), String> {
+    if low <= high {
+        Ok(())
+    } else {
+        Err(format!("empty range {}..={}", low, high))
+    }
+}
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bob", "Charlie", "Diana", "Eve", "Frank", "Grace", "Heidi", "Ivan", "Judy",
+    "Mallory", "Niaj", "Olivia", "Peggy", "Rupert", "Sybil", "Trent", "Victor", "Walter", "Zoë",
+];
+const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Garcia", "Miller", "Davis", "Lopez", "Wilson", "Anderson", "Thomas",
+    "Moore", "Martin", "Lee", "O'Brien", "Clark", "Lewis", "Young",
+];
+const DOMAINS: &[&str] = &["example.com", "example.org", "test.dev", "mail.test"];
+const GROCERIES: &[&str] = &[
+    "Apples", "Bananas", "Bread", "Butter", "Carrots", "Cheese", "Eggs", "Milk", "Oats", "Rice",
+    "Tomatoes", "Yogurt",
+];
+
+// Sequential ids: 1, 2, 3, ... (ignores the Rng so ids never collide)
+struct Sequence {
+    start: i64,
+}
+
+impl FieldGen for Sequence {
+    fn generate(&self, _rng: &mut Rng, row: usize) -> Value {
+        Value::Int(self.start + row as i64)
+    }
+}
+
+struct FullName;
+
+impl FieldGen for FullName {
+    fn generate(&self, rng: &mut Rng, _row: usize) -> Value {
+        let first = rng.pick(FIRST_NAMES);
+        let last = rng.pick(LAST_NAMES);
+        Value::Text(format!("{} {}", first, last))
+    }
+}
+
+// Emails include the row number so they stay unique within one data set
+struct Email;
+
+impl FieldGen for Email {
+    fn generate(&self, rng: &mut Rng, row: usize) -> Value {
+        let first = rng.pick(FIRST_NAMES).to_lowercase();
+        let last = rng.pick(LAST_NAMES).to_lowercase().replace('\'', "");
+        let domain = rng.pick(DOMAINS);
+        Value::Text(format!("{}.{}{}@{}", first, last, row + 1, domain))
+    }
+}
+
+struct IntRange {
+    min: i64,
+    max: i64,
+}
+
+impl FieldGen for IntRange {
+    fn generate(&self, rng: &mut Rng, _row: usize) -> Value {
+        Value::Int(rng.range_i64(self.min, self.max))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        ordered(self.min, self.max)
+    }
+}
+
+struct Price {
+    min_cents: i64,
+    max_cents: i64,
+}
+
+impl FieldGen for Price {
+    fn generate(&self, rng: &mut Rng, _row: usize) -> Value {
+        // Round to .x9 like real price tags: 3.49, 12.99, ...
+        let cents = rng.range_i64(self.min_cents, self.max_cents);
+        Value::Money((cents / 10 * 10 + 9).min(self.max_cents))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        ordered(self.min_cents, self.max_cents)
+    }
+}
+
+struct TimestampRange {
+    start: i64,
+    end: i64,
+}
+
+impl FieldGen for TimestampRange {
+    fn generate(&self, rng: &mut Rng, _row: usize) -> Value {
+        Value::Timestamp(rng.range_i64(self.start, self.end))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        ordered(self.start, self.end)
+    }
+}
+
+struct OneOf(&'static [&'static str]);
+
+impl FieldGen for OneOf {
+    fn generate(&self, rng: &mut Rng, _row: usize) -> Value {
+        Value::Text(rng.pick(self.0).to_string())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            Err("no choices".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Any closure can be a generator too - handy for one-off columns
+impl<F: Fn(&mut Rng, usize) -> Value> FieldGen for F {
+    fn generate(&self, rng: &mut Rng, row: usize) -> Value {
+        self(rng, row)
+    }
+}
+
+// ============================================================================
+// SCHEMA AND OUTPUT
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+enum FakeError {
+    BadField { column: String, reason: String },
+    BadRecord { row: usize, reason: String },
+}
+
+impl fmt::Display for FakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FakeError::BadField { column, reason } => {
+                write!(f, "column '{}': {}", column, reason)
+            }
+            FakeError::BadRecord { row, reason } => write!(f, "row {}: {}", row, reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    fields: Vec<(String, Value)>,
+}
+
+impl Record {
+    fn get(&self, column: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(c, _)| c == column)
+            .map(|(_, v)| v)
+    }
+}
+
+struct Schema {
+    columns: Vec<(String, Box<dyn FieldGen>)>,
+}
+
+impl Schema {
+    fn new() -> Self {
+        Schema {
+            columns: Vec::new(),
+        }
+    }
+
+    fn field(mut self, name: &str, generator: impl FieldGen + 'static) -> Result<Self, FakeError> {
+        generator.validate().map_err(|reason| FakeError::BadField {
+            column: name.to_string(),
+            reason,
+        })?;
+        self.columns.push((name.to_string(), Box::new(generator)));
+        Ok(self)
+    }
+
+    fn generate(&self, seed: u64, count: usize) -> Vec<Record> {
+        let mut rng = Rng::new(seed);
+        (0..count)
+            .map(|row| Record {
+                fields: self
+                    .columns
+                    .iter()
+                    .map(|(name, generator)| (name.clone(), generator.generate(&mut rng, row)))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn describe(&self) -> String {
+        let names: Vec<&str> = self.columns.iter().map(|(n, _)| n.as_str()).collect();
+        format!("Schema({})", names.join(", "))
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_csv(records: &[Record]) -> String {
+    let mut out = String::new();
+    if let Some(first) = records.first() {
+        let header: Vec<String> = first.fields.iter().map(|(c, _)| csv_escape(c)).collect();
+        out.push_str(&header.join(","));
+        out.push('\n');
+    }
+    for record in records {
+        let row: Vec<String> = record
+            .fields
+            .iter()
+            .map(|(_, v)| csv_escape(&v.to_string()))
+            .collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn to_json(records: &[Record]) -> String {
+    let rows: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let fields: Vec<String> = record
+                .fields
+                .iter()
+                .map(|(column, value)| {
+                    let rendered = match value {
+                        Value::Int(n) => n.to_string(),
+                        Value::Money(_) => value.to_string(), // 3.49 is a valid JSON number
+                        Value::Text(_) | Value::Timestamp(_) => json_escape(&value.to_string()),
+                    };
+                    format!("{}: {}", json_escape(column), rendered)
+                })
+                .collect();
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+// Map a generated record onto a struct - the "structs" output format
+trait FromRecord: Sized {
+    fn from_record(record: &Record) -> Result<Self, String>;
+}
+
+// Stops at the first record that doesn't fit rather than silently dropping it
+fn to_structs<T: FromRecord>(records: &[Record]) -> Result<Vec<T>, FakeError> {
+    records
+        .iter()
+        .enumerate()
+        .map(|(row, record)| {
+            T::from_record(record).map_err(|reason| FakeError::BadRecord { row, reason })
+        })
+        .collect()
+}
+
+fn int(record: &Record, column: &str) -> Option<i64> {
+    match record.get(column)? {
+        Value::Int(n) | Value::Money(n) | Value::Timestamp(n) => Some(*n),
+        Value::Text(_) => None,
+    }
+}
+
+fn text(record: &Record, column: &str) -> Option<String> {
+    match record.get(column)? {
+        Value::Text(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn require<T>(value: Option<T>, column: &str) -> Result<T, String> {
+    value.ok_or_else(|| format!("column '{}' is missing or has the wrong type", column))
+}
+
+fn int_u32(record: &Record, column: &str) -> Result<u32, String> {
+    let n = require(int(record, column), column)?;
+    u32::try_from(n).map_err(|_| format!("column '{}' value {} does not fit in u32", column, n))
+}
+
+// The same shape as ranges.rs `User`
+#[derive(Debug)]
+struct User {
+    id: u32,
+    name: String,
+    age: u32,
+}
+
+impl FromRecord for User {
+    fn from_record(record: &Record) -> Result<Self, String> {
+        Ok(User {
+            id: int_u32(record, "id")?,
+            name: require(text(record, "name"), "name")?,
+            age: int_u32(record, "age")?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct GroceryItem {
+    name: String,
+    quantity: u32,
+    price_cents: i64,
+}
+
+impl FromRecord for GroceryItem {
+    fn from_record(record: &Record) -> Result<Self, String> {
+        Ok(GroceryItem {
+            name: require(text(record, "item"), "item")?,
+            quantity: int_u32(record, "quantity")?,
+            price_cents: require(int(record, "price"), "price")?,
+        })
+    }
+}
+
+fn user_schema() -> Result<Schema, FakeError> {
+    Schema::new()
+        .field("id", Sequence { start: 1 })?
+        .field("name", FullName)?
+        .field("email", Email)?
+        .field("age", IntRange { min: 18, max: 80 })?
+        .field(
+            "signed_up",
+            TimestampRange {
+                start: 1_577_836_800, // 2020-01-01
+                end: 1_735_689_599,   // 2024-12-31
+            },
+        )
+}
+
+// Grocery items: built-in generators plus a closure for the quantity
+fn grocery_schema() -> Result<Schema, FakeError> {
+    Schema::new()
+        .field("item", OneOf(GROCERIES))?
+        .field("quantity", |rng: &mut Rng, _row: usize| {
+            // Mostly small quantities, occasionally a bulk buy
+            let bulk = rng.range(0, 9) == 0;
+            Value::Int(if bulk { 12 } else { rng.range(1, 4) as i64 })
+        })?
+        .field(
+            "price",
+            Price {
+                min_cents: 99,
+                max_cents: 1_299,
+            },
+        )
+}
+
+fn main() {
+    println!("🎲 === SEEDED FAKE DATA GENERATOR ===");
+
+    // ========================================================================
+    // 1. THE PRNG
+    // ========================================================================
+    println!("\n🔢 === THE PRNG ===");
+
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let first: Vec<u64> = (0..3).map(|_| a.next_u64()).collect();
+    let second: Vec<u64> = (0..3).map(|_| b.next_u64()).collect();
+    println!("Seed 42 twice: {:?}", first);
+    assert_eq!(first, second);
+    assert_ne!(first, {
+        let mut c = Rng::new(43);
+        (0..3).map(|_| c.next_u64()).collect::<Vec<_>>()
+    });
+
+    // range() stays inside its bounds and hits every value
+    let mut rng = Rng::new(7);
+    let mut seen = [0usize; 6];
+    for _ in 0..6_000 {
+        seen[(rng.range(1, 6) - 1) as usize] += 1;
+    }
+    println!("6 000 dice rolls: {:?}", seen);
+    assert!(seen.iter().all(|&count| (800..1_200).contains(&count)));
+
+    // Signed ranges work across zero and over the whole i64 span
+    assert!((0..1_000).all(|_| (-3..=3).contains(&rng.range_i64(-3, 3))));
+    let wide = IntRange {
+        min: i64::MIN,
+        max: i64::MAX,
+    };
+    assert!(matches!(wide.generate(&mut rng, 0), Value::Int(_)));
+
+    // ========================================================================
+    // 2. SCHEMAS AND RECORDS
+    // ========================================================================
+    println!("\n📐 === SCHEMAS AND RECORDS ===");
+
+    let schema = user_schema().unwrap();
+    println!("{}", schema.describe());
+    let records = schema.generate(2024, 5);
+    for record in &records {
+        let shown: Vec<String> = record
+            .fields
+            .iter()
+            .map(|(c, v)| format!("{}={}", c, v))
+            .collect();
+        println!("  {}", shown.join("  "));
+    }
+
+    // Same seed -> identical records; different seed -> different records
+    assert_eq!(records, user_schema().unwrap().generate(2024, 5));
+    assert_ne!(records, user_schema().unwrap().generate(2025, 5));
+    // Asking for more rows doesn't change the earlier ones
+    assert_eq!(records[..], user_schema().unwrap().generate(2024, 50)[..5]);
+
+    for record in &records {
+        let age = int(record, "age").unwrap();
+        assert!((18..=80).contains(&age));
+        assert!(text(record, "email").unwrap().contains('@'));
+    }
+
+    assert_eq!(format_iso8601(0), "1970-01-01T00:00:00Z");
+    assert_eq!(format_iso8601(1_709_210_096), "2024-02-29T12:34:56Z"); // Leap day
+
+    // ========================================================================
+    // 3. OUTPUT FORMATS
+    // ========================================================================
+    println!("\n📤 === OUTPUT FORMATS ===");
+
+    // Structs: the ranges.rs test users, but with varied realistic values
+    let users: Vec<User> = to_structs(&records).unwrap();
+    println!("As structs:");
+    for user in &users {
+        println!("  {:?}", user);
+    }
+    assert_eq!(users.len(), 5);
+    assert_eq!(users[0].id, 1);
+    assert!(
+        users
+            .iter()
+            .all(|u| (18..=80).contains(&u.age) && u.name.contains(' '))
+    );
+
+    println!("As CSV:");
+    let csv = to_csv(&records[..3]);
+    print!("{}", csv);
+    assert_eq!(csv.lines().count(), 4); // Header + 3 rows
+    assert_eq!(csv, to_csv(&user_schema().unwrap().generate(2024, 3)));
+
+    println!("As JSON:");
+    let json = to_json(&records[..2]);
+    print!("{}", json);
+    assert!(json.starts_with("[\n  {\"id\": 1, "));
+
+    // Escaping: names like O'Brien and values with commas/quotes stay valid
+    let tricky = Record {
+        fields: vec![(
+            "note".to_string(),
+            Value::Text("say \"hi\", ok".to_string()),
+        )],
+    };
+    assert_eq!(
+        to_csv(std::slice::from_ref(&tricky)),
+        "note\n\"say \"\"hi\"\", ok\"\n"
+    );
+    assert_eq!(
+        to_json(&[tricky]),
+        "[\n  {\"note\": \"say \\\"hi\\\", ok\"}\n]\n"
+    );
+
+    // ========================================================================
+    // 4. PLUGGABLE GENERATORS
+    // ========================================================================
+    println!("\n🔌 === PLUGGABLE GENERATORS ===");
+
+    let groceries = grocery_schema().unwrap();
+    println!("{}", groceries.describe());
+    let items: Vec<GroceryItem> = to_structs(&groceries.generate(1, 6)).unwrap();
+    let mut total = 0;
+    for item in &items {
+        let line = item.price_cents * i64::from(item.quantity);
+        total += line;
+        println!(
+            "  {:<10} x{:<3} @ {}",
+            item.name,
+            item.quantity,
+            Value::Money(item.price_cents)
+        );
+        assert_eq!(item.price_cents % 10, 9);
+    }
+    println!("  Total: {}", Value::Money(total));
+
+    // Refunds: the sign goes in front, the cents stay positive
+    assert_eq!(Value::Money(-150).to_string(), "-1.50");
+    assert_eq!(Value::Money(-50).to_string(), "-0.50");
+    assert_eq!(Value::Money(5).to_string(), "0.05");
+
+    // Students: reuse FullName with a score range
+    let students = Schema::new()
+        .field("name", FullName)
+        .and_then(|schema| schema.field("grade", IntRange { min: 55, max: 100 }))
+        .unwrap();
+    print!("{}", to_csv(&students.generate(99, 4)));
+
+    // Bad generators are rejected when the column is added, not mid-generation
+    let bad_fields = [
+        Schema::new().field("grade", IntRange { min: 100, max: 55 }),
+        Schema::new().field("item", OneOf(&[])),
+        Schema::new().field(
+            "price",
+            Price {
+                min_cents: 500,
+                max_cents: 100,
+            },
+        ),
+    ];
+    for result in &bad_fields {
+        println!("  ❌ {}", result.as_ref().err().unwrap());
+    }
+    assert_eq!(
+        bad_fields[1].as_ref().err(),
+        Some(&FakeError::BadField {
+            column: "item".to_string(),
+            reason: "no choices".to_string()
+        })
+    );
+
+    // Records that don't fit the struct are reported with their row, not dropped
+    let negative_age = Schema::new()
+        .field("id", Sequence { start: 1 })
+        .and_then(|schema| schema.field("name", FullName))
+        .and_then(|schema| schema.field("age", IntRange { min: -5, max: -1 }))
+        .unwrap();
+    let result: Result<Vec<User>, FakeError> = to_structs(&negative_age.generate(3, 2));
+    println!("  ❌ {}", result.as_ref().unwrap_err());
+    assert!(matches!(result, Err(FakeError::BadRecord { row: 0, .. })));
+    let huge_id = Schema::new()
+        .field("id", Sequence { start: 1 << 32 })
+        .and_then(|schema| schema.field("name", FullName))
+        .and_then(|schema| schema.field("age", IntRange { min: 30, max: 30 }))
+        .unwrap();
+    let result: Result<Vec<User>, FakeError> = to_structs(&huge_id.generate(3, 2));
+    assert_eq!(
+        result.unwrap_err(),
+        FakeError::BadRecord {
+            row: 0,
+            reason: "column 'id' value 4294967296 does not fit in u32".to_string()
+        }
+    );
+
+    println!("\n📋 === FAKE DATA SUMMARY ===");
+    println!("✅ SplitMix64 PRNG: same seed, same output, no external crates");
+    println!("✅ FieldGen trait (and plain closures) make every column pluggable");
+    println!("✅ One Schema renders to structs, CSV or JSON");
+    println!("✅ Bad ranges fail when the schema is built, bad rows fail by row");
+    println!("✅ Prices use integer cents, timestamps render as ISO-8601 UTC");
+}
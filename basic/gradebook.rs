@@ -0,0 +1,821 @@
+/*
+GRADEBOOK ANALYTICS - From Five Hard-Coded Students to Real Class Statistics
+============================================================================
+
+WHAT IS THIS?
+- iterator.rs computes an average and a maximum over five `Student { name, grade }` values
+- A real gradebook has many assessments per student, grouped into weighted categories
+  (homework 30%, exams 70%, ...), and teachers want more than an average
+- This file loads assessments from CSV, computes weighted scores, class statistics,
+  curved letter grades, outliers, and a per-student report
+
+REAL-WORLD ANALOGY:
+- Like a teacher's spreadsheet: one row per graded assignment,
+  a weights table at the top, and a summary sheet computed from both
+
+CSV FORMAT (one row per graded assessment, header required):
+    student,category,assessment,score,max
+    Alice,homework,HW1,18,20
+    Alice,exam,Midterm,88,100
+
+STATISTICS:
+┌──────────────┬──────────────────────────────────────────────────────────────┐
+│   Measure    │                         Definition                           │
+├──────────────┼──────────────────────────────────────────────────────────────┤
+│ mean         │ sum / count                                                  │
+│ median       │ middle value; average of the two middles for even counts     │
+│ std_dev      │ population standard deviation (divide by count)              │
+│ percentile   │ linear interpolation between closest ranks (0-100)           │
+│ outlier      │ below Q1 - 1.5*IQR or above Q3 + 1.5*IQR (needs 4+ students) │
+└──────────────┴──────────────────────────────────────────────────────────────┘
+
+WELL-DEFINED EDGE CASES:
+- Empty class: statistics are None, no ranks, the report says so
+- Ties: equal scores share a rank (1, 2, 2, 4), tied students are listed by name
+- Missing category for a student: the remaining category weights are re-normalized
+- Curves never push a score above 100 or below 0
+- Weights: negative, NaN or all-zero weights are rejected when the book is built
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+// ============================================================================
+// DATA MODEL
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct Assessment {
+    category: String,
+    name: String,
+    score: f64,
+    max: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Student {
+    name: String,
+    assessments: Vec<Assessment>,
+}
+
+impl Student {
+    // Percentage earned in one category: total points / total possible points
+    fn category_percent(&self, category: &str) -> Option<f64> {
+        let (earned, possible) = self
+            .assessments
+            .iter()
+            .filter(|a| a.category == category)
+            .fold((0.0, 0.0), |(e, p), a| (e + a.score, p + a.max));
+
+        if possible > 0.0 {
+            Some(earned / possible * 100.0)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum GradebookError {
+    MissingHeader,
+    WrongFieldCount { line: usize, found: usize },
+    MalformedQuote { line: usize },
+    BadNumber { line: usize, field: String },
+    InvalidScore { line: usize, score: f64, max: f64 },
+    UnknownCategory { line: usize, category: String },
+    InvalidWeight { category: String, weight: f64 },
+    NoWeight,
+    Io(String),
+}
+
+impl fmt::Display for GradebookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GradebookError::MissingHeader => {
+                write!(f, "expected header: student,category,assessment,score,max")
+            }
+            GradebookError::WrongFieldCount { line, found } => {
+                write!(f, "line {}: expected 5 fields, found {}", line, found)
+            }
+            GradebookError::MalformedQuote { line } => {
+                write!(
+                    f,
+                    "line {}: quoted field is unclosed or followed by text",
+                    line
+                )
+            }
+            GradebookError::BadNumber { line, field } => {
+                write!(f, "line {}: '{}' is not a number", line, field)
+            }
+            GradebookError::InvalidScore { line, score, max } => {
+                write!(f, "line {}: score {} is outside 0..={}", line, score, max)
+            }
+            GradebookError::UnknownCategory { line, category } => {
+                write!(f, "line {}: category '{}' has no weight", line, category)
+            }
+            GradebookError::InvalidWeight { category, weight } => {
+                write!(f, "category '{}' has invalid weight {}", category, weight)
+            }
+            GradebookError::NoWeight => write!(f, "category weights must not all be zero"),
+            GradebookError::Io(message) => write!(f, "io error: {}", message),
+        }
+    }
+}
+
+// Split one CSV line on commas, honouring "quoted, fields" and "" escapes.
+// None when a quote is never closed or text follows the closing quote.
+// Unquoted fields are trimmed.
+fn split_csv_line(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false; // Inside "..."
+    let mut was_quoted = false; // This field had quotes, so keep its spaces
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                was_quoted = true;
+            }
+            ',' if !quoted => {
+                let done = std::mem::take(&mut field);
+                fields.push(if was_quoted {
+                    done
+                } else {
+                    done.trim().to_string()
+                });
+                was_quoted = false;
+            }
+            c if was_quoted && !quoted && c.is_whitespace() => {} // Spaces after the closing quote
+            _ if was_quoted && !quoted => return None,            // Text after the closing quote
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(if was_quoted {
+        field
+    } else {
+        field.trim().to_string()
+    });
+    Some(fields)
+}
+
+// ============================================================================
+// STATISTICS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Stats {
+    count: usize,
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Stats {
+    // None for an empty slice - there is no sensible mean of nothing
+    fn from_values(values: &[f64]) -> Option<Stats> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let count = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / count as f64;
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count as f64;
+
+        Some(Stats {
+            count,
+            mean,
+            median: percentile_sorted(&sorted, 50.0),
+            std_dev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[count - 1],
+        })
+    }
+}
+
+// Linear interpolation between closest ranks; `sorted` must be non-empty
+fn percentile_sorted(sorted: &[f64], p: f64) -> f64 {
+    let p = p.clamp(0.0, 100.0);
+    let position = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    Some(percentile_sorted(&sorted, p))
+}
+
+// ============================================================================
+// CURVES AND LETTER GRADES
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+enum Curve {
+    None,
+    Flat(f64),       // Add the same number of points to everyone
+    TargetMean(f64), // Shift everyone so the class mean lands on the target
+    SquareRoot,      // 10 * sqrt(score): helps low scores more than high ones
+}
+
+impl Curve {
+    fn apply(&self, score: f64, class_mean: f64) -> f64 {
+        let curved = match self {
+            Curve::None => score,
+            Curve::Flat(points) => score + points,
+            Curve::TargetMean(target) => score + (target - class_mean),
+            Curve::SquareRoot => 10.0 * score.max(0.0).sqrt(),
+        };
+        curved.clamp(0.0, 100.0)
+    }
+}
+
+// Cutoffs are checked from the top; the first one the score reaches wins
+struct GradingScale {
+    cutoffs: Vec<(f64, &'static str)>,
+    fallback: &'static str,
+}
+
+impl GradingScale {
+    fn standard() -> Self {
+        GradingScale {
+            cutoffs: vec![(90.0, "A"), (80.0, "B"), (70.0, "C"), (60.0, "D")],
+            fallback: "F",
+        }
+    }
+
+    fn letter(&self, score: f64) -> &'static str {
+        self.cutoffs
+            .iter()
+            .find(|(cutoff, _)| score >= *cutoff)
+            .map(|(_, letter)| *letter)
+            .unwrap_or(self.fallback)
+    }
+}
+
+// ============================================================================
+// GRADEBOOK
+// ============================================================================
+
+struct Gradebook {
+    weights: BTreeMap<String, f64>,
+    students: BTreeMap<String, Student>, // Sorted by name for stable output
+    curve: Curve,
+    scale: GradingScale,
+}
+
+#[derive(Debug, Clone)]
+struct StudentResult {
+    name: String,
+    weighted: f64,
+    curved: f64,
+    letter: &'static str,
+    rank: usize, // 1-based, ties share a rank
+    tied: bool,
+    outlier: bool,
+}
+
+impl Gradebook {
+    // Weights must be finite and non-negative, and at least one must be positive
+    fn new(weights: &[(&str, f64)]) -> Result<Self, GradebookError> {
+        if let Some((category, weight)) = weights.iter().find(|(_, w)| !w.is_finite() || *w < 0.0) {
+            return Err(GradebookError::InvalidWeight {
+                category: category.to_string(),
+                weight: *weight,
+            });
+        }
+        if weights.iter().all(|(_, w)| *w == 0.0) {
+            return Err(GradebookError::NoWeight);
+        }
+        Ok(Gradebook {
+            weights: weights.iter().map(|(c, w)| (c.to_string(), *w)).collect(),
+            students: BTreeMap::new(),
+            curve: Curve::None,
+            scale: GradingScale::standard(),
+        })
+    }
+
+    fn with_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    fn with_scale(mut self, scale: GradingScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    fn load_csv(&mut self, text: &str) -> Result<usize, GradebookError> {
+        let mut lines = text.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.trim() == "student,category,assessment,score,max" => {}
+            _ => return Err(GradebookError::MissingHeader),
+        }
+
+        let mut loaded = 0;
+        for (index, raw) in lines {
+            let line = index + 1;
+            if raw.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_csv_line(raw).ok_or(GradebookError::MalformedQuote { line })?;
+            let fields: Vec<&str> = fields.iter().map(|f| f.as_str()).collect();
+            if fields.len() != 5 {
+                return Err(GradebookError::WrongFieldCount {
+                    line,
+                    found: fields.len(),
+                });
+            }
+
+            let number = |field: &str| {
+                field.parse::<f64>().map_err(|_| GradebookError::BadNumber {
+                    line,
+                    field: field.to_string(),
+                })
+            };
+            let score = number(fields[3])?;
+            let max = number(fields[4])?;
+            if max <= 0.0 || !(0.0..=max).contains(&score) {
+                return Err(GradebookError::InvalidScore { line, score, max });
+            }
+
+            let category = fields[1].to_string();
+            if !self.weights.contains_key(&category) {
+                return Err(GradebookError::UnknownCategory { line, category });
+            }
+
+            let student = self
+                .students
+                .entry(fields[0].to_string())
+                .or_insert_with(|| Student {
+                    name: fields[0].to_string(),
+                    assessments: Vec::new(),
+                });
+            student.assessments.push(Assessment {
+                category,
+                name: fields[2].to_string(),
+                score,
+                max,
+            });
+            loaded += 1;
+        }
+
+        Ok(loaded)
+    }
+
+    fn load_csv_file(&mut self, path: &str) -> Result<usize, GradebookError> {
+        let text = fs::read_to_string(path).map_err(|e| GradebookError::Io(e.to_string()))?;
+        self.load_csv(&text)
+    }
+
+    // Weighted average over the categories this student actually has
+    fn weighted_score(&self, student: &Student) -> Option<f64> {
+        let (total, weight_sum) = self
+            .weights
+            .iter()
+            .filter_map(|(category, weight)| {
+                student
+                    .category_percent(category)
+                    .map(|percent| (percent * weight, *weight))
+            })
+            .fold((0.0, 0.0), |(t, w), (p, wt)| (t + p, w + wt));
+
+        if weight_sum > 0.0 {
+            Some(total / weight_sum)
+        } else {
+            None
+        }
+    }
+
+    fn weighted_scores(&self) -> Vec<(String, f64)> {
+        self.students
+            .values()
+            .filter_map(|s| self.weighted_score(s).map(|score| (s.name.clone(), score)))
+            .collect()
+    }
+
+    fn stats(&self) -> Option<Stats> {
+        let scores: Vec<f64> = self.weighted_scores().iter().map(|(_, s)| *s).collect();
+        Stats::from_values(&scores)
+    }
+
+    // IQR rule; with fewer than 4 students the quartiles mean nothing
+    fn outliers(&self) -> Vec<String> {
+        let scores = self.weighted_scores();
+        if scores.len() < 4 {
+            return Vec::new();
+        }
+
+        let values: Vec<f64> = scores.iter().map(|(_, s)| *s).collect();
+        let q1 = percentile(&values, 25.0).unwrap();
+        let q3 = percentile(&values, 75.0).unwrap();
+        let fence = 1.5 * (q3 - q1);
+
+        scores
+            .into_iter()
+            .filter(|(_, s)| *s < q1 - fence || *s > q3 + fence)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    // Highest score first; ties share a rank and are ordered by name.
+    // Scores that only differ by float rounding (0.1 + 0.2 vs 0.3) count as tied.
+    fn results(&self) -> Vec<StudentResult> {
+        let rank_key = |score: f64| (score * 1e6).round() as i64;
+        let mut scores = self.weighted_scores();
+        let class_mean = match self.stats() {
+            Some(stats) => stats.mean,
+            None => return Vec::new(),
+        };
+        let outliers = self.outliers();
+
+        scores.sort_by(|a, b| {
+            rank_key(b.1)
+                .cmp(&rank_key(a.1))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+
+        let mut results: Vec<StudentResult> = Vec::with_capacity(scores.len());
+        for (position, (name, weighted)) in scores.iter().enumerate() {
+            let rank = match results.last() {
+                Some(prev) if rank_key(prev.weighted) == rank_key(*weighted) => prev.rank,
+                _ => position + 1,
+            };
+            let curved = self.curve.apply(*weighted, class_mean);
+            results.push(StudentResult {
+                name: name.clone(),
+                weighted: *weighted,
+                curved,
+                letter: self.scale.letter(curved),
+                rank,
+                tied: false,
+                outlier: outliers.contains(name),
+            });
+        }
+
+        // Second pass: mark everyone who shares a rank with someone else
+        for i in 0..results.len() {
+            let rank = results[i].rank;
+            results[i].tied = results.iter().filter(|r| r.rank == rank).count() > 1;
+        }
+
+        results
+    }
+
+    fn report(&self) -> String {
+        let results = self.results();
+        if results.is_empty() {
+            return "Gradebook report: no students\n".to_string();
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "Gradebook report ({} students, curve: {:?})\n",
+            results.len(),
+            self.curve
+        ));
+
+        for result in &results {
+            let student = &self.students[&result.name];
+            let breakdown: Vec<String> = self
+                .weights
+                .keys()
+                .map(|category| match student.category_percent(category) {
+                    Some(p) => format!("{} {:.1}%", category, p),
+                    None => format!("{} -", category),
+                })
+                .collect();
+
+            out.push_str(&format!(
+                "  #{:<2}{} {:<8} weighted {:>5.1}  curved {:>5.1}  {}  [{}] ({} graded){}\n",
+                result.rank,
+                if result.tied { "=" } else { " " },
+                result.name,
+                result.weighted,
+                result.curved,
+                result.letter,
+                breakdown.join(", "),
+                student.assessments.len(),
+                if result.outlier {
+                    "  ⚠️ outlier"
+                } else {
+                    ""
+                },
+            ));
+        }
+
+        if let Some(stats) = self.stats() {
+            let values: Vec<f64> = results.iter().map(|r| r.weighted).collect();
+            out.push_str(&format!(
+                "  mean {:.1}, median {:.1}, std dev {:.1}, p25 {:.1}, p75 {:.1}, p90 {:.1}\n",
+                stats.mean,
+                stats.median,
+                stats.std_dev,
+                percentile(&values, 25.0).unwrap(),
+                percentile(&values, 75.0).unwrap(),
+                percentile(&values, 90.0).unwrap(),
+            ));
+        }
+
+        out
+    }
+}
+
+const CLASS_CSV: &str = "student,category,assessment,score,max
+Alice,homework,HW1,18,20
+Alice,homework,HW2,19,20
+Alice,exam,Midterm,82,100
+Alice,exam,Final,88,100
+Bob,homework,HW1,20,20
+Bob,homework,HW2,20,20
+Bob,exam,Midterm,90,100
+Bob,exam,Final,94,100
+Charlie,homework,HW1,15,20
+Charlie,homework,HW2,14,20
+Charlie,exam,Midterm,75,100
+Charlie,exam,Final,80,100
+Diana,homework,HW1,20,20
+Diana,homework,HW2,19,20
+Diana,exam,Midterm,97,100
+Diana,exam,Final,98,100
+Eve,homework,HW1,16,20
+Eve,homework,HW2,17,20
+Eve,exam,Midterm,79,100
+Eve,exam,Final,84,100
+Frank,homework,HW1,4,20
+Frank,exam,Midterm,22,100
+Frank,exam,Final,30,100
+";
+
+fn approx(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn main() {
+    println!("📚 === GRADEBOOK ANALYTICS ===");
+
+    // ========================================================================
+    // 1. LOADING FROM CSV
+    // ========================================================================
+    println!("\n📥 === LOADING FROM CSV ===");
+
+    let mut book = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)]).unwrap();
+    let rows = book.load_csv(CLASS_CSV).expect("class CSV should load");
+    println!(
+        "Loaded {} assessments for {} students",
+        rows,
+        book.students.len()
+    );
+    assert_eq!(rows, 23);
+    assert_eq!(book.students.len(), 6);
+
+    // Weighted score: homework 92.5% * 0.3 + exam 85% * 0.7 = 87.25
+    let alice = book.weighted_score(&book.students["Alice"]).unwrap();
+    let first = &book.students["Alice"].assessments[0];
+    println!(
+        "Alice's first row: {} {} {}/{}",
+        first.category, first.name, first.score, first.max
+    );
+    println!("Alice weighted: {:.2}", alice);
+    assert!(approx(alice, 87.25));
+
+    // Loading a file goes through the same parser
+    let path = std::env::temp_dir().join("gradebook_demo.csv");
+    fs::write(&path, CLASS_CSV).unwrap();
+    let mut from_file = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)]).unwrap();
+    let file_rows = from_file.load_csv_file(path.to_str().unwrap()).unwrap();
+    fs::remove_file(&path).ok();
+    println!("Loaded the same {} rows from {}", file_rows, path.display());
+    assert_eq!(file_rows, rows);
+
+    // Bad input is reported with its line number
+    let errors = [
+        "name,grade\nAlice,85\n",
+        "student,category,assessment,score,max\nAlice,exam,Final,88\n",
+        "student,category,assessment,score,max\nAlice,exam,Final,eighty,100\n",
+        "student,category,assessment,score,max\nAlice,exam,Final,120,100\n",
+        "student,category,assessment,score,max\nAlice,quiz,Q1,5,10\n",
+    ];
+    for csv in errors {
+        let mut scratch = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)]).unwrap();
+        println!("  ❌ {}", scratch.load_csv(csv).unwrap_err());
+    }
+    let mut scratch = Gradebook::new(&[("exam", 1.0)]).unwrap();
+    assert_eq!(
+        scratch.load_csv(errors[4]),
+        Err(GradebookError::UnknownCategory {
+            line: 2,
+            category: "quiz".to_string()
+        })
+    );
+
+    // Quoted fields may contain commas; an unclosed quote is reported
+    let mut quoted = Gradebook::new(&[("exam", 1.0)]).unwrap();
+    quoted
+        .load_csv(
+            "student,category,assessment,score,max\n\
+             \"Smith, Jane\",exam,\"Final \"\"B\"\"\", 80 ,100\n",
+        )
+        .unwrap();
+    let jane = &quoted.students["Smith, Jane"];
+    assert_eq!(jane.assessments[0].name, "Final \"B\"");
+    assert_eq!(jane.assessments[0].score, 80.0);
+    assert_eq!(
+        quoted.load_csv("student,category,assessment,score,max\n\"Smith,exam,E,1,1\n"),
+        Err(GradebookError::MalformedQuote { line: 2 })
+    );
+    assert_eq!(
+        split_csv_line("\"a\" ,b"),
+        Some(vec!["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(split_csv_line("\"a\"b,c"), None);
+    assert_eq!(
+        quoted.load_csv("student,category,assessment,score,max\n\"Jane\"x,exam,E,1,1\n"),
+        Err(GradebookError::MalformedQuote { line: 2 })
+    );
+
+    // Weights are checked up front
+    for weights in [
+        &[("exam", -0.5)][..],
+        &[("exam", f64::NAN)],
+        &[("homework", 0.0), ("exam", 0.0)],
+        &[],
+    ] {
+        println!("  ❌ {}", Gradebook::new(weights).err().unwrap());
+    }
+    assert_eq!(
+        Gradebook::new(&[("exam", -0.5)]).err(),
+        Some(GradebookError::InvalidWeight {
+            category: "exam".to_string(),
+            weight: -0.5
+        })
+    );
+    assert_eq!(Gradebook::new(&[]).err(), Some(GradebookError::NoWeight));
+
+    // ========================================================================
+    // 2. CLASS STATISTICS
+    // ========================================================================
+    println!("\n📊 === CLASS STATISTICS ===");
+
+    let stats = book.stats().unwrap();
+    println!("{:?}", stats);
+    assert_eq!(stats.count, 6);
+    assert!(stats.min < stats.median && stats.median < stats.max);
+
+    // Known values for the statistics helpers
+    let sample = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    let sample_stats = Stats::from_values(&sample).unwrap();
+    assert!(approx(sample_stats.mean, 5.0));
+    assert!(approx(sample_stats.median, 4.5));
+    assert!(approx(sample_stats.std_dev, 2.0));
+    assert_eq!(percentile(&[10.0, 20.0, 30.0, 40.0], 50.0), Some(25.0));
+    assert_eq!(percentile(&[10.0, 20.0, 30.0, 40.0], 100.0), Some(40.0));
+    assert_eq!(percentile(&[7.0], 90.0), Some(7.0));
+    println!("Sample {:?} -> {:?}", sample, sample_stats);
+
+    // ========================================================================
+    // 3. OUTLIERS AND RANKS
+    // ========================================================================
+    println!("\n🔍 === OUTLIERS AND RANKS ===");
+
+    let outliers = book.outliers();
+    println!("Outliers (IQR rule): {:?}", outliers);
+    assert_eq!(outliers, vec!["Frank".to_string()]);
+
+    // A student with no homework at all is graded on exams alone
+    let mut partial = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)]).unwrap();
+    partial
+        .load_csv("student,category,assessment,score,max\nGus,exam,Final,70,100\n")
+        .unwrap();
+    let gus = partial.weighted_score(&partial.students["Gus"]).unwrap();
+    println!("Gus (exam only) weighted: {:.2}", gus);
+    assert!(approx(gus, 70.0));
+
+    let results = book.results();
+    let order: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+    println!("Ranking: {:?}", order);
+    assert_eq!(order[0], "Diana");
+    assert_eq!(order[order.len() - 1], "Frank");
+
+    // Ties: same score -> same rank, next rank skips
+    let mut tie_book = Gradebook::new(&[("exam", 1.0)]).unwrap();
+    tie_book
+        .load_csv(
+            "student,category,assessment,score,max\n\
+             Zed,exam,E,90,100\nAmy,exam,E,90,100\nBo,exam,E,70,100\n",
+        )
+        .unwrap();
+    let tie_results = tie_book.results();
+    let ranks: Vec<(&str, usize, bool)> = tie_results
+        .iter()
+        .map(|r| (r.name.as_str(), r.rank, r.tied))
+        .collect();
+    println!("Tie handling: {:?}", ranks);
+    assert_eq!(
+        ranks,
+        vec![("Amy", 1, true), ("Zed", 1, true), ("Bo", 3, false)]
+    );
+
+    // 0.1 + 0.2 out of 1 and 30 out of 100 are the same score, despite float rounding
+    let mut float_tie = Gradebook::new(&[("exam", 1.0)]).unwrap();
+    float_tie
+        .load_csv(
+            "student,category,assessment,score,max\n\
+             Cy,exam,A,0.1,0.5\nCy,exam,B,0.2,0.5\nAl,exam,E,30,100\n",
+        )
+        .unwrap();
+    let float_ranks: Vec<(String, usize)> = float_tie
+        .results()
+        .into_iter()
+        .map(|r| (r.name, r.rank))
+        .collect();
+    assert_eq!(
+        float_ranks,
+        vec![("Al".to_string(), 1), ("Cy".to_string(), 1)]
+    );
+
+    // ========================================================================
+    // 4. CURVES AND LETTER GRADES
+    // ========================================================================
+    println!("\n📈 === CURVES AND LETTER GRADES ===");
+
+    assert_eq!(Curve::Flat(5.0).apply(97.0, 0.0), 100.0); // Capped at 100
+    assert_eq!(Curve::SquareRoot.apply(64.0, 0.0), 80.0);
+    assert_eq!(Curve::TargetMean(80.0).apply(70.0, 75.0), 75.0);
+
+    for curve in [
+        Curve::None,
+        Curve::Flat(5.0),
+        Curve::TargetMean(85.0),
+        Curve::SquareRoot,
+    ] {
+        let mut curved_book = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)])
+            .unwrap()
+            .with_curve(curve);
+        curved_book.load_csv(CLASS_CSV).unwrap();
+        let letters: Vec<String> = curved_book
+            .results()
+            .iter()
+            .map(|r| format!("{}={}", r.name, r.letter))
+            .collect();
+        println!("  {:<18} {}", format!("{:?}", curve), letters.join(" "));
+    }
+
+    // A stricter custom scale
+    let strict = GradingScale {
+        cutoffs: vec![(93.0, "A"), (85.0, "B"), (77.0, "C")],
+        fallback: "F",
+    };
+    assert_eq!(strict.letter(92.9), "B");
+    assert_eq!(GradingScale::standard().letter(92.9), "A");
+
+    // ========================================================================
+    // 5. PER-STUDENT REPORT
+    // ========================================================================
+    println!("\n📝 === PER-STUDENT REPORT ===");
+
+    let mut report_book = Gradebook::new(&[("homework", 0.3), ("exam", 0.7)])
+        .unwrap()
+        .with_curve(Curve::Flat(3.0))
+        .with_scale(strict);
+    report_book.load_csv(CLASS_CSV).unwrap();
+    print!("{}", report_book.report());
+
+    // Empty class: no statistics, no ranks, no panic
+    let empty = Gradebook::new(&[("exam", 1.0)]).unwrap();
+    assert!(empty.stats().is_none());
+    assert!(empty.results().is_empty());
+    assert!(empty.outliers().is_empty());
+    print!("{}", empty.report());
+
+    println!("\n📋 === GRADEBOOK SUMMARY ===");
+    println!("✅ CSV rows are validated and errors name the line");
+    println!("✅ Category weights are re-normalized when a category is missing");
+    println!("✅ mean, median, std dev and percentiles use one sorted copy");
+    println!("✅ Curves are pluggable and clamp to 0..=100");
+    println!("✅ Ties share a rank; empty classes return None instead of NaN");
+}
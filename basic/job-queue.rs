@@ -0,0 +1,666 @@
+/*
+JOB QUEUE - Priorities, Retries and a Worker Pool
+=================================================
+
+WHAT IS THIS?
+- while-let.rs `TaskQueue` walks a Vec of results that were decided in advance:
+  nothing actually runs, and a failed task just stays failed
+- A real job queue runs work: each job is a closure, higher priorities go first,
+  a failed job is retried after a growing delay, and a job that keeps failing is
+  parked in a "dead-letter" list for a human to look at
+- Several worker threads pull jobs at once, and shutdown waits for in-flight work
+
+REAL-WORLD ANALOGY:
+- A print shop: urgent orders jump the line (priority), a jammed printer
+  means "try again in 1 minute, then 2, then 4" (exponential backoff),
+  and an order that jams three times goes in the "problems" tray (dead letter)
+- At closing time the staff finish what's in the queue before going home (graceful shutdown)
+
+JOB LIFECYCLE:
+┌──────────┐  picked by worker   ┌─────────┐   Ok    ┌───────────┐
+│  ready   │ ──────────────────► │ running │ ──────► │ succeeded │
+└──────────┘                     └─────────┘         └───────────┘
+     ▲                             │ Err / panic
+     │ backoff elapsed             ▼
+┌──────────┐   attempts left   ┌────────┐  no attempts left  ┌─────────────┐
+│ delayed  │ ◄──────────────── │ failed │ ─────────────────► │ dead letter │
+└──────────┘                   └────────┘                    └─────────────┘
+
+KEY CONCEPTS:
+- Jobs are `Box<dyn FnMut(u32) -> Result<String, String> + Send>`; FnMut so a retry can run it again
+- One Mutex guards the queue state; a Condvar wakes idle workers when work arrives
+- Delayed retries wake workers with `wait_timeout` - no extra timer thread needed
+- A panicking job is caught and treated as a failure, so it can't kill a worker
+*/
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// JOBS
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Priority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+type JobFn = Box<dyn FnMut(u32) -> Result<String, String> + Send>;
+
+struct Job {
+    id: u64,
+    name: String,
+    priority: Priority,
+    attempts: u32, // Attempts made so far
+    max_attempts: u32,
+    run: JobFn,
+}
+
+// Heap order: highest priority first, then lowest sequence number (FIFO)
+struct Ready {
+    seq: u64,
+    job: Job,
+}
+
+impl Ord for Ready {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.job
+            .priority
+            .cmp(&other.job.priority)
+            .then(Reverse(self.seq).cmp(&Reverse(other.seq)))
+    }
+}
+
+impl PartialOrd for Ready {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ready {
+    fn eq(&self, other: &Self) -> bool {
+        self.seq == other.seq
+    }
+}
+
+impl Eq for Ready {}
+
+// Earliest due time first
+struct Delayed {
+    due: Instant,
+    job: Job,
+}
+
+impl Ord for Delayed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.due.cmp(&self.due)
+    }
+}
+
+impl PartialOrd for Delayed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Delayed {
+    fn eq(&self, other: &Self) -> bool {
+        self.due == other.due
+    }
+}
+
+impl Eq for Delayed {}
+
+// ============================================================================
+// REPORTING
+// ============================================================================
+
+#[derive(Debug, Clone)]
+struct Completed {
+    id: u64,
+    name: String,
+    attempts: u32,
+    worker: usize,
+    output: String,
+}
+
+#[derive(Debug, Clone)]
+struct DeadLetter {
+    id: u64,
+    name: String,
+    attempts: u32,
+    last_error: String,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Report {
+    succeeded: usize,
+    failed: usize,
+    retries: usize,
+    completed: Vec<Completed>, // In completion order
+    dead_letters: Vec<DeadLetter>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "succeeded: {}, failed: {}, retries: {}",
+            self.succeeded, self.failed, self.retries
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct QueueClosed;
+
+impl fmt::Display for QueueClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "queue is shutting down and no longer accepts jobs")
+    }
+}
+
+// ============================================================================
+// THE QUEUE
+// ============================================================================
+
+#[derive(Debug, Clone, Copy)]
+struct QueueConfig {
+    workers: usize,
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        QueueConfig {
+            workers: 4,
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl QueueConfig {
+    // base, 2*base, 4*base, ... capped at max_backoff
+    fn backoff(&self, failed_attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failed_attempts.saturating_sub(1));
+        self.base_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    ready: BinaryHeap<Ready>,
+    delayed: BinaryHeap<Delayed>,
+    running: usize,
+    closed: bool,
+    next_id: u64,
+    next_seq: u64,
+    report: Report,
+}
+
+impl State {
+    fn enqueue(&mut self, job: Job) {
+        self.next_seq += 1;
+        self.ready.push(Ready {
+            seq: self.next_seq,
+            job,
+        });
+    }
+
+    // Move retries whose backoff has elapsed into the ready heap
+    fn promote_due(&mut self, now: Instant) {
+        while self.delayed.peek().is_some_and(|d| d.due <= now) {
+            let job = self.delayed.pop().expect("peeked").job;
+            self.enqueue(job);
+        }
+    }
+
+    fn is_drained(&self) -> bool {
+        self.ready.is_empty() && self.delayed.is_empty() && self.running == 0
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    wakeup: Condvar,
+    config: QueueConfig,
+}
+
+struct JobQueue {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl JobQueue {
+    // Jobs can be submitted before start(), e.g. to demonstrate priority order
+    fn new(config: QueueConfig) -> Self {
+        JobQueue {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State::default()),
+                wakeup: Condvar::new(),
+                config,
+            }),
+            workers: Vec::new(),
+        }
+    }
+
+    fn start(&mut self) {
+        // With no workers nothing would ever run, and shutdown() would drop every job
+        assert!(self.shared.config.workers > 0, "need at least one worker");
+        for worker_id in 0..self.shared.config.workers {
+            let shared = Arc::clone(&self.shared);
+            self.workers
+                .push(thread::spawn(move || worker_loop(worker_id, &shared)));
+        }
+    }
+
+    fn submit<F>(&self, name: &str, priority: Priority, job: F) -> Result<u64, QueueClosed>
+    where
+        F: FnMut(u32) -> Result<String, String> + Send + 'static,
+    {
+        self.submit_with_attempts(name, priority, self.shared.config.max_attempts, job)
+    }
+
+    fn submit_with_attempts<F>(
+        &self,
+        name: &str,
+        priority: Priority,
+        max_attempts: u32,
+        job: F,
+    ) -> Result<u64, QueueClosed>
+    where
+        F: FnMut(u32) -> Result<String, String> + Send + 'static,
+    {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.closed {
+            return Err(QueueClosed);
+        }
+        state.next_id += 1;
+        let id = state.next_id;
+        state.enqueue(Job {
+            id,
+            name: name.to_string(),
+            priority,
+            attempts: 0,
+            max_attempts: max_attempts.max(1),
+            run: Box::new(job),
+        });
+        self.shared.wakeup.notify_one();
+        Ok(id)
+    }
+
+    // Stop accepting jobs, let everything queued (and its retries) finish, join workers.
+    // A queue that was never started is started here, so its jobs run instead of vanishing.
+    fn shutdown(mut self) -> Report {
+        if self.workers.is_empty() && !self.shared.state.lock().unwrap().closed {
+            self.start();
+        }
+        assert!(
+            self.close_and_join(),
+            "worker thread panicked outside a job"
+        );
+        std::mem::take(&mut self.shared.state.lock().unwrap().report)
+    }
+
+    // false if a worker died outside a job; Drop ignores that rather than panic twice
+    fn close_and_join(&mut self) -> bool {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.closed = true;
+        }
+        self.shared.wakeup.notify_all();
+        let mut clean = true;
+        for worker in self.workers.drain(..) {
+            clean &= worker.join().is_ok(); // Join every worker, even after a failure
+        }
+        clean
+    }
+}
+
+// Dropping a started queue without shutdown() still drains it and joins the workers,
+// so no thread outlives the queue; only the report is lost
+impl Drop for JobQueue {
+    fn drop(&mut self) {
+        self.close_and_join();
+    }
+}
+
+fn worker_loop(worker_id: usize, shared: &Shared) {
+    loop {
+        // Wait for a ready job, a due retry, or the end
+        let mut job = {
+            let mut state = shared.state.lock().unwrap();
+            loop {
+                state.promote_due(Instant::now());
+                if let Some(ready) = state.ready.pop() {
+                    state.running += 1;
+                    break ready.job;
+                }
+                if state.closed && state.is_drained() {
+                    shared.wakeup.notify_all(); // Let the other workers see it too
+                    return;
+                }
+                state = match state.delayed.peek().map(|d| d.due) {
+                    Some(due) => {
+                        let timeout = due.saturating_duration_since(Instant::now());
+                        shared.wakeup.wait_timeout(state, timeout).unwrap().0
+                    }
+                    None => shared.wakeup.wait(state).unwrap(),
+                };
+            }
+        };
+
+        // Run without holding the lock; a panic becomes an ordinary failure
+        job.attempts += 1;
+        let attempt = job.attempts;
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| (job.run)(attempt)))
+            .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(&payload))));
+
+        let mut state = shared.state.lock().unwrap();
+        state.running -= 1;
+        match outcome {
+            Ok(output) => {
+                state.report.succeeded += 1;
+                state.report.completed.push(Completed {
+                    id: job.id,
+                    name: job.name,
+                    attempts: job.attempts,
+                    worker: worker_id,
+                    output,
+                });
+            }
+            Err(_) if job.attempts < job.max_attempts => {
+                state.report.retries += 1;
+                let due = Instant::now() + shared.config.backoff(job.attempts);
+                state.delayed.push(Delayed { due, job });
+            }
+            Err(error) => {
+                state.report.failed += 1;
+                state.report.dead_letters.push(DeadLetter {
+                    id: job.id,
+                    name: job.name,
+                    attempts: job.attempts,
+                    last_error: error,
+                });
+            }
+        }
+        // Something changed: a retry to schedule, or possibly the last job finished
+        shared.wakeup.notify_all();
+    }
+}
+
+fn panic_message(payload: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn main() {
+    println!("🏭 === JOB QUEUE ===");
+
+    let quick = QueueConfig {
+        workers: 1,
+        max_attempts: 3,
+        base_backoff: Duration::from_millis(2),
+        max_backoff: Duration::from_millis(50),
+    };
+
+    // ========================================================================
+    // 1. THE while-let.rs TASKS, ACTUALLY RUNNING
+    // ========================================================================
+    println!("\n📋 === TASKS FROM while-let.rs ===");
+
+    let mut queue = JobQueue::new(quick);
+    let tasks: [(&str, Result<&str, &str>); 5] = [
+        ("Task 1", Ok("Success")),
+        ("Task 2", Ok("Success")),
+        ("Task 3", Err("Failed - Network error")),
+        ("Task 4", Ok("Success")),
+        ("Task 5", Err("Failed - Timeout")),
+    ];
+    for (name, outcome) in tasks {
+        queue
+            .submit(name, Priority::Normal, move |_attempt| {
+                outcome.map(str::to_string).map_err(str::to_string)
+            })
+            .unwrap();
+    }
+    queue.start();
+    let report = queue.shutdown();
+    for done in &report.completed {
+        println!("  ✅ #{} {}: {}", done.id, done.name, done.output);
+    }
+    for dead in &report.dead_letters {
+        println!(
+            "  ❌ {}: {} (after {} attempts)",
+            dead.name, dead.last_error, dead.attempts
+        );
+    }
+    println!("  {}", report);
+    assert_eq!((report.succeeded, report.failed, report.retries), (3, 2, 4));
+
+    // ========================================================================
+    // 2. PRIORITIES
+    // ========================================================================
+    println!("\n🥇 === PRIORITY ORDER (1 worker) ===");
+
+    let mut queue = JobQueue::new(quick);
+    let submissions = [
+        ("backup", Priority::Low),
+        ("email #1", Priority::Normal),
+        ("payment", Priority::Critical),
+        ("email #2", Priority::Normal),
+        ("thumbnail", Priority::High),
+    ];
+    for (name, priority) in submissions {
+        queue.submit(name, priority, |_| Ok(String::new())).unwrap();
+    }
+    queue.start();
+    let order: Vec<String> = queue
+        .shutdown()
+        .completed
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    println!("  ran in order: {:?}", order);
+    assert_eq!(
+        order,
+        ["payment", "thumbnail", "email #1", "email #2", "backup"]
+    );
+
+    // ========================================================================
+    // 3. RETRY WITH EXPONENTIAL BACKOFF
+    // ========================================================================
+    println!("\n⏳ === RETRY WITH BACKOFF ===");
+
+    let config = QueueConfig {
+        workers: 2,
+        max_attempts: 4,
+        base_backoff: Duration::from_millis(20),
+        max_backoff: Duration::from_millis(500),
+    };
+    println!(
+        "  backoff schedule: {:?}",
+        (1..=5).map(|n| config.backoff(n)).collect::<Vec<_>>()
+    );
+    assert_eq!(config.backoff(3), Duration::from_millis(80));
+    assert_eq!(config.backoff(10), Duration::from_millis(500)); // capped
+
+    let attempt_times = Arc::new(Mutex::new(Vec::new()));
+    let times = Arc::clone(&attempt_times);
+    let mut queue = JobQueue::new(config);
+    queue.start();
+    queue
+        .submit("flaky upload", Priority::High, move |attempt| {
+            times.lock().unwrap().push(Instant::now());
+            if attempt < 3 {
+                Err(format!("connection reset (attempt {})", attempt))
+            } else {
+                Ok(format!("uploaded on attempt {}", attempt))
+            }
+        })
+        .unwrap();
+    let report = queue.shutdown();
+    let times = attempt_times.lock().unwrap();
+    let gaps: Vec<Duration> = times.windows(2).map(|w| w[1] - w[0]).collect();
+    println!(
+        "  {} -> gaps between attempts: {:?}",
+        report.completed[0].output, gaps
+    );
+    assert_eq!(report.completed[0].attempts, 3);
+    assert!(gaps[0] >= Duration::from_millis(20) && gaps[1] >= Duration::from_millis(40));
+
+    // ========================================================================
+    // 4. WORKER POOL
+    // ========================================================================
+    println!("\n👷 === WORKER POOL (4 workers) ===");
+
+    let mut queue = JobQueue::new(QueueConfig {
+        workers: 4,
+        ..QueueConfig::default()
+    });
+    queue.start();
+    let start = Instant::now();
+    for i in 0..40 {
+        queue
+            .submit(&format!("resize #{}", i), Priority::Normal, |_| {
+                thread::sleep(Duration::from_millis(10));
+                Ok("done".to_string())
+            })
+            .unwrap();
+    }
+    let report = queue.shutdown();
+    let elapsed = start.elapsed();
+    let mut per_worker = [0; 4];
+    for done in &report.completed {
+        per_worker[done.worker] += 1;
+    }
+    println!(
+        "  40 x 10ms jobs in {:?} (sequential: 400ms); jobs per worker: {:?}",
+        elapsed, per_worker
+    );
+    assert_eq!(report.succeeded, 40);
+    assert!(per_worker.iter().filter(|&&n| n > 0).count() > 1);
+
+    // ========================================================================
+    // 5. DEAD LETTERS, PANICS AND GRACEFUL SHUTDOWN
+    // ========================================================================
+    println!("\n🪦 === DEAD LETTERS AND SHUTDOWN ===");
+
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {})); // Keep the demo output clean; the queue reports it
+
+    let mut queue = JobQueue::new(quick);
+    queue.start();
+    queue
+        .submit_with_attempts("parse report", Priority::Normal, 2, |_| {
+            let numbers: Vec<u32> = Vec::new();
+            Ok(format!("first = {}", numbers[0])) // Index out of bounds: panics
+        })
+        .unwrap();
+    queue
+        .submit("send invoice", Priority::Normal, |attempt| {
+            Err(format!(
+                "SMTP 550 mailbox unavailable (attempt {})",
+                attempt
+            ))
+        })
+        .unwrap();
+    for i in 0..5 {
+        queue
+            .submit(&format!("cleanup #{}", i), Priority::Low, |_| {
+                thread::sleep(Duration::from_millis(5));
+                Ok("ok".to_string())
+            })
+            .unwrap();
+    }
+
+    // Shutdown right away: queued jobs and pending retries still run to the end
+    let shared = Arc::clone(&queue.shared);
+    let report = queue.shutdown();
+    panic::set_hook(default_hook);
+
+    for dead in &report.dead_letters {
+        println!(
+            "  🪦 #{} {} after {} attempts: {}",
+            dead.id, dead.name, dead.attempts, dead.last_error
+        );
+    }
+    println!("  {}", report);
+    assert_eq!(report.succeeded, 5);
+    assert_eq!(report.failed, 2);
+    assert_eq!(report.retries, 1 + 2); // panic job: 2 attempts, invoice: 3 attempts
+    assert!(
+        report
+            .dead_letters
+            .iter()
+            .any(|d| d.last_error.contains("panicked"))
+    );
+
+    // Never started: shutdown() starts the workers so queued jobs still run
+    let idle = JobQueue::new(quick);
+    for i in 0..3 {
+        idle.submit(&format!("queued #{}", i), Priority::Normal, |_| {
+            Ok("ok".to_string())
+        })
+        .unwrap();
+    }
+    let report = idle.shutdown();
+    println!("  shutdown before start: {}", report);
+    assert_eq!(report.succeeded, 3);
+
+    // Dropping without shutdown() still finishes the work and joins the workers
+    let finished = Arc::new(Mutex::new(0));
+    {
+        let mut dropped = JobQueue::new(quick);
+        dropped.start();
+        for _ in 0..4 {
+            let finished = Arc::clone(&finished);
+            dropped
+                .submit("counted", Priority::Normal, move |_| {
+                    thread::sleep(Duration::from_millis(2));
+                    *finished.lock().unwrap() += 1;
+                    Ok(String::new())
+                })
+                .unwrap();
+        }
+    }
+    assert_eq!(*finished.lock().unwrap(), 4);
+    println!("  dropped queue ran all 4 jobs");
+
+    // A closed queue refuses new work
+    let late = JobQueue {
+        shared,
+        workers: Vec::new(),
+    };
+    assert_eq!(
+        late.submit("too late", Priority::High, |_| Ok(String::new())),
+        Err(QueueClosed)
+    );
+    println!("  submit after shutdown: {}", QueueClosed);
+
+    println!("\n📋 === JOB QUEUE SUMMARY ===");
+    println!("✅ Jobs are boxed FnMut closures, so a retry simply calls them again");
+    println!("✅ A BinaryHeap runs higher priorities first, FIFO within a priority");
+    println!("✅ Failures retry after base * 2^n, then land in the dead-letter list");
+    println!("✅ Worker threads share one Mutex + Condvar; panics are caught per job");
+    println!("✅ shutdown() drains queued work and retries before joining the workers");
+    println!("✅ Drop closes and joins too; shutdown() before start() still runs the jobs");
+}
@@ -0,0 +1,850 @@
+/*
+JSON - An RFC 8259 Parser and Serializer for JsonValue
+======================================================
+
+WHAT IS THIS?
+- if-let.rs declares `enum JsonValue { String, Number, Boolean, Array, Object, Null }`
+  but the only way to get one is to build it by hand
+- Its Object is a HashMap, so {"name":..,"age":..} can come back as {"age":..,"name":..}
+- This file adds the missing pieces:
+  parse text into JsonValue (with line:column errors), print it back compact or pretty,
+  and find things inside it with JSON Pointer ("/tags/0")
+
+REAL-WORLD ANALOGY:
+- Parsing is a customs officer checking every item against a strict list,
+  and writing down exactly which suitcase and pocket the problem was in
+- JSON Pointer is a street address: country / city / street / house number
+
+GRAMMAR CHEAT SHEET (RFC 8259):
+┌─────────────┬──────────────────────────────────────┬───────────────────────────┐
+│ Value       │ Valid                                │ Invalid                   │
+├─────────────┼──────────────────────────────────────┼───────────────────────────┤
+│ number      │ 0  -1  3.14  1e5  -0.5E-3            │ 01  1.  .5  +1  NaN       │
+│ string      │ "a\"b"  "\u00e9"  "\ud83e\udd80"     │ 'a'  "tab⇥"  "\x41"       │
+│ array       │ []  [1, "two", null]                 │ [1,]  [1 2]               │
+│ object      │ {}  {"a": 1, "b": [true]}            │ {a: 1}  {"a" 1}  {"a":1,} │
+│ literal     │ true  false  null                    │ True  nul  undefined      │
+└─────────────┴──────────────────────────────────────┴───────────────────────────┘
+
+KEY CONCEPTS:
+- Recursive descent: one function per grammar rule, each calling the others
+- Characters outside the Basic Multilingual Plane (like 🦀) are escaped as
+  TWO \u escapes - a surrogate pair - that must be joined back into one char
+- A depth limit stops "[[[[[[..." from overflowing the stack
+- Objects are a Vec of (key, value) pairs, so key order survives a round trip
+*/
+
+use std::fmt;
+
+// ============================================================================
+// VALUE TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    String(String),
+    Number(f64),
+    Boolean(bool),
+    Array(Vec<JsonValue>),
+    Object(JsonObject),
+    Null,
+}
+
+// Insertion-ordered map. Lookups are linear, which is fine for typical JSON objects.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct JsonObject {
+    entries: Vec<(String, JsonValue)>,
+}
+
+impl JsonObject {
+    fn new() -> Self {
+        JsonObject::default()
+    }
+
+    // Replacing an existing key keeps its original position
+    fn insert(&mut self, key: impl Into<String>, value: JsonValue) -> Option<JsonValue> {
+        let key = key.into();
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, slot)) => Some(std::mem::replace(slot, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(k, _)| k.as_str())
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+impl JsonValue {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(obj) => obj.get(key),
+            _ => None,
+        }
+    }
+
+    // RFC 6901: "" is the whole document, "/a/0" is key "a" then index 0,
+    // and "~1" / "~0" stand for "/" and "~" inside a key
+    fn pointer(&self, pointer: &str) -> Option<&JsonValue> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        let rest = pointer.strip_prefix('/')?;
+        rest.split('/').try_fold(self, |current, token| {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            match current {
+                JsonValue::Object(obj) => obj.get(&token),
+                JsonValue::Array(items) => {
+                    // No leading zeros, no signs: "01" and "+1" are not indexes
+                    let canonical = token == "0" || !token.starts_with('0');
+                    if canonical && !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit()) {
+                        items.get(token.parse::<usize>().ok()?)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+// ============================================================================
+// PARSER
+// ============================================================================
+
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ErrorKind {
+    UnexpectedEnd,
+    UnexpectedChar(char),
+    InvalidNumber,
+    NumberOutOfRange,
+    InvalidEscape(char),
+    InvalidUnicodeEscape,
+    LoneSurrogate(u32),
+    ControlCharacter(char),
+    TooDeep(usize),
+    TrailingCharacters,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ParseError {
+    line: usize,
+    col: usize,
+    kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.col)?;
+        match &self.kind {
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::NumberOutOfRange => write!(f, "number is too large to represent"),
+            ErrorKind::InvalidEscape(c) => write!(f, "invalid escape '\\{}'", c),
+            ErrorKind::InvalidUnicodeEscape => write!(f, "\\u must be followed by 4 hex digits"),
+            ErrorKind::LoneSurrogate(u) => write!(f, "unpaired surrogate \\u{:04x}", u),
+            ErrorKind::ControlCharacter(c) => {
+                write!(f, "control character {:?} must be escaped in a string", c)
+            }
+            ErrorKind::TooDeep(limit) => write!(f, "nesting deeper than {} levels", limit),
+            ErrorKind::TrailingCharacters => write!(f, "unexpected text after the JSON value"),
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize, // Byte offset into text
+    line: usize,
+    col: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str, max_depth: usize) -> Self {
+        Parser {
+            text,
+            pos: 0,
+            line: 1,
+            col: 1,
+            depth: 0,
+            max_depth,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(c)
+    }
+
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            col: self.col,
+            kind,
+        }
+    }
+
+    // Error about the character under the cursor (or the end of input)
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ErrorKind::UnexpectedChar(c)),
+            None => self.error(ErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<JsonValue, ParseError> {
+        self.skip_whitespace();
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        if self.pos < self.text.len() {
+            return Err(self.error(ErrorKind::TrailingCharacters));
+        }
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, ParseError> {
+        match self.peek() {
+            Some('{') => self.nested(Self::parse_object),
+            Some('[') => self.nested(Self::parse_array),
+            Some('"') => self.parse_string().map(JsonValue::String),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some('t') => self.parse_literal("true", JsonValue::Boolean(true)),
+            Some('f') => self.parse_literal("false", JsonValue::Boolean(false)),
+            Some('n') => self.parse_literal("null", JsonValue::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, ParseError>,
+    ) -> Result<JsonValue, ParseError> {
+        if self.depth == self.max_depth {
+            return Err(self.error(ErrorKind::TooDeep(self.max_depth)));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, ParseError> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => {
+                    self.bump();
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    // Duplicate keys: RFC 8259 leaves it open; here the last value wins
+    fn parse_object(&mut self) -> Result<JsonValue, ParseError> {
+        self.expect('{')?;
+        let mut object = JsonObject::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(JsonValue::Object(object));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.unexpected());
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            self.skip_whitespace();
+            let value = self.parse_value()?;
+            object.insert(key, value);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => {
+                    self.bump();
+                    return Ok(JsonValue::Object(object));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            let (line, col) = (self.line, self.col);
+            let c = self
+                .bump()
+                .ok_or_else(|| self.error(ErrorKind::UnexpectedEnd))?;
+            match c {
+                '"' => return Ok(out),
+                '\\' => out.push(self.parse_escape(line, col)?),
+                c if (c as u32) < 0x20 => {
+                    return Err(ParseError {
+                        line,
+                        col,
+                        kind: ErrorKind::ControlCharacter(c),
+                    });
+                }
+                c => out.push(c),
+            }
+        }
+    }
+
+    // Called after the backslash; (line, col) is where the backslash was
+    fn parse_escape(&mut self, line: usize, col: usize) -> Result<char, ParseError> {
+        let at = |kind| ParseError { line, col, kind };
+        let c = self
+            .bump()
+            .ok_or_else(|| self.error(ErrorKind::UnexpectedEnd))?;
+        Ok(match c {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let first = self.parse_hex4()?;
+                match first {
+                    0xD800..=0xDBFF => {
+                        // High surrogate: a \uDC00-\uDFFF low surrogate must follow
+                        if !self.text[self.pos..].starts_with("\\u") {
+                            return Err(at(ErrorKind::LoneSurrogate(first)));
+                        }
+                        self.bump();
+                        self.bump();
+                        let second = self.parse_hex4()?;
+                        if !(0xDC00..=0xDFFF).contains(&second) {
+                            return Err(at(ErrorKind::LoneSurrogate(first)));
+                        }
+                        let combined = 0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00);
+                        char::from_u32(combined).expect("surrogate pair is a valid scalar")
+                    }
+                    0xDC00..=0xDFFF => return Err(at(ErrorKind::LoneSurrogate(first))),
+                    _ => char::from_u32(first).expect("non-surrogate BMP code point"),
+                }
+            }
+            other => return Err(at(ErrorKind::InvalidEscape(other))),
+        })
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, ParseError> {
+        let digits = self.text.get(self.pos..self.pos + 4);
+        match digits.filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit())) {
+            Some(d) => {
+                let value = u32::from_str_radix(d, 16).expect("checked hex digits");
+                for _ in 0..4 {
+                    self.bump();
+                }
+                Ok(value)
+            }
+            None => Err(self.error(ErrorKind::InvalidUnicodeEscape)),
+        }
+    }
+
+    // -? (0 | [1-9][0-9]*) (.[0-9]+)? ([eE][+-]?[0-9]+)?
+    fn parse_number(&mut self) -> Result<JsonValue, ParseError> {
+        let (start, line, col) = (self.pos, self.line, self.col);
+        let invalid = |kind| ParseError { line, col, kind };
+
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        match self.peek() {
+            Some('0') => {
+                self.bump();
+                if matches!(self.peek(), Some('0'..='9')) {
+                    return Err(invalid(ErrorKind::InvalidNumber)); // Leading zero
+                }
+            }
+            Some('1'..='9') => self.digits(),
+            _ => return Err(invalid(ErrorKind::InvalidNumber)),
+        }
+        if self.peek() == Some('.') {
+            self.bump();
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(invalid(ErrorKind::InvalidNumber));
+            }
+            self.digits();
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.bump();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.bump();
+            }
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(invalid(ErrorKind::InvalidNumber));
+            }
+            self.digits();
+        }
+
+        let n: f64 = self.text[start..self.pos]
+            .parse()
+            .map_err(|_| invalid(ErrorKind::InvalidNumber))?;
+        if n.is_infinite() {
+            return Err(invalid(ErrorKind::NumberOutOfRange));
+        }
+        Ok(JsonValue::Number(n))
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.bump();
+        }
+    }
+}
+
+impl JsonValue {
+    fn parse(text: &str) -> Result<JsonValue, ParseError> {
+        Self::parse_with_depth(text, DEFAULT_MAX_DEPTH)
+    }
+
+    fn parse_with_depth(text: &str, max_depth: usize) -> Result<JsonValue, ParseError> {
+        Parser::new(text, max_depth).parse_document()
+    }
+}
+
+// ============================================================================
+// SERIALIZERS
+// ============================================================================
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(out: &mut String, n: f64) {
+    if !n.is_finite() {
+        out.push_str("null"); // JSON has no NaN or Infinity
+    } else if n.fract() == 0.0 && n.abs() < 1e15 {
+        out.push_str(&format!("{}", n as i64)); // 30, not 30.0
+    } else {
+        out.push_str(&format!("{:?}", n)); // Shortest round-trip form, e.g. 0.1 or 1e300
+    }
+}
+
+// indent = None for compact output
+fn write_value(out: &mut String, value: &JsonValue, indent: Option<usize>, level: usize) {
+    let newline = |out: &mut String, level: usize| {
+        if let Some(width) = indent {
+            out.push('\n');
+            out.push_str(&" ".repeat(width * level));
+        }
+    };
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+        JsonValue::Number(n) => write_number(out, *n),
+        JsonValue::String(s) => write_string(out, s),
+        JsonValue::Array(items) if items.is_empty() => out.push_str("[]"),
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, level + 1);
+                write_value(out, item, indent, level + 1);
+            }
+            newline(out, level);
+            out.push(']');
+        }
+        JsonValue::Object(obj) if obj.len() == 0 => out.push_str("{}"),
+        JsonValue::Object(obj) => {
+            out.push('{');
+            for (i, (key, item)) in obj.entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                newline(out, level + 1);
+                write_string(out, key);
+                out.push_str(if indent.is_some() { ": " } else { ":" });
+                write_value(out, item, indent, level + 1);
+            }
+            newline(out, level);
+            out.push('}');
+        }
+    }
+}
+
+impl JsonValue {
+    fn to_compact(&self) -> String {
+        let mut out = String::new();
+        write_value(&mut out, self, None, 0);
+        out
+    }
+
+    fn to_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        write_value(&mut out, self, Some(indent), 0);
+        out
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_compact())
+    }
+}
+
+// ============================================================================
+// RANDOM VALUES FOR ROUND-TRIP CHECKS
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+fn random_value(rng: &mut XorShift, depth: usize) -> JsonValue {
+    const CHARS: &[char] = &[
+        'a', 'Z', ' ', '"', '\\', '/', '\n', '\u{1}', 'é', '🦀', '\u{2028}',
+    ];
+    let scalar_only = depth >= 4;
+    match rng.below(if scalar_only { 4 } else { 6 }) {
+        0 => JsonValue::Null,
+        1 => JsonValue::Boolean(rng.below(2) == 0),
+        2 => {
+            let mantissa = rng.below(2_000_001) as f64 - 1_000_000.0;
+            let exponent = rng.below(41) as i32 - 20;
+            JsonValue::Number(mantissa * 10f64.powi(exponent))
+        }
+        3 => JsonValue::String(
+            (0..rng.below(6))
+                .map(|_| CHARS[rng.below(CHARS.len() as u64) as usize])
+                .collect(),
+        ),
+        4 => JsonValue::Array(
+            (0..rng.below(4))
+                .map(|_| random_value(rng, depth + 1))
+                .collect(),
+        ),
+        _ => {
+            let mut obj = JsonObject::new();
+            for i in 0..rng.below(4) {
+                obj.insert(format!("k{}", i), random_value(rng, depth + 1));
+            }
+            JsonValue::Object(obj)
+        }
+    }
+}
+
+fn main() {
+    println!("🧩 === JSON (RFC 8259) ===");
+
+    // ========================================================================
+    // 1. THE if-let.rs VALUE, PARSED INSTEAD OF HAND-BUILT
+    // ========================================================================
+    println!("\n📥 === PARSING ===");
+
+    let text = r#"{
+  "name": "Alice",
+  "age": 30,
+  "active": true,
+  "tags": ["developer", "rust"],
+  "address": {"city": "Lisbon", "zip": null},
+  "a/b": 1,
+  "m~n": 2
+}"#;
+    let json = JsonValue::parse(text).unwrap();
+
+    // The same if-let lookups as in if-let.rs
+    if let Some(JsonValue::String(name)) = json.get("name") {
+        println!("  Name: {}", name);
+    }
+    if let Some(JsonValue::Number(age)) = json.get("age") {
+        println!("  Age: {}", age);
+    }
+    if let Some(JsonValue::Array(tags)) = json.get("tags") {
+        let tags: Vec<&str> = tags.iter().filter_map(JsonValue::as_str).collect();
+        println!("  Tags: {:?}", tags);
+    }
+
+    // Key order is exactly as written, unlike the HashMap version
+    if let JsonValue::Object(obj) = &json {
+        let keys: Vec<&str> = obj.keys().collect();
+        println!("  Keys in order: {:?}", keys);
+        assert_eq!(
+            keys,
+            ["name", "age", "active", "tags", "address", "a/b", "m~n"]
+        );
+    }
+
+    // ========================================================================
+    // 2. SERIALIZERS
+    // ========================================================================
+    println!("\n📤 === COMPACT AND PRETTY ===");
+
+    let compact = json.to_compact();
+    println!("  {}", compact);
+    println!("{}", json.to_pretty(2));
+    assert_eq!(
+        compact,
+        r#"{"name":"Alice","age":30,"active":true,"tags":["developer","rust"],"address":{"city":"Lisbon","zip":null},"a/b":1,"m~n":2}"#
+    );
+    assert_eq!(JsonValue::parse(&json.to_pretty(4)).unwrap(), json);
+
+    // ========================================================================
+    // 3. JSON POINTER
+    // ========================================================================
+    println!("\n📍 === JSON POINTER ===");
+
+    let pointers = [
+        "/tags/0",
+        "/address/city",
+        "/a~1b",
+        "/m~0n",
+        "",
+        "/tags/2",
+        "/tags/01",
+        "tags",
+    ];
+    for p in pointers {
+        let found = json.pointer(p).map(JsonValue::to_compact);
+        println!("  {:<15} -> {:?}", format!("{:?}", p), found);
+    }
+    assert_eq!(
+        json.pointer("/tags/0").and_then(JsonValue::as_str),
+        Some("developer")
+    );
+    assert_eq!(json.pointer("/a~1b").and_then(JsonValue::as_f64), Some(1.0));
+    assert_eq!(json.pointer("/m~0n").and_then(JsonValue::as_f64), Some(2.0));
+    assert_eq!(json.pointer(""), Some(&json));
+    assert_eq!(json.pointer("/tags/01"), None); // Leading zeros are not indexes
+    assert_eq!(json.pointer("tags"), None); // Must start with '/'
+
+    // ========================================================================
+    // 4. STRINGS: ESCAPES AND SURROGATE PAIRS
+    // ========================================================================
+    println!("\n🔤 === ESCAPES AND SURROGATES ===");
+
+    let escapes = r#""quote \" slash \/ tab \t e-acute \u00e9 crab \ud83e\udd80""#;
+    let decoded = JsonValue::parse(escapes).unwrap();
+    println!("  {} -> {:?}", escapes, decoded.as_str().unwrap());
+    assert_eq!(
+        decoded.as_str(),
+        Some("quote \" slash / tab \t e-acute é crab 🦀")
+    );
+    // Output keeps non-ASCII as-is and escapes only what JSON requires
+    println!("  re-encoded: {}", decoded);
+    assert_eq!(
+        decoded.to_compact(),
+        r#""quote \" slash / tab \t e-acute é crab 🦀""#
+    );
+
+    // ========================================================================
+    // 5. ERRORS WITH LINE AND COLUMN
+    // ========================================================================
+    println!("\n🚨 === ERRORS ===");
+
+    let bad = [
+        "{\n  \"a\": 1,\n  \"b\": [1, 2,]\n}",
+        "[01]",
+        "[1.]",
+        "{\"a\" 1}",
+        "{a: 1}",
+        "\"tab\there\"",
+        "\"\\x41\"",
+        "\"\\ud83e\"",
+        "\"\\udd80\\ud83e\"",
+        "\"\\u12G4\"",
+        "[1] x",
+        "nul",
+        "[\"unterminated",
+        "1e999",
+        "",
+    ];
+    for input in bad {
+        let err = JsonValue::parse(input).unwrap_err();
+        println!("  {:<28} {}", format!("{:?}", input), err);
+    }
+    assert_eq!(
+        JsonValue::parse(bad[0]).unwrap_err(),
+        ParseError {
+            line: 3,
+            col: 14,
+            kind: ErrorKind::UnexpectedChar(']')
+        }
+    );
+    assert_eq!(
+        JsonValue::parse(bad[7]).unwrap_err().kind,
+        ErrorKind::LoneSurrogate(0xd83e)
+    );
+    assert_eq!(
+        JsonValue::parse(bad[13]).unwrap_err().kind,
+        ErrorKind::NumberOutOfRange
+    );
+
+    let good = [
+        "0",
+        "-0",
+        "-1.5e-3",
+        "1E+2",
+        " \t\n[ ] ",
+        "{}",
+        "\"\"",
+        "[null,true,false]",
+    ];
+    for input in good {
+        assert!(JsonValue::parse(input).is_ok(), "should accept {:?}", input);
+    }
+    println!("  ✅ {} valid edge cases accepted", good.len());
+
+    // ========================================================================
+    // 6. DEPTH LIMIT
+    // ========================================================================
+    println!("\n🪆 === DEPTH LIMIT ===");
+
+    let deep = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+    let err = JsonValue::parse(&deep).unwrap_err();
+    println!("  100,000 nested arrays: {}", err);
+    assert_eq!(err.kind, ErrorKind::TooDeep(DEFAULT_MAX_DEPTH));
+
+    let three = "[[[1]]]";
+    assert!(JsonValue::parse_with_depth(three, 3).is_ok());
+    assert_eq!(
+        JsonValue::parse_with_depth(three, 2).unwrap_err().kind,
+        ErrorKind::TooDeep(2)
+    );
+    println!("  ✅ parse_with_depth(\"[[[1]]]\", 2) refuses, limit 3 accepts");
+
+    // ========================================================================
+    // 7. ROUND-TRIP PROPERTY
+    // ========================================================================
+    println!("\n🎲 === ROUND TRIP ===");
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2_000 {
+        let value = random_value(&mut rng, 0);
+        assert_eq!(JsonValue::parse(&value.to_compact()).unwrap(), value);
+        assert_eq!(JsonValue::parse(&value.to_pretty(2)).unwrap(), value);
+    }
+    println!("  ✅ 2,000 random documents survive compact and pretty round trips");
+
+    // Numbers print the way people expect
+    for (n, expected) in [
+        (30.0, "30"),
+        (-0.5, "-0.5"),
+        (0.1, "0.1"),
+        (1e300, "1e300"),
+        (f64::NAN, "null"),
+    ] {
+        assert_eq!(JsonValue::Number(n).to_compact(), expected);
+    }
+
+    println!("\n📋 === JSON SUMMARY ===");
+    println!("✅ Recursive-descent parser follows the RFC 8259 grammar exactly");
+    println!("✅ Errors point at the line and column of the offending character");
+    println!("✅ \\u escapes, including surrogate pairs, decode to real chars");
+    println!("✅ A depth limit turns stack overflows into ordinary errors");
+    println!("✅ Objects keep key order; JSON Pointer finds values by path");
+}
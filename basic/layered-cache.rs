@@ -0,0 +1,592 @@
+/*
+LAYERED CACHE - LRU + TTL in Front of a Fallback Chain
+======================================================
+
+WHAT IS THIS?
+- option-combinator.rs shows the classic fallback chain:
+    get_cached_data().or_else(fetch_from_database).or_else(fetch_from_api)
+- But its cache ALWAYS misses and nothing is ever written back,
+  so every call pays for the database (or the API) again
+- This file puts a real cache in front of that chain:
+  LRU eviction, per-entry TTL, write-through, negative caching and statistics
+
+REAL-WORLD ANALOGY:
+- Your desk (cache) holds the few folders you used most recently
+- If a folder isn't on your desk, you walk to the filing room (database),
+  and if it isn't there, you phone head office (API)
+- Whatever you fetch goes back on your desk - and into the filing room,
+  so nobody phones head office for it again
+- You also write down "head office doesn't have X" so you stop phoning about X for a while
+
+LOOKUP FLOW:
+┌──────────────┬──────────────────────────────┬───────────────────────────────┐
+│ Cache state  │ What get() does              │ Counted as                    │
+├──────────────┼──────────────────────────────┼───────────────────────────────┤
+│ fresh value  │ return it                    │ hit                           │
+│ fresh "none" │ return None, skip sources    │ negative hit                  │
+│ expired      │ drop it, then ask sources    │ expiration + miss             │
+│ absent       │ ask sources in order         │ miss                          │
+│ source hit   │ store in cache + earlier     │ per-source hit                │
+│              │ sources (write-through)      │                               │
+│ all say none │ remember "none" for a while  │ (negative entry stored)       │
+└──────────────┴──────────────────────────────┴───────────────────────────────┘
+
+KEY CONCEPTS:
+- Time comes from a Clock trait, so tests can move time forward by hand
+- LRU order is a BTreeMap from a "last used" counter to the key:
+  the first entry is always the least recently used one
+- Sources plug in through a trait, so fakes can stand in for the database and API
+- A source ERROR is not the same as "not found" and is never negatively cached
+*/
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
+use std::time::Instant;
+
+// ============================================================================
+// CLOCKS
+// ============================================================================
+
+trait Clock {
+    fn now_ms(&self) -> u64;
+}
+
+struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    fn new() -> Self {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+}
+
+// Shared handle: the test keeps one copy to advance time, the cache holds another
+#[derive(Clone, Default)]
+struct ManualClock {
+    now: Rc<Cell<u64>>,
+}
+
+impl ManualClock {
+    fn advance(&self, ms: u64) {
+        self.now.set(self.now.get() + ms);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.now.get()
+    }
+}
+
+// ============================================================================
+// SOURCES
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct SourceError {
+    source: String,
+    message: String,
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: {}", self.source, self.message)
+    }
+}
+
+trait Source<K, V> {
+    fn name(&self) -> &str;
+
+    // Ok(None) means "definitely not here"; Err means "couldn't ask"
+    fn fetch(&self, key: &K) -> Result<Option<V>, SourceError>;
+
+    // Write-through target. Read-only sources (like an external API) keep the default.
+    fn store(&self, _key: &K, _value: &V) {}
+}
+
+// ============================================================================
+// LRU + TTL STORE
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cached<V> {
+    Value(V),
+    Missing, // Negative entry: every source said "not found"
+}
+
+struct Slot<V> {
+    cached: Cached<V>,
+    expires_at: u64,
+    last_used: u64,
+}
+
+struct LruStore<K, V> {
+    capacity: usize,
+    slots: HashMap<K, Slot<V>>,
+    order: BTreeMap<u64, K>, // last_used -> key, oldest first
+    tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruStore<K, V> {
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "cache capacity must be at least 1");
+        LruStore {
+            capacity,
+            slots: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(slot) = self.slots.get_mut(key) {
+            self.order.remove(&slot.last_used);
+            self.tick += 1;
+            slot.last_used = self.tick;
+            self.order.insert(self.tick, key.clone());
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<&Slot<V>> {
+        self.touch(key);
+        self.slots.get(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<Slot<V>> {
+        let slot = self.slots.remove(key)?;
+        self.order.remove(&slot.last_used);
+        Some(slot)
+    }
+
+    // Returns the evicted key, if making room pushed one out
+    fn insert(&mut self, key: K, cached: Cached<V>, expires_at: u64) -> Option<K> {
+        let mut evicted = None;
+        if self.remove(&key).is_none()
+            && self.slots.len() == self.capacity
+            && let Some((_, oldest)) = self.order.pop_first()
+        {
+            self.slots.remove(&oldest);
+            evicted = Some(oldest);
+        }
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.slots.insert(
+            key,
+            Slot {
+                cached,
+                expires_at,
+                last_used: self.tick,
+            },
+        );
+        evicted
+    }
+
+    // Least recently used first
+    fn keys(&self) -> Vec<&K> {
+        self.order.values().collect()
+    }
+}
+
+// ============================================================================
+// LAYERED CACHE
+// ============================================================================
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct CacheStats {
+    hits: u64,
+    negative_hits: u64,
+    misses: u64,
+    expirations: u64,
+    evictions: u64,
+    source_errors: u64,
+    source_hits: BTreeMap<String, u64>,
+}
+
+impl CacheStats {
+    fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.negative_hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            (self.hits + self.negative_hits) as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hits={} negative_hits={} misses={} expirations={} evictions={} errors={} hit_ratio={:.0}%",
+            self.hits,
+            self.negative_hits,
+            self.misses,
+            self.expirations,
+            self.evictions,
+            self.source_errors,
+            self.hit_ratio() * 100.0
+        )?;
+        for (source, count) in &self.source_hits {
+            write!(f, " {}={}", source, count)?;
+        }
+        Ok(())
+    }
+}
+
+struct LayeredCache<K, V, C: Clock> {
+    store: LruStore<K, V>,
+    sources: Vec<Box<dyn Source<K, V>>>,
+    clock: C,
+    ttl_ms: u64,
+    negative_ttl_ms: u64,
+    stats: CacheStats,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone, C: Clock> LayeredCache<K, V, C> {
+    fn new(capacity: usize, ttl_ms: u64, clock: C) -> Self {
+        LayeredCache {
+            store: LruStore::new(capacity),
+            sources: Vec::new(),
+            clock,
+            ttl_ms,
+            negative_ttl_ms: ttl_ms / 10,
+            stats: CacheStats::default(),
+        }
+    }
+
+    fn negative_ttl(mut self, ms: u64) -> Self {
+        self.negative_ttl_ms = ms;
+        self
+    }
+
+    // Sources are asked in the order they are added
+    fn with_source(mut self, source: impl Source<K, V> + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    fn insert(&mut self, key: K, cached: Cached<V>, ttl_ms: u64) {
+        let expires_at = self.clock.now_ms().saturating_add(ttl_ms); // A huge TTL must not wrap into the past
+        if self.store.insert(key, cached, expires_at).is_some() {
+            self.stats.evictions += 1;
+        }
+    }
+
+    // Only the cache layer; None means "not cached (or expired)"
+    fn cached(&mut self, key: &K) -> Option<Cached<V>> {
+        let now = self.clock.now_ms();
+        let expired = self.store.get(key)?.expires_at <= now;
+        if expired {
+            self.store.remove(key);
+            self.stats.expirations += 1;
+            return None;
+        }
+        self.store.get(key).map(|slot| slot.cached.clone())
+    }
+
+    fn get(&mut self, key: &K) -> Result<Option<V>, SourceError> {
+        match self.cached(key) {
+            Some(Cached::Value(value)) => {
+                self.stats.hits += 1;
+                return Ok(Some(value));
+            }
+            Some(Cached::Missing) => {
+                self.stats.negative_hits += 1;
+                return Ok(None);
+            }
+            None => self.stats.misses += 1,
+        }
+
+        let mut first_error = None;
+        for (i, source) in self.sources.iter().enumerate() {
+            match source.fetch(key) {
+                Ok(Some(value)) => {
+                    *self
+                        .stats
+                        .source_hits
+                        .entry(source.name().to_string())
+                        .or_default() += 1;
+                    // Write-through: every layer in front of this one learns the value
+                    for earlier in &self.sources[..i] {
+                        earlier.store(key, &value);
+                    }
+                    self.insert(key.clone(), Cached::Value(value.clone()), self.ttl_ms);
+                    return Ok(Some(value));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.stats.source_errors += 1;
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        // "Nobody has it" is cacheable; "somebody couldn't answer" is not
+        match first_error {
+            Some(e) => Err(e),
+            None => {
+                self.insert(key.clone(), Cached::Missing, self.negative_ttl_ms);
+                Ok(None)
+            }
+        }
+    }
+
+    // Write a new value everywhere: cache and every writable source
+    fn put(&mut self, key: K, value: V) {
+        for source in &self.sources {
+            source.store(&key, &value);
+        }
+        self.insert(key, Cached::Value(value), self.ttl_ms);
+    }
+
+    fn invalidate(&mut self, key: &K) -> bool {
+        self.store.remove(key).is_some()
+    }
+}
+
+// ============================================================================
+// FAKE SOURCES - STAND-INS FOR THE DATABASE AND THE API
+// ============================================================================
+
+// Rc-shared so the test can inspect the fake after handing it to the cache
+#[derive(Clone, Default)]
+struct FakeDatabase {
+    rows: Rc<RefCell<HashMap<String, String>>>,
+    calls: Rc<Cell<u32>>,
+}
+
+impl FakeDatabase {
+    fn with(rows: &[(&str, &str)]) -> Self {
+        let db = FakeDatabase::default();
+        for (k, v) in rows {
+            db.rows.borrow_mut().insert(k.to_string(), v.to_string());
+        }
+        db
+    }
+}
+
+impl Source<String, String> for FakeDatabase {
+    fn name(&self) -> &str {
+        "database"
+    }
+
+    fn fetch(&self, key: &String) -> Result<Option<String>, SourceError> {
+        self.calls.set(self.calls.get() + 1);
+        Ok(self.rows.borrow().get(key).cloned())
+    }
+
+    fn store(&self, key: &String, value: &String) {
+        self.rows.borrow_mut().insert(key.clone(), value.clone());
+    }
+}
+
+#[derive(Clone, Default)]
+struct FakeApi {
+    calls: Rc<Cell<u32>>,
+    down: Rc<Cell<bool>>,
+}
+
+impl Source<String, String> for FakeApi {
+    fn name(&self) -> &str {
+        "api"
+    }
+
+    fn fetch(&self, key: &String) -> Result<Option<String>, SourceError> {
+        self.calls.set(self.calls.get() + 1);
+        if self.down.get() {
+            return Err(SourceError {
+                source: "api".to_string(),
+                message: "503 Service Unavailable".to_string(),
+            });
+        }
+        // The API knows about every "user:*" key
+        Ok(key
+            .strip_prefix("user:")
+            .map(|id| format!("Data from API for user {}", id)))
+    }
+}
+
+fn main() {
+    println!("🗄️  === LAYERED CACHE ===");
+
+    let clock = ManualClock::default();
+    let db = FakeDatabase::with(&[("config", "Data from database")]);
+    let api = FakeApi::default();
+    let mut cache = LayeredCache::new(3, 60_000, clock.clone())
+        .negative_ttl(5_000)
+        .with_source(db.clone())
+        .with_source(api.clone());
+    let key = |k: &str| k.to_string();
+
+    // ========================================================================
+    // 1. THE option-combinator.rs CHAIN, WITH A CACHE THAT WORKS
+    // ========================================================================
+    println!("\n🔄 === CACHE → DATABASE → API ===");
+
+    for attempt in 1..=3 {
+        let data = cache.get(&key("config")).unwrap();
+        println!(
+            "  attempt {}: {:?} (db calls: {})",
+            attempt,
+            data,
+            db.calls.get()
+        );
+    }
+    assert_eq!(db.calls.get(), 1); // Only the first call reached the database
+    assert_eq!(cache.stats().hits, 2);
+
+    // ========================================================================
+    // 2. WRITE-THROUGH ON FALLBACK HITS
+    // ========================================================================
+    println!("\n✍️  === WRITE-THROUGH ===");
+
+    let value = cache.get(&key("user:7")).unwrap();
+    println!("  user:7 -> {:?}", value);
+    println!(
+        "  database now has user:7: {:?}",
+        db.rows.borrow().get("user:7")
+    );
+    assert_eq!(api.calls.get(), 1);
+    assert!(db.rows.borrow().contains_key("user:7")); // API value copied into the database
+
+    // Even after the cache forgets it, the database answers - the API isn't asked again
+    cache.invalidate(&key("user:7"));
+    cache.get(&key("user:7")).unwrap();
+    assert_eq!(api.calls.get(), 1);
+    println!("  ✅ After invalidation the database answered; API calls still 1");
+
+    // ========================================================================
+    // 3. TTL WITH AN INJECTABLE CLOCK
+    // ========================================================================
+    println!("\n⏰ === TTL ===");
+
+    let db_calls = db.calls.get();
+    clock.advance(59_999);
+    cache.get(&key("config")).unwrap();
+    assert_eq!(db.calls.get(), db_calls); // Still fresh
+    clock.advance(60_000);
+    cache.get(&key("config")).unwrap();
+    assert_eq!(db.calls.get(), db_calls + 1); // Expired, refetched
+    println!(
+        "  t={}ms: entry expired and was refetched (expirations: {})",
+        clock.now_ms(),
+        cache.stats().expirations
+    );
+    assert!(cache.stats().expirations >= 1);
+
+    // ========================================================================
+    // 4. NEGATIVE CACHING AND SOURCE ERRORS
+    // ========================================================================
+    println!("\n🚫 === NEGATIVE CACHING ===");
+
+    let before = (db.calls.get(), api.calls.get());
+    for _ in 0..3 {
+        assert_eq!(cache.get(&key("nonexistent")).unwrap(), None);
+    }
+    let after = (db.calls.get(), api.calls.get());
+    println!(
+        "  3 lookups of a missing key -> sources asked {} time(s) each",
+        after.0 - before.0
+    );
+    assert_eq!((after.0 - before.0, after.1 - before.1), (1, 1));
+    assert_eq!(cache.stats().negative_hits, 2);
+
+    clock.advance(5_000); // Negative TTL is much shorter than the normal TTL
+    cache.get(&key("nonexistent")).unwrap();
+    assert_eq!(db.calls.get() - before.0, 2);
+    println!("  After 5s the 'not found' expired and the sources were asked again");
+
+    // An outage is an error, not an answer - so it must not be remembered
+    api.down.set(true);
+    let outage = cache.get(&key("user:99"));
+    println!(
+        "  API down: {:?}",
+        outage.as_ref().map_err(ToString::to_string)
+    );
+    assert!(outage.is_err());
+    api.down.set(false);
+    assert_eq!(
+        cache.get(&key("user:99")).unwrap().as_deref(),
+        Some("Data from API for user 99")
+    );
+    println!("  ✅ Once the API recovered the value was fetched - the error wasn't cached");
+
+    // ========================================================================
+    // 5. LRU EVICTION
+    // ========================================================================
+    println!("\n♻️  === LRU EVICTION (capacity 3) ===");
+
+    let mut small =
+        LayeredCache::new(3, 60_000, ManualClock::default()).with_source(FakeDatabase::with(&[
+            ("a", "1"),
+            ("b", "2"),
+            ("c", "3"),
+            ("d", "4"),
+        ]));
+    for k in ["a", "b", "c"] {
+        small.get(&key(k)).unwrap();
+    }
+    small.get(&key("a")).unwrap(); // a is now the most recently used
+    small.get(&key("d")).unwrap(); // evicts b, the least recently used
+    let order: Vec<&String> = small.store.keys();
+    println!("  LRU → MRU: {:?}", order);
+    assert_eq!(order, ["c", "a", "d"]);
+    assert_eq!(small.stats().evictions, 1);
+    assert_eq!(small.store.len(), 3);
+
+    small.put(key("e"), "5".to_string());
+    assert_eq!(small.store.keys(), ["a", "d", "e"]);
+    println!("  put(e) evicted c: {:?}", small.store.keys());
+
+    // ========================================================================
+    // 6. STATISTICS
+    // ========================================================================
+    println!("\n📊 === STATISTICS ===");
+    println!("  main cache:  {}", cache.stats());
+    println!("  small cache: {}", small.stats());
+    assert_eq!(cache.stats().source_hits.get("api"), Some(&2));
+
+    // The same type works on a real clock too
+    let mut real = LayeredCache::new(10, 1_000, SystemClock::new())
+        .with_source(FakeDatabase::with(&[("k", "v")]));
+    assert_eq!(real.get(&key("k")).unwrap().as_deref(), Some("v"));
+    assert_eq!(real.get(&key("k")).unwrap().as_deref(), Some("v"));
+    assert_eq!(real.stats().hits, 1);
+
+    // A "forever" TTL saturates instead of wrapping around to an expired time
+    let clock = ManualClock::default();
+    clock.advance(1_000);
+    let mut forever = LayeredCache::new(10, u64::MAX, clock.clone())
+        .with_source(FakeDatabase::with(&[("k", "v")]));
+    forever.get(&key("k")).unwrap();
+    clock.advance(1_000_000);
+    forever.get(&key("k")).unwrap();
+    assert_eq!(forever.stats().hits, 1);
+
+    println!("\n📋 === LAYERED CACHE SUMMARY ===");
+    println!("✅ Cache hits skip the database and API entirely");
+    println!("✅ Fallback hits are written back into the cache and earlier sources");
+    println!("✅ TTL uses a Clock trait, so tests move time instead of sleeping");
+    println!("✅ 'Not found' is cached briefly; source errors are never cached");
+    println!("✅ LRU keeps the most recently used entries within capacity");
+}
@@ -0,0 +1,500 @@
+/*
+LINKED LIST - A Generic Singly Linked List
+==========================================
+
+WHAT IS THIS?
+- while-let.rs builds `Node { value: i32, next: Option<Box<Node>> }` by hand,
+  four levels deep, and can only walk it
+- This file turns that Node into a real generic container, LinkedList<T>:
+  push/pop at the front, three kinds of iterators, reverse, append, split_off,
+  and a cursor for inserting and removing in the middle
+- It also fixes a trap hiding in the naive version: dropping a long list
+
+REAL-WORLD ANALOGY:
+- A treasure hunt: each clue (node) holds a prize (value) and tells you
+  where the next clue is (next). You can only start from the first clue.
+- A cursor is you, standing at one clue, able to slip a new clue in
+  or pull the current one out and re-tie the chain
+
+THE DROP TRAP:
+┌──────────────────────────────┬──────────────────────────────────────────────┐
+│ Automatic (derived) drop     │ Drop node 1 → drops its Box → drops node 2   │
+│                              │ → ... one stack frame PER NODE. A million    │
+│                              │ nodes = stack overflow.                      │
+├──────────────────────────────┼──────────────────────────────────────────────┤
+│ Our Drop impl                │ Loop: detach the head's `next`, drop the     │
+│                              │ head (which now has no next), repeat.        │
+│                              │ Constant stack, any length.                  │
+└──────────────────────────────┴──────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- `Option<Box<Node<T>>>` is a "link": either nothing, or an owned next node
+- `Option::take()` moves a link out and leaves None - the key to every operation
+- Iter borrows, IterMut borrows mutably, IntoIter consumes: the same trio as Vec
+- A cursor holds `&mut Link` - a mutable reference to the slot it is standing on
+*/
+
+use std::fmt;
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+struct LinkedList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> LinkedList<T> {
+    fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.value)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+
+    // Flip every `next` pointer in one pass; no allocation, no moves of values
+    fn reverse(&mut self) {
+        let mut reversed: Link<T> = None;
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    // The empty link at the very end of the list
+    fn tail_link(&mut self) -> &mut Link<T> {
+        let mut link = &mut self.head;
+        while let Some(node) = link {
+            link = &mut node.next;
+        }
+        link
+    }
+
+    // Moves every element of `other` to the end of `self`, leaving `other` empty. O(len(self)).
+    fn append(&mut self, other: &mut LinkedList<T>) {
+        *self.tail_link() = other.head.take();
+        self.len += std::mem::take(&mut other.len);
+    }
+
+    // Keeps [0, at) and returns [at, len). Panics if at > len, like Vec::split_off.
+    fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(
+            at <= self.len,
+            "split_off index {} out of bounds (len {})",
+            at,
+            self.len
+        );
+        let mut link = &mut self.head;
+        for _ in 0..at {
+            link = &mut link.as_mut().expect("index checked above").next;
+        }
+        let tail = LinkedList {
+            head: link.take(),
+            len: self.len - at,
+        };
+        self.len = at;
+        tail
+    }
+
+    fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            link: Some(&mut self.head),
+            index: 0,
+            len: &mut self.len,
+        }
+    }
+}
+
+// Iterative, so a long list can't overflow the stack
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+            // `node` is dropped here with next == None: no recursion
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList::new()
+    }
+}
+
+// ============================================================================
+// ITERATORS
+// ============================================================================
+
+struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.value
+        })
+    }
+}
+
+struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// Keeps the iterator's order (push_front alone would reverse it)
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        let mut tail = &mut list.head;
+        for value in iter {
+            let node = tail.insert(Box::new(Node { value, next: None }));
+            tail = &mut node.next;
+            list.len += 1;
+        }
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+// ============================================================================
+// CURSOR
+// ============================================================================
+
+// Stands on a link: the element there (if any) is "current".
+// At the end of the list the link is None and current() is None.
+struct CursorMut<'a, T> {
+    link: Option<&'a mut Link<T>>, // Always Some; Option only so we can move the borrow
+    index: usize,
+    len: &'a mut usize,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn current(&mut self) -> Option<&mut T> {
+        self.link.as_mut()?.as_mut().map(|node| &mut node.value)
+    }
+
+    // Step to the next element; false (and no move) if already at the end
+    fn move_next(&mut self) -> bool {
+        let link = self.link.take().expect("cursor always holds a link");
+        if link.is_none() {
+            self.link = Some(link);
+            return false;
+        }
+        self.link = Some(&mut link.as_mut().expect("checked above").next);
+        self.index += 1;
+        true
+    }
+
+    // New element goes at the cursor and becomes current; the old current follows it
+    fn insert(&mut self, value: T) {
+        let link = self.link.as_mut().expect("cursor always holds a link");
+        let next = link.take();
+        **link = Some(Box::new(Node { value, next }));
+        *self.len += 1;
+    }
+
+    // Removes the current element; the one after it becomes current
+    fn remove(&mut self) -> Option<T> {
+        let link = self.link.as_mut().expect("cursor always holds a link");
+        let node = link.take()?;
+        **link = node.next;
+        *self.len -= 1;
+        Some(node.value)
+    }
+}
+
+// ============================================================================
+// DEMO HELPERS
+// ============================================================================
+
+// Counts drops so we can prove every element is dropped exactly once
+struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+fn main() {
+    println!("🔗 === LINKED LIST ===");
+
+    // ========================================================================
+    // 1. THE while-let.rs WALK, ON THE GENERIC LIST
+    // ========================================================================
+    println!("\n🚶 === WALKING THE LIST ===");
+
+    let mut list = LinkedList::new();
+    for value in (1..=4).rev() {
+        list.push_front(value); // Same 1 → 2 → 3 → 4 chain as while-let.rs
+    }
+    // Exactly the while-let.rs loop, now over Node<T>
+    let mut current = list.head.as_deref();
+    while let Some(node) = current {
+        println!("  Node value: {}", node.value);
+        current = node.next.as_deref();
+    }
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.front(), Some(&1));
+
+    // Generic: any T works
+    let words: LinkedList<String> = ["alpha", "beta", "gamma"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    println!("  Strings: {:?}", words);
+    assert_eq!(words.iter().map(String::len).sum::<usize>(), 14);
+
+    // ========================================================================
+    // 2. PUSH, POP, ITER, ITER_MUT, INTO_ITER
+    // ========================================================================
+    println!("\n🔄 === PUSH / POP / ITERATORS ===");
+
+    let mut stack: LinkedList<i32> = LinkedList::new();
+    assert!(stack.is_empty() && stack.pop_front().is_none());
+    stack.push_front(10);
+    stack.push_front(20);
+    *stack.front_mut().unwrap() += 1;
+    assert_eq!(stack.pop_front(), Some(21));
+    assert_eq!(stack.pop_front(), Some(10));
+    assert_eq!(stack.pop_front(), None);
+    println!("  push/pop behaves like a stack ✅");
+
+    for value in &mut list {
+        *value *= 10;
+    }
+    println!("  After iter_mut (x10): {:?}", list);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 20, 30, 40]);
+
+    let owned: Vec<i32> = list.into_iter().map(|v| v + 1).collect();
+    println!("  into_iter consumed the list: {:?}", owned);
+    assert_eq!(owned, [11, 21, 31, 41]);
+
+    // ========================================================================
+    // 3. REVERSE, APPEND, SPLIT_OFF
+    // ========================================================================
+    println!("\n✂️  === REVERSE / APPEND / SPLIT_OFF ===");
+
+    let mut a: LinkedList<i32> = (1..=5).collect();
+    a.reverse();
+    println!("  reversed: {:?}", a);
+    assert_eq!(a, (1..=5).rev().collect());
+
+    let mut b: LinkedList<i32> = (6..=8).collect();
+    a.reverse();
+    a.append(&mut b);
+    println!("  appended: {:?} (other is now {:?})", a, b);
+    assert_eq!(a, (1..=8).collect());
+    assert!(b.is_empty());
+    assert_eq!(b.len(), 0);
+
+    let tail = a.split_off(3);
+    println!("  split_off(3): {:?} + {:?}", a, tail);
+    assert_eq!((a.len(), tail.len()), (3, 5));
+    assert_eq!(tail.front(), Some(&4));
+    let everything = a.split_off(0);
+    assert!(a.is_empty() && everything.len() == 3);
+    let mut empty_end = LinkedList::<i32>::new();
+    assert!(empty_end.split_off(0).is_empty());
+
+    // ========================================================================
+    // 4. CURSOR: INSERT AND REMOVE MID-LIST
+    // ========================================================================
+    println!("\n🎯 === CURSOR ===");
+
+    let mut list: LinkedList<i32> = (1..=10).collect();
+    let mut cursor = list.cursor_front_mut();
+    // Remove every multiple of 3 and insert a 0 before every multiple of 4
+    while let Some(&mut value) = cursor.current() {
+        if value % 3 == 0 {
+            cursor.remove();
+            continue; // The next element is now current
+        }
+        if value % 4 == 0 {
+            cursor.insert(0);
+            cursor.move_next(); // Step past the new 0 onto the original value
+        }
+        cursor.move_next();
+    }
+    assert!(!cursor.move_next()); // At the end: stays put
+    println!("  cursor ended at index {}", cursor.index());
+    println!("  edited list: {:?}", list);
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [1, 2, 0, 4, 5, 7, 0, 8, 10]
+    );
+    assert_eq!(list.len(), 9);
+
+    // Inserting at the end appends
+    let mut cursor = list.cursor_front_mut();
+    while cursor.move_next() {}
+    cursor.insert(99);
+    assert_eq!(list.iter().last(), Some(&99));
+    assert_eq!(list.len(), 10);
+
+    // ========================================================================
+    // 5. DROP: EVERY ELEMENT ONCE, AND A MILLION WITHOUT OVERFLOW
+    // ========================================================================
+    println!("\n🗑️  === DROP ===");
+
+    let drops = std::cell::Cell::new(0);
+    {
+        let mut counted: LinkedList<DropCounter> = (0..100).map(|_| DropCounter(&drops)).collect();
+        let mut tail = counted.split_off(40);
+        counted.cursor_front_mut().remove();
+        drop(counted.pop_front());
+        counted.append(&mut tail);
+        assert_eq!(drops.get(), 2);
+    }
+    println!("  100 counted elements, {} drops", drops.get());
+    assert_eq!(drops.get(), 100);
+
+    // Run on a deliberately small stack: a recursive drop would overflow here
+    let survived = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut big = LinkedList::new();
+            for i in 0..1_000_000u32 {
+                big.push_front(i);
+            }
+            let len = big.len();
+            drop(big);
+            len
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    println!(
+        "  Built and dropped {} nodes on a 64 KiB stack ✅",
+        survived
+    );
+    assert_eq!(survived, 1_000_000);
+
+    println!("\n📋 === LINKED LIST SUMMARY ===");
+    println!("✅ LinkedList<T> is the while-let.rs Node made generic, with a length");
+    println!("✅ Iter / IterMut / IntoIter mirror Vec's borrow, mutate and consume");
+    println!("✅ reverse, append and split_off relink nodes without moving values");
+    println!("✅ CursorMut inserts and removes in the middle in O(1) at the cursor");
+    println!("✅ Drop is a loop, so even a million nodes can't overflow the stack");
+}
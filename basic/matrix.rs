@@ -0,0 +1,736 @@
+/*
+MATRIX<T> - One Type for Grids, Tables and Linear Algebra
+=========================================================
+
+WHAT IS THIS?
+- ranges.rs builds a 5x5 grid of (row, col) tuples and prints a multiplication
+  table with two nested loops - neither can be passed around or reused
+- Matrix<T> stores rows * cols values in ONE Vec (row-major) and indexes with (row, col)
+- It adds row/column/diagonal iterators, transpose, element-wise math,
+  matrix multiplication, determinant and inverse for f64, and zero-copy views
+
+REAL-WORLD ANALOGY:
+- Like a spreadsheet: cells addressed by (row, column), stored line by line
+- A view is like selecting a block of cells - you see them in place, nothing is copied
+
+ROW-MAJOR STORAGE (2 x 3 matrix):
+    [ a b c ]      data = [a, b, c, d, e, f]
+    [ d e f ]      (r, c) lives at data[r * cols + c]
+
+OPERATIONS:
+┌────────────────────────┬────────────────────────────────────────────────────────┐
+│       Operation        │                        Notes                           │
+├────────────────────────┼────────────────────────────────────────────────────────┤
+│ m[(r, c)]              │ Index / IndexMut, panics when out of bounds like Vec   │
+│ m.get(r, c)            │ Option<&T> - the non-panicking version                 │
+│ row(r) / col(c)        │ row is a slice; col is an iterator stepping by `cols`  │
+│ diagonal()             │ (0,0), (1,1), ... up to the shorter side               │
+│ &a + &b, &a - &b       │ Element-wise; checked_add / checked_sub return Result  │
+│ &a * &b                │ Matrix product; checked_mul returns Result             │
+│ determinant / inverse  │ f64 only - Gaussian elimination with partial pivoting  │
+│ view(rows, cols)       │ Borrowed sub-matrix, no allocation                     │
+└────────────────────────┴────────────────────────────────────────────────────────┘
+*/
+
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Range, Sub};
+
+// ============================================================================
+// NUMERIC TRAIT
+// ============================================================================
+
+// The handful of things matrix arithmetic needs from an element type
+trait Numeric: Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty => $zero:expr, $one:expr);* $(;)?) => {
+        $(impl Numeric for $t {
+            fn zero() -> Self { $zero }
+            fn one() -> Self { $one }
+        })*
+    };
+}
+
+impl_numeric! {
+    i32 => 0, 1;
+    i64 => 0, 1;
+    u32 => 0, 1;
+    usize => 0, 1;
+    f64 => 0.0, 1.0;
+}
+
+#[derive(Debug, PartialEq)]
+enum MatrixError {
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    OutOfBounds,
+    NotSquare,
+    Singular,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch: {}x{} vs {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+            MatrixError::RaggedRows {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} columns, expected {}",
+                row, found, expected
+            ),
+            MatrixError::OutOfBounds => write!(f, "range is outside the matrix"),
+            MatrixError::NotSquare => write!(f, "matrix is not square"),
+            MatrixError::Singular => write!(f, "matrix is singular"),
+        }
+    }
+}
+
+// ============================================================================
+// MATRIX
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows * cols).map(|i| f(i / cols, i % cols)).collect();
+        Matrix { rows, cols, data }
+    }
+
+    fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        let row_count = rows.len();
+        let mut data = Vec::with_capacity(row_count * cols);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(MatrixError::RaggedRows {
+                    row: i,
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+        }
+        Ok(Matrix {
+            rows: row_count,
+            cols,
+            data,
+        })
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None // col >= cols must not wrap into the next row
+        }
+    }
+
+    fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        // max(1) keeps chunks() happy for 0-column matrices
+        self.data.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of range", col);
+        self.data.iter().skip(col).step_by(self.cols)
+    }
+
+    fn diagonal(&self) -> impl Iterator<Item = &T> {
+        let len = self.rows.min(self.cols);
+        self.data.iter().step_by(self.cols + 1).take(len)
+    }
+
+    fn map<U>(&self, f: impl Fn(&T) -> U) -> Matrix<U> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    fn zip_with<U>(
+        &self,
+        other: &Matrix<T>,
+        f: impl Fn(&T, &T) -> U,
+    ) -> Result<Matrix<U>, MatrixError> {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    // Borrow a rectangular block without copying
+    fn view(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        MatrixView::whole(self).view(rows, cols)
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    fn new(rows: usize, cols: usize, fill: T) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![fill; rows * cols],
+        }
+    }
+
+    fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.cols, self.rows, |r, c| self[(c, r)].clone())
+    }
+}
+
+impl<T: Numeric> Matrix<T> {
+    fn identity(n: usize) -> Self {
+        Matrix::from_fn(n, n, |r, c| if r == c { T::one() } else { T::zero() })
+    }
+
+    fn checked_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(other, |&a, &b| a + b)
+    }
+
+    fn checked_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(other, |&a, &b| a - b)
+    }
+
+    // Element-wise (Hadamard) product - not the matrix product
+    fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(other, |&a, &b| a * b)
+    }
+
+    fn scale(&self, k: T) -> Matrix<T> {
+        self.map(|&x| x * k)
+    }
+
+    fn checked_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        let mut result = Matrix::new(self.rows, other.cols, T::zero());
+        // i-k-j loop order walks both inputs row by row (cache friendly)
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..other.cols {
+                    result[(i, j)] = result[(i, j)] + a * other[(k, j)];
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Matrix<f64> {
+    // Gaussian elimination with partial pivoting; each row swap flips the sign.
+    // The result is the exact product of the pivots - tiny values are not rounded to 0.
+    fn determinant(&self) -> Result<f64, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.rows;
+        let mut m = self.clone();
+        let mut det = 1.0;
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| m[(a, col)].abs().total_cmp(&m[(b, col)].abs()))
+                .unwrap();
+            if m[(pivot, col)] == 0.0 {
+                return Ok(0.0);
+            }
+            if pivot != col {
+                m.swap_rows(pivot, col);
+                det = -det;
+            }
+            det *= m[(col, col)];
+            for row in col + 1..n {
+                let factor = m[(row, col)] / m[(col, col)];
+                for c in col..n {
+                    m[(row, c)] -= factor * m[(col, c)];
+                }
+            }
+        }
+        Ok(det)
+    }
+
+    // Gauss-Jordan on [A | I]; when A turns into I, the right half is A⁻¹.
+    // Each row is judged against its own scale (largest original entry), so
+    // diag(1e10, 1e-10) is fine, while a row that cancels down to rounding noise
+    // - n ulps of where it started - means the matrix is singular.
+    fn inverse(&self) -> Result<Matrix<f64>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inv = Matrix::identity(n);
+        let mut scales: Vec<f64> = (0..n)
+            .map(|row| (0..n).fold(0.0_f64, |m, c| m.max(self[(row, c)].abs())))
+            .collect();
+        let relative = |value: f64, scale: f64| {
+            if scale > 0.0 {
+                value.abs() / scale
+            } else {
+                0.0
+            }
+        };
+
+        for col in 0..n {
+            // Scaled partial pivoting: the biggest entry relative to its own row
+            let pivot = (col..n)
+                .max_by(|&x, &y| {
+                    relative(a[(x, col)], scales[x]).total_cmp(&relative(a[(y, col)], scales[y]))
+                })
+                .unwrap();
+            if a[(pivot, col)].abs() <= n as f64 * f64::EPSILON * scales[pivot] {
+                return Err(MatrixError::Singular);
+            }
+            a.swap_rows(pivot, col);
+            inv.swap_rows(pivot, col);
+            scales.swap(pivot, col);
+
+            let p = a[(col, col)];
+            for c in 0..n {
+                a[(col, c)] /= p;
+                inv[(col, c)] /= p;
+            }
+            for row in (0..n).filter(|&r| r != col) {
+                let factor = a[(row, col)];
+                for c in 0..n {
+                    a[(row, c)] -= factor * a[(col, c)];
+                    inv[(row, c)] -= factor * inv[(col, c)];
+                }
+            }
+        }
+        Ok(inv)
+    }
+
+    fn approx_eq(&self, other: &Matrix<f64>, epsilon: f64) -> bool {
+        self.shape() == other.shape()
+            && self
+                .data
+                .iter()
+                .zip(&other.data)
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl<T> Matrix<T> {
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for c in 0..self.cols {
+                self.data.swap(a * self.cols + c, b * self.cols + c);
+            }
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+// Operators panic on mismatched shapes, like indexing a Vec out of bounds;
+// use checked_add / checked_sub / checked_mul to get a Result instead
+impl<T: Numeric> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        self.checked_add(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Numeric> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        self.checked_sub(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Numeric> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.checked_mul(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Pad every cell to the widest one so columns line up
+        let cells: Vec<String> = self
+            .data
+            .iter()
+            .map(|x| match f.precision() {
+                Some(p) => format!("{:.*}", p, x),
+                None => x.to_string(),
+            })
+            .collect();
+        let width = cells.iter().map(|c| c.len()).max().unwrap_or(0);
+        for row in cells.chunks(self.cols.max(1)) {
+            let padded: Vec<String> = row
+                .iter()
+                .map(|c| format!("{:>w$}", c, w = width))
+                .collect();
+            writeln!(f, "[ {} ]", padded.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// VIEWS
+// ============================================================================
+
+// A window into a parent matrix: same data, different offsets and size
+#[derive(Debug, Clone, Copy)]
+struct MatrixView<'a, T> {
+    data: &'a [T],
+    stride: usize, // Column count of the parent - distance between rows
+    row_offset: usize,
+    col_offset: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    fn whole(matrix: &'a Matrix<T>) -> Self {
+        MatrixView {
+            data: &matrix.data,
+            stride: matrix.cols,
+            row_offset: 0,
+            col_offset: 0,
+            rows: matrix.rows,
+            cols: matrix.cols,
+        }
+    }
+
+    // Ranges are relative to this view, so views of views work naturally
+    fn view(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        if rows.start > rows.end
+            || cols.start > cols.end
+            || rows.end > self.rows
+            || cols.end > self.cols
+        {
+            return Err(MatrixError::OutOfBounds);
+        }
+        Ok(MatrixView {
+            data: self.data,
+            stride: self.stride,
+            row_offset: self.row_offset + rows.start,
+            col_offset: self.col_offset + cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+        })
+    }
+
+    fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.rows, "row {} out of range", row);
+        let start = (self.row_offset + row) * self.stride + self.col_offset;
+        &self.data[start..start + self.cols]
+    }
+
+    fn rows_iter(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.rows).map(|r| self.row(r))
+    }
+
+    fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::from_fn(self.rows, self.cols, |r, c| self[(r, c)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.cols, "column {} out of range", col);
+        &self.row(row)[col]
+    }
+}
+
+// ============================================================================
+// PROPERTY-CHECK HELPERS
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_small(&mut self) -> i64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % 21) as i64 - 10 // -10..=10 keeps products far from overflow
+    }
+
+    fn matrix(&mut self, rows: usize, cols: usize) -> Matrix<i64> {
+        Matrix::from_fn(rows, cols, |_, _| self.next_small())
+    }
+}
+
+fn main() {
+    println!("🧮 === GENERIC MATRIX TYPE ===");
+
+    // ========================================================================
+    // 1. REPLACING THE ranges.rs GRID AND MULTIPLICATION TABLE
+    // ========================================================================
+    println!("\n🔲 === GRID AND MULTIPLICATION TABLE ===");
+
+    let grid = Matrix::from_fn(5, 5, |r, c| format!("({},{})", r, c));
+    print!("Grid coordinates:\n{}", grid);
+    assert_eq!(grid[(2, 3)], "(2,3)");
+
+    let table = Matrix::from_fn(5, 5, |r, c| (r + 1) * (c + 1));
+    print!("5x5 multiplication table:\n{}", table);
+    assert_eq!(table.row(2), &[3, 6, 9, 12, 15]);
+    assert_eq!(
+        table.col(4).copied().collect::<Vec<_>>(),
+        vec![5, 10, 15, 20, 25]
+    );
+    assert_eq!(
+        table.diagonal().copied().collect::<Vec<_>>(),
+        vec![1, 4, 9, 16, 25]
+    ); // Squares
+    assert_eq!(table, table.transpose()); // i * j == j * i
+
+    // ========================================================================
+    // 2. CONSTRUCTION, INDEXING AND ITERATORS
+    // ========================================================================
+    println!("\n📐 === CONSTRUCTION AND ITERATORS ===");
+
+    let mut m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    m[(1, 2)] = 60;
+    print!("2x3 matrix:\n{}", m);
+    println!(
+        "Shape {:?}, rows {:?}",
+        m.shape(),
+        m.rows_iter().collect::<Vec<_>>()
+    );
+    println!("Transpose:\n{}", m.transpose());
+    assert_eq!(m.get(1, 2), Some(&60));
+    assert_eq!(m.get(0, 3), None); // Would be (1, 0) if it wrapped
+    assert_eq!(m.transpose().shape(), (3, 2));
+    assert_eq!(m.diagonal().copied().collect::<Vec<_>>(), vec![1, 5]);
+
+    let ragged = Matrix::from_rows(vec![vec![1, 2], vec![3]]);
+    println!("Ragged rows: {}", ragged.unwrap_err());
+
+    // ========================================================================
+    // 3. ELEMENT-WISE OPS AND MULTIPLICATION
+    // ========================================================================
+    println!("\n✖️  === ARITHMETIC ===");
+
+    let a = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let b = Matrix::from_rows(vec![vec![5, 6], vec![7, 8]]).unwrap();
+    print!("a + b:\n{}", &a + &b);
+    print!("a ∘ b (element-wise):\n{}", a.hadamard(&b).unwrap());
+    print!("a * b:\n{}", &a * &b);
+    assert_eq!(
+        &a * &b,
+        Matrix::from_rows(vec![vec![19, 22], vec![43, 50]]).unwrap()
+    );
+    assert_eq!(&(&a + &b) - &b, a);
+    assert_eq!(
+        a.scale(3),
+        Matrix::from_rows(vec![vec![3, 6], vec![9, 12]]).unwrap()
+    );
+
+    // (3x2) * (2x2) works, (2x2) * (3x2) does not
+    let tall = Matrix::new(3, 2, 1);
+    assert_eq!(tall.checked_mul(&a).unwrap().shape(), (3, 2));
+    println!(
+        "checked_mul mismatch: {}",
+        a.checked_mul(&tall).unwrap_err()
+    );
+    let wide = tall.transpose();
+    assert!(a.checked_add(&wide).is_err());
+
+    // Properties on random integer matrices (exact arithmetic, so == is fine)
+    let mut rng = XorShift(0xDEC0_DED5_EED5);
+    for n in 1..=6 {
+        let a = rng.matrix(n, n + 1);
+        let b = rng.matrix(n + 1, n + 2);
+        let c = rng.matrix(n + 2, n);
+        assert_eq!(&a * &Matrix::identity(n + 1), a);
+        assert_eq!(&Matrix::identity(n) * &a, a);
+        assert_eq!(&(&a * &b) * &c, &a * &(&b * &c)); // Associativity
+        assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+        assert_eq!(a.transpose().transpose(), a);
+    }
+    println!("Identity, associativity and transpose properties hold for random matrices");
+
+    // ========================================================================
+    // 4. DETERMINANT AND INVERSE (f64)
+    // ========================================================================
+    println!("\n🔁 === DETERMINANT AND INVERSE ===");
+
+    let f = Matrix::from_rows(vec![
+        vec![4.0, 7.0, 2.0],
+        vec![3.0, 6.0, 1.0],
+        vec![2.0, 5.0, 3.0],
+    ])
+    .unwrap();
+    let inv = f.inverse().unwrap();
+    println!("det = {:.3}", f.determinant().unwrap());
+    print!("inverse:\n{:.3}", inv);
+    assert!((f.determinant().unwrap() - 9.0).abs() < 1e-9);
+    assert!((&f * &inv).approx_eq(&Matrix::identity(3), 1e-9));
+
+    let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    println!(
+        "Singular matrix: det = {}, inverse = {:?}",
+        singular.determinant().unwrap(),
+        singular.inverse()
+    );
+    assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+
+    // Badly scaled is not singular: each row is judged on its own scale
+    let tiny = Matrix::<f64>::identity(2).map(|x| x * 1e-13);
+    assert!(tiny.determinant().unwrap() > 0.0);
+    assert!(tiny.inverse().is_ok());
+    let mut lopsided = Matrix::identity(2);
+    lopsided[(0, 0)] = 1e10;
+    lopsided[(1, 1)] = 1e-10;
+    assert_eq!(lopsided.determinant(), Ok(1.0));
+    let lopsided_inv = lopsided.inverse().unwrap();
+    assert!((lopsided_inv[(0, 0)] - 1e-10).abs() < 1e-24);
+    assert!((lopsided_inv[(1, 1)] - 1e10).abs() < 1e-4);
+
+    // A row that cancels down to rounding noise is singular, even if the product
+    // of the pivots isn't exactly zero
+    let rounded = Matrix::from_rows(vec![vec![0.1, 0.2], vec![0.3, 0.6]]).unwrap();
+    assert!(rounded.determinant().unwrap().abs() < 1e-15);
+    assert_eq!(rounded.inverse(), Err(MatrixError::Singular));
+    assert_eq!(Matrix::new(2, 2, 0.0).determinant(), Ok(0.0));
+    assert_eq!(
+        Matrix::new(2, 3, 1.0).determinant(),
+        Err(MatrixError::NotSquare)
+    );
+
+    for n in 1..=5 {
+        let a = rng.matrix(n, n).map(|&x| x as f64);
+        let b = rng.matrix(n, n).map(|&x| x as f64);
+        let (da, db) = (a.determinant().unwrap(), b.determinant().unwrap());
+        let dab = (&a * &b).determinant().unwrap();
+        assert!((dab - da * db).abs() <= 1e-6 * (1.0 + dab.abs())); // det(AB) = det(A)det(B)
+        if da.abs() > 1e-6 {
+            assert!((&a * &a.inverse().unwrap()).approx_eq(&Matrix::identity(n), 1e-9));
+        }
+    }
+    println!("det(AB) = det(A)·det(B) and A·A⁻¹ = I hold for random matrices");
+
+    // ========================================================================
+    // 5. ZERO-COPY VIEWS
+    // ========================================================================
+    println!("\n🔍 === ZERO-COPY VIEWS ===");
+
+    let big = Matrix::from_fn(6, 6, |r, c| r * 10 + c);
+    let block = big.view(1..4, 2..5).unwrap();
+    println!("Rows 1..4, cols 2..5 of a 6x6 matrix:");
+    for row in block.rows_iter() {
+        println!("  {:?}", row);
+    }
+    assert_eq!(block[(0, 0)], 12);
+    assert_eq!(block.row(2), &[32, 33, 34]);
+
+    // A view of a view still points into the original storage
+    let inner = block.view(1..3, 1..3).unwrap();
+    assert_eq!(
+        inner.to_matrix(),
+        Matrix::from_rows(vec![vec![23, 24], vec![33, 34]]).unwrap()
+    );
+    assert!(std::ptr::eq(&inner[(0, 0)], &big[(2, 3)]));
+    assert_eq!(big.view(0..7, 0..1).unwrap_err(), MatrixError::OutOfBounds);
+    println!(
+        "Nested view {:?} shares memory with the parent",
+        inner.to_matrix().data
+    );
+
+    println!("\n📋 === MATRIX SUMMARY ===");
+    println!("✅ One Vec<T> in row-major order, indexed with m[(row, col)]");
+    println!("✅ Rows are slices; columns and diagonals are stepping iterators");
+    println!("✅ Operators panic on shape mismatch, checked_* methods return Result");
+    println!("✅ Determinant and inverse use pivoting for numerical stability");
+    println!("✅ Views borrow a block of the parent without copying");
+}
@@ -0,0 +1,585 @@
+/*
+NUMBER THEORY - Primes, Factors and Modular Arithmetic on u64
+=============================================================
+
+WHAT IS THIS?
+- ranges.rs checks primes with trial division up to `(n as f64).sqrt() as i32`
+- That is fine for 2..=50, but it is O(√n) per number and the float cast
+  can round the wrong way for large values
+- This file adds the standard toolbox: sieves, Miller-Rabin, factorization,
+  gcd/lcm, modular exponentiation/inverse and Euler's totient
+
+REAL-WORLD ANALOGY:
+- Trial division is checking every key on a keyring one by one
+- A sieve is crossing names off a class list: once you know 2 is prime,
+  every multiple of 2 is crossed off in one sweep
+- Miller-Rabin is a set of "witness" questions a composite number can't answer correctly
+
+WHICH TOOL FOR WHICH JOB:
+┌─────────────────────┬──────────────────────────────┬─────────────────────────────────┐
+│        Task         │           Function           │            Cost                 │
+├─────────────────────┼──────────────────────────────┼─────────────────────────────────┤
+│ All primes ≤ n      │ sieve(n)                     │ O(n log log n) time, O(n) bytes │
+│ Primes in [lo, hi)  │ segmented_primes(lo, hi)     │ 32 KiB blocks + primes ≤ √hi    │
+│ Is this u64 prime?  │ is_prime(n) (Miller-Rabin)   │ 12 bases, exact for all u64     │
+│ Factor a u64        │ factorize(n)                 │ Trial division + Pollard's rho  │
+│ a^e mod m           │ mod_pow(a, e, m)             │ O(log e), None if m = 0         │
+│ a⁻¹ mod m           │ mod_inverse(a, m)            │ Extended Euclid, None if gcd≠1  │
+│ φ(n)                │ totient(n)                   │ Uses factorize                  │
+└─────────────────────┴──────────────────────────────┴─────────────────────────────────┘
+
+KEY CONCEPTS:
+- Products of two u64 values can overflow, so modular multiplication goes through u128
+- Integer square roots are computed with integers only - no float rounding surprises
+- The demo cross-checks every method against the others on thousands of inputs
+*/
+
+// ============================================================================
+// BASICS: gcd, lcm, integer square root
+// ============================================================================
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// None on overflow instead of silently wrapping
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Largest r with r * r <= n, without going through f64
+fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from above; (n + 1) / 2 is written so it can't overflow
+    let mut x = n;
+    let mut y = n / 2 + (n & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+// The ranges.rs version, kept as a slow reference for cross-checking
+fn is_prime_trial(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    (2..=isqrt(n)).all(|i| !n.is_multiple_of(i))
+}
+
+// ============================================================================
+// SIEVES
+// ============================================================================
+
+// is_prime[i] for every i in 0..=limit
+fn sieve_flags(limit: usize) -> Vec<bool> {
+    let mut flags = vec![true; limit + 1];
+    flags[0] = false;
+    if limit >= 1 {
+        flags[1] = false;
+    }
+
+    let mut p = 2;
+    while p * p <= limit {
+        if flags[p] {
+            // Smaller multiples were already crossed off by smaller primes
+            for multiple in (p * p..=limit).step_by(p) {
+                flags[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    flags
+}
+
+fn sieve(limit: usize) -> Vec<u64> {
+    sieve_flags(limit)
+        .iter()
+        .enumerate()
+        .filter(|(_, is_prime)| **is_prime)
+        .map(|(n, _)| n as u64)
+        .collect()
+}
+
+// Numbers sieved per block: 32 KiB of flags, small enough to stay in L1/L2 cache
+const BLOCK: u64 = 32 * 1024;
+
+// Primes in [low, high), produced one block at a time from a single reused buffer.
+// Only the base primes up to √high are kept (with the next multiple of each to
+// cross off), so [0, 10^10) streams in constant memory apart from those.
+// Near u64::MAX that is ~203 million base primes (a few GB, tens of seconds):
+// to check a handful of huge numbers, is_prime is far cheaper.
+struct SegmentedPrimes {
+    base: Vec<u32>,          // Primes ≤ √(high - 1); they always fit in u32
+    next_multiple: Vec<u64>, // Per base prime: next multiple to cross off
+    block: Vec<bool>,
+    block_low: u64,
+    position: usize, // Next flag to look at in `block`
+    high: u64,
+}
+
+fn segmented_primes(low: u64, high: u64) -> SegmentedPrimes {
+    let limit = if high > 2 { isqrt(high - 1) } else { 0 };
+    // The base primes are a prime range too: small ones come from a plain sieve,
+    // big ones from another segmented pass (limits shrink 2^64 -> 2^32 -> 2^16)
+    let base: Vec<u32> = if limit <= BLOCK {
+        sieve(limit as usize)
+            .into_iter()
+            .map(|p| p as u32)
+            .collect()
+    } else {
+        segmented_primes(2, limit + 1).map(|p| p as u32).collect()
+    };
+    let next_multiple = base
+        .iter()
+        .map(|&p| {
+            let p = p as u64;
+            // First multiple of p inside the range, but never p itself.
+            // Past u64::MAX means "never": nothing in the range to cross off.
+            let first = low.div_ceil(p).saturating_mul(p);
+            first.max(p * p)
+        })
+        .collect();
+
+    SegmentedPrimes {
+        base,
+        next_multiple,
+        block: Vec::with_capacity(BLOCK as usize),
+        block_low: low,
+        position: 0,
+        high,
+    }
+}
+
+impl SegmentedPrimes {
+    // Sieve [block_low, block_low + BLOCK) into the reused buffer
+    fn fill_block(&mut self) {
+        let block_high = self.block_low.saturating_add(BLOCK).min(self.high);
+        self.block.clear();
+        self.block
+            .resize((block_high - self.block_low) as usize, true);
+        for n in self.block_low..block_high.min(2) {
+            self.block[(n - self.block_low) as usize] = false; // 0 and 1
+        }
+
+        for (&p, next) in self.base.iter().zip(&mut self.next_multiple) {
+            let mut multiple = *next;
+            while multiple < block_high {
+                self.block[(multiple - self.block_low) as usize] = false;
+                multiple = multiple.saturating_add(p as u64);
+            }
+            *next = multiple;
+        }
+        self.position = 0;
+    }
+}
+
+impl Iterator for SegmentedPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            while self.position < self.block.len() {
+                let offset = self.position;
+                self.position += 1;
+                if self.block[offset] {
+                    return Some(self.block_low + offset as u64);
+                }
+            }
+            // Move on to the next block (the first call starts the first one)
+            let next_low = self.block_low + self.block.len() as u64;
+            if next_low >= self.high {
+                return None;
+            }
+            self.block_low = next_low;
+            self.fill_block();
+        }
+    }
+}
+
+fn segmented_sieve(low: u64, high: u64) -> Vec<u64> {
+    segmented_primes(low, high).collect()
+}
+
+// ============================================================================
+// MODULAR ARITHMETIC
+// ============================================================================
+
+fn mod_mul(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+// Square-and-multiply: a^e mod m in O(log e) steps; None when m is 0
+fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> Option<u64> {
+    match modulus {
+        0 => return None,
+        1 => return Some(0),
+        _ => {}
+    }
+    let mut result = 1;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    Some(result)
+}
+
+// x such that a * x ≡ 1 (mod m), or None when a and m share a factor
+fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    // Extended Euclid on signed 128-bit values so the coefficients can go negative
+    let (mut old_r, mut r) = (a as i128 % m as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+
+    if old_r != 1 {
+        return if m == 1 { Some(0) } else { None };
+    }
+    Some(old_s.rem_euclid(m as i128) as u64)
+}
+
+// ============================================================================
+// MILLER-RABIN
+// ============================================================================
+
+// Testing these 12 bases is proven to be exact for every n < 3.18 * 10^23,
+// which covers all of u64 - no randomness needed
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // Write n - 1 = d * 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &a in &WITNESSES {
+        let mut x = mod_pow(a, d, n).expect("n > 1");
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false; // `a` proves n is composite
+    }
+    true
+}
+
+// ============================================================================
+// FACTORIZATION
+// ============================================================================
+
+// Pollard's rho with Floyd's cycle detection: finds a non-trivial factor of an odd composite n
+fn pollard_rho(n: u64) -> u64 {
+    // Deterministic: try the polynomials x² + 1, x² + 2, ... until one works
+    for c in 1.. {
+        // Add in u128: x² mod n + c can pass u64::MAX when n is close to it
+        let f = |x: u64| ((mod_mul(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2u64, 2u64, 1u64);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!("1.. never ends")
+}
+
+// Prime factors with multiplicity, sorted: 360 -> [(2, 3), (3, 2), (5, 1)]
+fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut primes = Vec::new();
+    let mut remaining = n;
+
+    // Small factors are quicker by trial division
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while remaining > 1 && remaining.is_multiple_of(p) {
+            primes.push(p);
+            remaining /= p;
+        }
+    }
+
+    // Whatever is left splits into large factors with Pollard's rho
+    let mut stack = vec![remaining];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, count)) if *last == p => *count += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+// φ(n) = n * Π (1 - 1/p) over the distinct primes p dividing n
+fn totient(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .iter()
+        .fold(n, |result, &(p, _)| result / p * (p - 1))
+}
+
+// ============================================================================
+// CROSS-CHECK HELPERS
+// ============================================================================
+
+// Tiny xorshift generator so the checks are repeatable without external crates
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn main() {
+    println!("🔢 === NUMBER THEORY TOOLBOX ===");
+
+    // ========================================================================
+    // 1. SIEVES
+    // ========================================================================
+    println!("\n🧹 === SIEVES ===");
+
+    let small = sieve(50);
+    println!("sieve(50): {:?}", small);
+    let trial: Vec<u64> = (2..=50).filter(|&n| is_prime_trial(n)).collect();
+    assert_eq!(small, trial); // Same answer as ranges.rs
+
+    let window = segmented_sieve(1_000_000_000_000, 1_000_000_000_100);
+    println!("Primes in [10^12, 10^12 + 100): {:?}", window);
+    assert!(window.iter().all(|&p| is_prime(p)));
+
+    // Far-out windows only need the base primes, which come from their own segments
+    for top in [1u64 << 48, 1_000_000_000_000_000] {
+        let checked: Vec<u64> = (top - 100..top).filter(|&n| is_prime(n)).collect();
+        assert_eq!(segmented_sieve(top - 100, top), checked);
+    }
+
+    // Streaming: count primes below 10^7 without holding them, across ~300 blocks
+    let below_ten_million = segmented_primes(0, 10_000_000).count();
+    println!("π(10^7) = {} (streamed block by block)", below_ten_million);
+    assert_eq!(below_ten_million, 664_579);
+
+    // Property: the segmented sieve matches the plain sieve on every window
+    let full = sieve(20_000);
+    for (low, high) in [
+        (0, 1),
+        (0, 100),
+        (2, 3),
+        (90, 97),
+        (97, 98),
+        (1_000, 20_001),
+    ] {
+        let expected: Vec<u64> = full
+            .iter()
+            .copied()
+            .filter(|&p| p >= low && p < high)
+            .collect();
+        assert_eq!(segmented_sieve(low, high), expected, "[{}, {})", low, high);
+    }
+    let many_blocks = sieve(199_999);
+    assert_eq!(segmented_sieve(0, 200_000), many_blocks);
+    println!("π(20 000) = {} (segmented sieve agrees)", full.len());
+    assert_eq!(full.len(), 2_262);
+
+    // ========================================================================
+    // 2. MILLER-RABIN
+    // ========================================================================
+    println!("\n🎲 === MILLER-RABIN ===");
+
+    let largest_u64_prime = 18_446_744_073_709_551_557;
+    let carmichael = 561; // Fools the simple Fermat test, not Miller-Rabin
+    let strong_pseudoprime = 3_215_031_751; // Fools bases 2, 3, 5 and 7 together
+    for n in [
+        largest_u64_prime,
+        u64::MAX,
+        carmichael,
+        strong_pseudoprime,
+        1_000_000_007,
+    ] {
+        println!("  is_prime({}) = {}", n, is_prime(n));
+    }
+    assert!(is_prime(largest_u64_prime));
+    assert!(!is_prime(u64::MAX));
+    assert!(!is_prime(carmichael));
+    assert!(!is_prime(strong_pseudoprime));
+
+    // Property: Miller-Rabin agrees with the sieve for every n ≤ 20 000
+    let flags = sieve_flags(20_000);
+    for (n, &expected) in flags.iter().enumerate() {
+        assert_eq!(is_prime(n as u64), expected, "n = {}", n);
+    }
+    println!("  Agrees with the sieve for all n ≤ 20 000");
+
+    // ========================================================================
+    // 3. FACTORIZATION AND TOTIENT
+    // ========================================================================
+    println!("\n🧩 === FACTORIZATION AND TOTIENT ===");
+
+    for n in [360, 1_001, 600_851_475_143, largest_u64_prime, u64::MAX] {
+        let factors = factorize(n);
+        let shown: Vec<String> = factors
+            .iter()
+            .map(|(p, k)| {
+                if *k == 1 {
+                    p.to_string()
+                } else {
+                    format!("{}^{}", p, k)
+                }
+            })
+            .collect();
+        println!("  {} = {}   φ = {}", n, shown.join(" × "), totient(n));
+    }
+    assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factorize(1), vec![]);
+    // A semiprime of two ~32-bit primes - hopeless for trial division
+    assert_eq!(
+        factorize(4_294_967_291 * 4_294_967_279),
+        vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+    );
+
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..2_000 {
+        let n = rng.next() >> (rng.next() % 64); // Mix of small and huge numbers
+        if n == 0 {
+            continue;
+        }
+        let factors = factorize(n);
+        // Property: factors are prime, sorted, and multiply back to n
+        let product = factors.iter().fold(1u64, |acc, &(p, k)| acc * p.pow(k));
+        assert_eq!(product, n);
+        assert!(factors.iter().all(|&(p, _)| is_prime(p)));
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+    println!("  2 000 random u64 values factor back to themselves");
+
+    // Property: φ(n) equals the count of 1 ≤ k ≤ n with gcd(k, n) = 1
+    for n in 1..=500u64 {
+        let counted = (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64;
+        assert_eq!(totient(n), counted, "n = {}", n);
+    }
+    println!("  totient matches gcd counting for n ≤ 500");
+
+    // ========================================================================
+    // 4. GCD, LCM AND MODULAR ARITHMETIC
+    // ========================================================================
+    println!("\n➗ === GCD, LCM AND MODULAR ARITHMETIC ===");
+
+    println!(
+        "  gcd(48, 18) = {}, lcm(4, 6) = {:?}",
+        gcd(48, 18),
+        lcm(4, 6)
+    );
+    assert_eq!(gcd(48, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(u64::MAX, u64::MAX - 1), None); // Overflow is reported
+
+    println!(
+        "  3^200 mod 1 000 000 007 = {}",
+        mod_pow(3, 200, 1_000_000_007).unwrap()
+    );
+    assert_eq!(mod_pow(2, 10, 1_000), Some(24));
+    assert_eq!(mod_pow(5, 0, 7), Some(1));
+    assert_eq!(mod_pow(5, 3, 0), None); // No arithmetic modulo 0
+    assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), Some(1)); // No overflow thanks to u128
+
+    println!(
+        "  3⁻¹ mod 11 = {:?}, 6⁻¹ mod 9 = {:?}",
+        mod_inverse(3, 11),
+        mod_inverse(6, 9)
+    );
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(6, 9), None);
+
+    for _ in 0..2_000 {
+        let m = rng.next() % 1_000_000 + 2;
+        let a = rng.next();
+        // Property: an inverse exists exactly when gcd(a, m) = 1
+        match mod_inverse(a, m) {
+            Some(x) => assert_eq!(mod_mul(a % m, x, m), 1),
+            None => assert_ne!(gcd(a % m, m), 1),
+        }
+        // Property: Fermat's little theorem for prime moduli
+        if is_prime(m) && !a.is_multiple_of(m) {
+            assert_eq!(mod_pow(a, m - 1, m), Some(1));
+        }
+        // Property: lcm * gcd = a * b
+        let b = rng.next() % 100_000 + 1;
+        let a_small = a % 100_000 + 1;
+        assert_eq!(lcm(a_small, b).unwrap() * gcd(a_small, b), a_small * b);
+    }
+    println!("  2 000 random inverse / Fermat / lcm checks passed");
+
+    // isqrt is exact where the f64 shortcut from ranges.rs is not:
+    // k² - 1 rounds up to k² as an f64, so the float version answers k instead of k - 1
+    let k = 4_294_967_295u64;
+    let just_below = k * k - 1;
+    println!(
+        "  isqrt({}) = {}, f64 sqrt = {}",
+        just_below,
+        isqrt(just_below),
+        (just_below as f64).sqrt() as u64
+    );
+    assert_eq!(isqrt(just_below), k - 1);
+    assert_eq!(isqrt(k * k), k);
+    assert_eq!(isqrt(u64::MAX), 4_294_967_295);
+
+    println!("\n📋 === NUMBER THEORY SUMMARY ===");
+    println!("✅ Sieve for all primes up to n, segmented sieve for far-away windows");
+    println!("✅ Miller-Rabin with 12 fixed bases is exact for every u64");
+    println!("✅ Pollard's rho factors numbers trial division never could");
+    println!("✅ u128 intermediates keep mod_mul / mod_pow overflow-free");
+    println!("✅ Every method is cross-checked against another one in main()");
+}
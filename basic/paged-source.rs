@@ -0,0 +1,677 @@
+/*
+PAGED SOURCE - Cursor Pagination Behind an Iterator
+===================================================
+
+WHAT IS THIS?
+- while-let.rs has a `DataSource` with `fetch_next()` that clones one String
+  out of a Vec and bumps an index by hand
+- Real data sources (HTTP APIs, databases, big files) hand out data in PAGES,
+  and tell you where to continue with an opaque CURSOR ("next_page_token")
+- This file defines a `PagedSource` trait for that, plus an adapter that turns any
+  paged source into a plain `Iterator`, fetches the next page in the background while
+  you work on the current one, retries failed pages, and can resume from a checkpoint
+
+REAL-WORLD ANALOGY:
+- Reading a long book from a library that lends one chapter at a time:
+  while you read chapter 3, a friend is already fetching chapter 4 (prefetch);
+  if the library is closed, the friend tries again later (retry);
+  and your bookmark says "chapter 5, page 2" so you can stop and resume (checkpoint)
+
+HOW THE PIECES FIT:
+┌────────────────────┬──────────────────────────────────────────────────────┐
+│ Piece              │ Job                                                  │
+├────────────────────┼──────────────────────────────────────────────────────┤
+│ Cursor             │ Opaque "where to continue" token - only the source   │
+│                    │ that issued it knows what's inside                   │
+│ PagedSource        │ fetch_page(cursor, size) -> Page { items, next }     │
+│ PagedIter          │ Iterator over items; owns a worker thread that       │
+│                    │ fetches page N+1 while page N is being consumed      │
+│ ResumePoint        │ (page cursor, items already consumed in that page)   │
+│ InMemorySource     │ Pages over a shared Vec                              │
+│ LineFileSource     │ Pages of lines; the cursor is a byte offset to seek  │
+└────────────────────┴──────────────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- The source moves into a worker thread; requests and pages travel over channels
+- Only retryable errors are retried, with exponential backoff capped at 30s;
+  a missing or unreadable file is permanent
+- Each page is fetched from its own cursor, so any page can be re-fetched or resumed
+*/
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// CURSORS, PAGES, ERRORS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct Cursor(String);
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Offset-based cursors, tagged so one source's cursor can't be fed to another
+fn offset_cursor(tag: &str, offset: u64) -> Cursor {
+    Cursor(format!("{}:{:x}", tag, offset))
+}
+
+fn parse_offset_cursor(tag: &str, cursor: Option<&Cursor>) -> Result<u64, PageError> {
+    let Some(cursor) = cursor else {
+        return Ok(0);
+    };
+    cursor
+        .0
+        .strip_prefix(tag)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| {
+            PageError::permanent(format!("cursor '{}' was not issued by this source", cursor))
+        })
+}
+
+#[derive(Debug)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<Cursor>, // None = this was the last page
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PageError {
+    message: String,
+    retryable: bool,
+}
+
+impl PageError {
+    fn transient(message: impl Into<String>) -> Self {
+        PageError {
+            message: message.into(),
+            retryable: true,
+        }
+    }
+
+    fn permanent(message: impl Into<String>) -> Self {
+        PageError {
+            message: message.into(),
+            retryable: false,
+        }
+    }
+
+    // A missing file or bad permissions won't fix themselves; interrupted or
+    // timed-out I/O might
+    fn from_io(context: &str, error: io::Error) -> Self {
+        let message = format!("{}: {}", context, error);
+        match error.kind() {
+            io::ErrorKind::NotFound
+            | io::ErrorKind::PermissionDenied
+            | io::ErrorKind::IsADirectory
+            | io::ErrorKind::InvalidInput
+            | io::ErrorKind::InvalidData => PageError::permanent(message),
+            _ => PageError::transient(message),
+        }
+    }
+}
+
+impl fmt::Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.retryable {
+            "transient"
+        } else {
+            "permanent"
+        };
+        write!(f, "{} page error: {}", kind, self.message)
+    }
+}
+
+trait PagedSource {
+    type Item;
+
+    // cursor None = first page
+    fn fetch_page(
+        &mut self,
+        cursor: Option<&Cursor>,
+        page_size: usize,
+    ) -> Result<Page<Self::Item>, PageError>;
+}
+
+// ============================================================================
+// IMPLEMENTATIONS
+// ============================================================================
+
+// Pages over a shared Vec; each page clones only its own items
+struct InMemorySource<T> {
+    data: Arc<Vec<T>>,
+}
+
+impl<T> InMemorySource<T> {
+    fn new(data: Vec<T>) -> Self {
+        InMemorySource {
+            data: Arc::new(data),
+        }
+    }
+}
+
+impl<T: Clone> PagedSource for InMemorySource<T> {
+    type Item = T;
+
+    fn fetch_page(
+        &mut self,
+        cursor: Option<&Cursor>,
+        page_size: usize,
+    ) -> Result<Page<T>, PageError> {
+        let start = parse_offset_cursor("mem", cursor)? as usize;
+        let start = start.min(self.data.len());
+        let end = (start + page_size).min(self.data.len());
+        Ok(Page {
+            items: self.data[start..end].to_vec(),
+            next: (end < self.data.len()).then(|| offset_cursor("mem", end as u64)),
+        })
+    }
+}
+
+// One line per item; the cursor is the byte offset of the next unread line,
+// so a page can be fetched with a single seek - even by a different process
+struct LineFileSource {
+    path: PathBuf,
+    reader: Option<BufReader<File>>,
+}
+
+impl LineFileSource {
+    fn new(path: impl Into<PathBuf>) -> Self {
+        LineFileSource {
+            path: path.into(),
+            reader: None,
+        }
+    }
+}
+
+impl PagedSource for LineFileSource {
+    type Item = String;
+
+    fn fetch_page(
+        &mut self,
+        cursor: Option<&Cursor>,
+        page_size: usize,
+    ) -> Result<Page<String>, PageError> {
+        let offset = parse_offset_cursor("file", cursor)?;
+        if self.reader.is_none() {
+            let file = File::open(&self.path)
+                .map_err(|e| PageError::from_io(&format!("opening {}", self.path.display()), e))?;
+            self.reader = Some(BufReader::new(file));
+        }
+        let reader = self.reader.as_mut().expect("opened above");
+        reader
+            .seek(SeekFrom::Start(offset))
+            .map_err(|e| PageError::from_io("seek", e))?;
+
+        let mut items = Vec::with_capacity(page_size);
+        let mut position = offset;
+        let mut line = String::new();
+        while items.len() < page_size {
+            line.clear();
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|e| PageError::from_io("read", e))?;
+            if read == 0 {
+                return Ok(Page { items, next: None });
+            }
+            position += read as u64;
+            items.push(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+        // Peek: only hand out a cursor if there is something after this page
+        let more = !reader
+            .fill_buf()
+            .map_err(|e| PageError::transient(format!("read: {}", e)))?
+            .is_empty();
+        Ok(Page {
+            items,
+            next: more.then(|| offset_cursor("file", position)),
+        })
+    }
+}
+
+// Test helper: fails the first attempt of every `every`-th fetch
+struct Flaky<S> {
+    inner: S,
+    every: usize,
+    calls: usize,
+    failed_last: bool,
+}
+
+impl<S: PagedSource> PagedSource for Flaky<S> {
+    type Item = S::Item;
+
+    fn fetch_page(
+        &mut self,
+        cursor: Option<&Cursor>,
+        page_size: usize,
+    ) -> Result<Page<S::Item>, PageError> {
+        self.calls += 1;
+        if !self.failed_last && self.calls.is_multiple_of(self.every) {
+            self.failed_last = true;
+            return Err(PageError::transient("503 Service Unavailable"));
+        }
+        self.failed_last = false;
+        self.inner.fetch_page(cursor, page_size)
+    }
+}
+
+// ============================================================================
+// THE ITERATOR ADAPTER
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct ResumePoint {
+    page: Option<Cursor>, // Cursor of the page being consumed (None = first page)
+    skip: usize,          // Items of that page already handed out
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+impl RetryPolicy {
+    // initial, 2x, 4x, ... capped so a large max_retries can't overflow or sleep forever
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_BACKOFF)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct FetchStats {
+    pages_requested: usize,
+    pages_received: usize,
+    retries: usize,
+}
+
+type Response<T> = Result<Page<T>, PageError>;
+
+struct PagedIter<T> {
+    requests: Option<Sender<Option<Cursor>>>,
+    responses: Receiver<Response<T>>,
+    worker: Option<JoinHandle<()>>,
+    buffer: VecDeque<T>,
+    in_flight: Option<Option<Cursor>>, // Cursor of the page being fetched right now
+    current: ResumePoint,
+    skip_on_arrival: usize, // From a resume: items to drop from the first page
+    retry: RetryPolicy,
+    attempts: u32,
+    stats: FetchStats,
+    done: bool,
+}
+
+impl<T: Send + 'static> PagedIter<T> {
+    fn new<S>(source: S, page_size: usize) -> Self
+    where
+        S: PagedSource<Item = T> + Send + 'static,
+    {
+        Self::resume(
+            source,
+            page_size,
+            ResumePoint {
+                page: None,
+                skip: 0,
+            },
+        )
+    }
+
+    fn resume<S>(mut source: S, page_size: usize, from: ResumePoint) -> Self
+    where
+        S: PagedSource<Item = T> + Send + 'static,
+    {
+        // An empty page would point back at the same cursor and never finish
+        assert!(page_size > 0, "page size must be at least 1");
+        let (request_tx, request_rx) = mpsc::channel::<Option<Cursor>>();
+        let (response_tx, response_rx) = mpsc::channel();
+        let worker = thread::spawn(move || {
+            // Ends when the iterator drops its request sender
+            for cursor in request_rx {
+                let page = source.fetch_page(cursor.as_ref(), page_size);
+                if response_tx.send(page).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut iter = PagedIter {
+            requests: Some(request_tx),
+            responses: response_rx,
+            worker: Some(worker),
+            buffer: VecDeque::new(),
+            in_flight: None,
+            current: from.clone(),
+            skip_on_arrival: from.skip,
+            retry: RetryPolicy {
+                max_retries: 3,
+                initial_backoff: Duration::from_millis(5),
+            },
+            attempts: 0,
+            stats: FetchStats::default(),
+            done: false,
+        };
+        iter.stats.pages_requested += 1;
+        iter.request(from.page);
+        iter
+    }
+
+    fn with_retry(mut self, max_retries: u32, initial_backoff: Duration) -> Self {
+        self.retry = RetryPolicy {
+            max_retries,
+            initial_backoff,
+        };
+        self
+    }
+
+    // Sends a fetch to the worker; callers count new pages vs retries
+    fn request(&mut self, cursor: Option<Cursor>) {
+        self.in_flight = Some(cursor.clone());
+        if let Some(tx) = &self.requests {
+            tx.send(cursor).expect("worker thread stopped unexpectedly");
+        }
+    }
+
+    // Where to restart so that the next item yielded would be the same as ours
+    fn checkpoint(&self) -> ResumePoint {
+        self.current.clone()
+    }
+
+    fn stats(&self) -> FetchStats {
+        self.stats
+    }
+}
+
+impl<T: Send + 'static> Iterator for PagedIter<T> {
+    type Item = Result<T, PageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                self.current.skip += 1;
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+            let Some(cursor) = self.in_flight.take() else {
+                self.done = true; // Last page consumed, nothing in flight
+                return None;
+            };
+
+            match self
+                .responses
+                .recv()
+                .expect("worker thread stopped unexpectedly")
+            {
+                Ok(page) => {
+                    self.stats.pages_received += 1;
+                    self.attempts = 0;
+                    // Prefetch: ask for page N+1 before handing out anything from page N
+                    if let Some(next) = page.next {
+                        self.stats.pages_requested += 1;
+                        self.request(Some(next));
+                    }
+                    self.current = ResumePoint {
+                        page: cursor,
+                        skip: self.skip_on_arrival,
+                    };
+                    self.buffer = page.items.into_iter().skip(self.skip_on_arrival).collect();
+                    self.skip_on_arrival = 0;
+                }
+                Err(e) if e.retryable && self.attempts < self.retry.max_retries => {
+                    let backoff = self.retry.backoff(self.attempts);
+                    self.attempts += 1;
+                    self.stats.retries += 1;
+                    thread::sleep(backoff);
+                    self.request(cursor);
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for PagedIter<T> {
+    fn drop(&mut self) {
+        self.requests.take(); // Closes the channel so the worker's loop ends
+        if let Some(worker) = self.worker.take() {
+            worker.join().ok();
+        }
+    }
+}
+
+// ============================================================================
+// DEMO HELPERS
+// ============================================================================
+
+// Simulates a slow backend so prefetching has something to hide
+struct Slow<S> {
+    inner: S,
+    delay: Duration,
+}
+
+impl<S: PagedSource> PagedSource for Slow<S> {
+    type Item = S::Item;
+
+    fn fetch_page(
+        &mut self,
+        cursor: Option<&Cursor>,
+        page_size: usize,
+    ) -> Result<Page<S::Item>, PageError> {
+        thread::sleep(self.delay);
+        self.inner.fetch_page(cursor, page_size)
+    }
+}
+
+struct AlwaysDown;
+
+impl PagedSource for AlwaysDown {
+    type Item = String;
+
+    fn fetch_page(&mut self, _: Option<&Cursor>, _: usize) -> Result<Page<String>, PageError> {
+        Err(PageError::transient("connection reset"))
+    }
+}
+
+fn records(n: usize) -> Vec<String> {
+    (1..=n).map(|i| format!("Record {}", i)).collect()
+}
+
+fn main() {
+    println!("📚 === PAGED SOURCE ===");
+
+    // ========================================================================
+    // 1. THE while-let.rs LOOP, OVER PAGES
+    // ========================================================================
+    println!("\n🔁 === while let OVER A PAGED SOURCE ===");
+
+    let mut source = PagedIter::new(InMemorySource::new(records(4)), 2);
+    let mut record_count = 0;
+    while let Some(Ok(record)) = source.next() {
+        record_count += 1;
+        println!("  Record {}: {}", record_count, record);
+    }
+    println!("  All records fetched! Total: {}", record_count);
+    assert_eq!(record_count, 4);
+    assert_eq!(source.stats().pages_received, 2);
+
+    // It's an Iterator, so all the adapters work
+    let odd: Vec<String> = PagedIter::new(InMemorySource::new(records(10)), 3)
+        .filter_map(Result::ok)
+        .filter(|r| r.ends_with(['1', '3', '5', '7', '9']))
+        .collect();
+    println!("  Odd records via .filter(): {:?}", odd);
+    assert_eq!(odd.len(), 5);
+
+    // ========================================================================
+    // 2. CURSORS ARE OPAQUE AND CHECKED
+    // ========================================================================
+    println!("\n🎟️  === CURSORS ===");
+
+    let mut mem = InMemorySource::new(records(5));
+    let first = mem.fetch_page(None, 2).unwrap();
+    println!(
+        "  first page: {:?}, next cursor: {:?}",
+        first.items, first.next
+    );
+    let second = mem.fetch_page(first.next.as_ref(), 2).unwrap();
+    println!(
+        "  second page: {:?}, next cursor: {:?}",
+        second.items, second.next
+    );
+    let foreign = Cursor("file:10".to_string());
+    let err = mem.fetch_page(Some(&foreign), 2).unwrap_err();
+    println!("  someone else's cursor: {}", err);
+    assert!(!err.retryable);
+
+    // ========================================================================
+    // 3. PREFETCHING
+    // ========================================================================
+    println!("\n⚡ === PREFETCH ===");
+
+    let delay = Duration::from_millis(20);
+    let slow = Slow {
+        inner: InMemorySource::new(records(12)),
+        delay,
+    };
+    let start = Instant::now();
+    let mut iter = PagedIter::new(slow, 3);
+    let first = iter.next().unwrap().unwrap();
+    // The moment page 1 arrived, page 2 was requested - before we saw "Record 1"
+    assert_eq!(iter.stats().pages_requested, 2);
+    println!("  got {:?}; page 2 already requested", first);
+    for item in iter.by_ref() {
+        item.unwrap();
+        thread::sleep(delay / 3); // "Work" on each item: a page's worth ~= one fetch
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "  4 pages x {:?} fetch + {:?} of work took {:?} (sequential would be ~{:?})",
+        delay,
+        delay / 3 * 11,
+        elapsed,
+        delay * 4 + delay / 3 * 11
+    );
+
+    // ========================================================================
+    // 4. RETRY ON PAGE ERRORS
+    // ========================================================================
+    println!("\n🔂 === RETRY ===");
+
+    let flaky = Flaky {
+        inner: InMemorySource::new(records(10)),
+        every: 2,
+        calls: 0,
+        failed_last: false,
+    };
+    let mut iter = PagedIter::new(flaky, 2).with_retry(3, Duration::from_millis(1));
+    let items: Vec<String> = iter.by_ref().map(Result::unwrap).collect();
+    println!("  {} items, stats: {:?}", items.len(), iter.stats());
+    assert_eq!(items, records(10));
+    assert!(iter.stats().retries >= 2);
+    assert_eq!(iter.stats().pages_received, 5);
+
+    // Out of retries -> one Err, then the iterator ends
+    let results: Vec<_> = PagedIter::new(AlwaysDown, 2)
+        .with_retry(2, Duration::from_millis(1))
+        .collect();
+    println!("  always failing source -> {:?}", results);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+
+    // ========================================================================
+    // 5. RESUME FROM A CHECKPOINT
+    // ========================================================================
+    println!("\n🔖 === RESUME ===");
+
+    let data = records(10);
+    let mut iter = PagedIter::new(InMemorySource::new(data.clone()), 4);
+    let before: Vec<String> = iter.by_ref().take(6).map(Result::unwrap).collect();
+    let checkpoint = iter.checkpoint();
+    drop(iter); // "Crash" mid-page
+    println!("  consumed {:?}", before);
+    println!("  checkpoint: {:?}", checkpoint);
+
+    let after: Vec<String> = PagedIter::resume(InMemorySource::new(data.clone()), 4, checkpoint)
+        .map(Result::unwrap)
+        .collect();
+    println!("  resumed with {:?}", after);
+    assert_eq!([before, after].concat(), data);
+
+    // ========================================================================
+    // 6. FILE-BACKED SOURCE
+    // ========================================================================
+    println!("\n📄 === LINE FILE SOURCE ===");
+
+    let path = std::env::temp_dir().join("rust-learning-paged-source.txt");
+    {
+        let mut file = File::create(&path).unwrap();
+        for i in 1..=23 {
+            writeln!(file, "line {:02}", i).unwrap();
+        }
+    }
+
+    let mut iter = PagedIter::new(LineFileSource::new(&path), 5);
+    let first_eight: Vec<String> = iter.by_ref().take(8).map(Result::unwrap).collect();
+    let checkpoint = iter.checkpoint();
+    println!(
+        "  read {} lines, checkpoint {:?}",
+        first_eight.len(),
+        checkpoint
+    );
+    drop(iter);
+
+    // A fresh source (think: next run of the program) seeks straight to the page
+    let mut iter = PagedIter::resume(LineFileSource::new(&path), 5, checkpoint);
+    let rest: Vec<String> = iter.by_ref().map(Result::unwrap).collect();
+    println!("  resumed: {} .. {}", rest[0], rest[rest.len() - 1]);
+    assert_eq!(first_eight.len() + rest.len(), 23);
+    assert_eq!(rest[0], "line 09");
+    assert_eq!(rest.last().unwrap(), "line 23");
+    assert_eq!(iter.stats().pages_received, 4); // pages 2..=5 (of 5 lines each)
+
+    // A missing file is permanent: no retries, the stream ends at once
+    let mut missing = PagedIter::new(LineFileSource::new("/no/such/file.txt"), 5)
+        .with_retry(3, Duration::from_millis(1));
+    let error = missing.next().unwrap().unwrap_err();
+    println!("  missing file: {}", error);
+    assert!(!error.retryable);
+    assert_eq!(missing.stats().retries, 0);
+    assert!(missing.next().is_none());
+
+    // Backoff doubles per attempt but never overflows past the cap
+    let policy = RetryPolicy {
+        max_retries: 100,
+        initial_backoff: Duration::from_millis(5),
+    };
+    assert_eq!(policy.backoff(0), Duration::from_millis(5));
+    assert_eq!(policy.backoff(3), Duration::from_millis(40));
+    assert_eq!(policy.backoff(40), MAX_BACKOFF);
+    assert_eq!(policy.backoff(u32::MAX), MAX_BACKOFF);
+    std::fs::remove_file(&path).ok();
+
+    println!("\n📋 === PAGED SOURCE SUMMARY ===");
+    println!("✅ PagedSource fetches pages by opaque cursor; the adapter makes it an Iterator");
+    println!("✅ Page N+1 is fetched on a worker thread while page N is consumed");
+    println!("✅ Transient page errors are retried with backoff; permanent ones end the stream");
+    println!("✅ checkpoint() + resume() continue exactly where a previous run stopped");
+    println!("✅ LineFileSource cursors are byte offsets, so resuming is a single seek");
+}
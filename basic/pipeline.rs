@@ -0,0 +1,391 @@
+/*
+FUNCTION PIPELINES - Reusable, Composable Processing Stages
+===========================================================
+
+WHAT IS A PIPELINE?
+- A pipeline is a chain of small stages that each do one job
+- Every stage takes the output of the previous stage as its input
+- Stages are closures stored in a struct, so a pipeline can be built once and reused
+- This grows `apply_operation` from closure.rs (one Fn(i32) -> i32 over a slice)
+  into a generic type that chains many operations
+
+REAL-WORLD ANALOGY:
+- Like a factory conveyor belt with stations: wash → cut → inspect → pack
+- Each station can change the item (map), throw it away (filter),
+  look at it without touching it (tap), or stop the whole line (try_map error)
+- Two belts can be joined end to end (then) to make a longer one
+
+STAGE TYPES:
+┌──────────────┬──────────────────────────────────┬─────────────────────────────────────┐
+│    Stage     │          Closure type            │             Effect                  │
+├──────────────┼──────────────────────────────────┼─────────────────────────────────────┤
+│ map          │ Fn(U) -> V                       │ Transform every item                │
+│ filter       │ Fn(&U) -> bool                   │ Drop items that don't match         │
+│ tap          │ Fn(&U)                           │ Observe items (logging, counting)   │
+│ try_map      │ Fn(U) -> Result<V, E>            │ Transform, stop on the first error  │
+│ then         │ Pipeline<U, V, E>                │ Append another pipeline's stages    │
+└──────────────┴──────────────────────────────────┴─────────────────────────────────────┘
+
+KEY CONCEPTS:
+- Stages are stored as Arc<dyn Fn ... + Send + Sync> so pipelines can be cloned
+  cheaply and shared with worker threads
+- Each item flows through all stages before the next item starts (lazy, no temp Vecs)
+- Result<Option<V>, E> encodes the three outcomes: keep, skip (filtered), fail
+- run_parallel splits input into chunks, runs each chunk on a std thread,
+  and reassembles the output in the original order
+*/
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// What a pipeline does to one item:
+// Ok(Some(v)) = keep v, Ok(None) = filtered out, Err(e) = stop everything
+type StageFn<T, U, E> = Arc<dyn Fn(T) -> Result<Option<U>, E> + Send + Sync>;
+
+struct Pipeline<T, U, E> {
+    name: Option<String>,
+    stages: Vec<String>, // Human-readable description of each stage
+    run_one: StageFn<T, U, E>,
+}
+
+impl<T: 'static, E: 'static> Pipeline<T, T, E> {
+    // Start with an empty pipeline that passes items through unchanged
+    fn new() -> Self {
+        Pipeline {
+            name: None,
+            stages: Vec::new(),
+            run_one: Arc::new(|item| Ok(Some(item))),
+        }
+    }
+}
+
+impl<T: 'static, U: 'static, E: 'static> Pipeline<T, U, E> {
+    fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    // Every builder method wraps the existing closure in a new one,
+    // so the stages run in the order they were added
+    fn push_stage<V: 'static>(
+        self,
+        description: String,
+        stage: impl Fn(U) -> Result<Option<V>, E> + Send + Sync + 'static,
+    ) -> Pipeline<T, V, E> {
+        let previous = self.run_one;
+        let mut stages = self.stages;
+        stages.push(description);
+
+        Pipeline {
+            name: self.name,
+            stages,
+            run_one: Arc::new(move |item| match previous(item)? {
+                Some(value) => stage(value),
+                None => Ok(None), // Already filtered out - skip later stages
+            }),
+        }
+    }
+
+    fn map<V: 'static>(
+        self,
+        label: &str,
+        f: impl Fn(U) -> V + Send + Sync + 'static,
+    ) -> Pipeline<T, V, E> {
+        self.push_stage(format!("map({})", label), move |item| Ok(Some(f(item))))
+    }
+
+    fn filter(
+        self,
+        label: &str,
+        predicate: impl Fn(&U) -> bool + Send + Sync + 'static,
+    ) -> Pipeline<T, U, E> {
+        self.push_stage(format!("filter({})", label), move |item| {
+            Ok(if predicate(&item) { Some(item) } else { None })
+        })
+    }
+
+    fn tap(self, label: &str, f: impl Fn(&U) + Send + Sync + 'static) -> Pipeline<T, U, E> {
+        self.push_stage(format!("tap({})", label), move |item| {
+            f(&item);
+            Ok(Some(item))
+        })
+    }
+
+    fn try_map<V: 'static>(
+        self,
+        label: &str,
+        f: impl Fn(U) -> Result<V, E> + Send + Sync + 'static,
+    ) -> Pipeline<T, V, E> {
+        self.push_stage(format!("try_map({})", label), move |item| f(item).map(Some))
+    }
+
+    // Compose two pipelines: items leave `self` and enter `next`
+    fn then<V: 'static>(self, next: Pipeline<U, V, E>) -> Pipeline<T, V, E> {
+        let first = self.run_one;
+        let second = next.run_one;
+        let mut stages = self.stages;
+        stages.extend(next.stages);
+
+        Pipeline {
+            name: self.name.or(next.name),
+            stages,
+            run_one: Arc::new(move |item| match first(item)? {
+                Some(value) => second(value),
+                None => Ok(None),
+            }),
+        }
+    }
+
+    // Run every item through all stages; the first error stops the run
+    fn run<I: IntoIterator<Item = T>>(&self, input: I) -> Result<Vec<U>, E> {
+        let mut output = Vec::new();
+        for item in input {
+            if let Some(value) = (self.run_one)(item)? {
+                output.push(value);
+            }
+        }
+        Ok(output)
+    }
+}
+
+impl<T, U, E> Pipeline<T, U, E>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+    E: Send + 'static,
+{
+    // Split the input into `workers` chunks and process each on its own thread.
+    // Output keeps the input order; if any chunk fails, the error from the
+    // earliest failing chunk is returned.
+    fn run_parallel(&self, input: Vec<T>, workers: usize) -> Result<Vec<U>, E> {
+        let workers = workers.max(1);
+        let chunk_size = input.len().div_ceil(workers).max(1);
+
+        let mut chunks: Vec<Vec<T>> = Vec::new();
+        let mut items = input.into_iter().peekable();
+        while items.peek().is_some() {
+            chunks.push(items.by_ref().take(chunk_size).collect());
+        }
+
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                let run_one = Arc::clone(&self.run_one);
+                thread::spawn(move || {
+                    let mut output = Vec::with_capacity(chunk.len());
+                    for item in chunk {
+                        if let Some(value) = run_one(item)? {
+                            output.push(value);
+                        }
+                    }
+                    Ok(output)
+                })
+            })
+            .collect();
+
+        let mut output = Vec::new();
+        let mut first_error = None;
+        for handle in handles {
+            // Join every thread before returning, even after an error
+            match handle.join().expect("pipeline worker panicked") {
+                Ok(part) => output.extend(part),
+                Err(e) => {
+                    if first_error.is_none() {
+                        first_error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(output),
+        }
+    }
+}
+
+// Clone is cheap: the closure chain is shared through the Arc
+impl<T, U, E> Clone for Pipeline<T, U, E> {
+    fn clone(&self) -> Self {
+        Pipeline {
+            name: self.name.clone(),
+            stages: self.stages.clone(),
+            run_one: Arc::clone(&self.run_one),
+        }
+    }
+}
+
+// Closures can't be printed, so Debug shows the stage descriptions instead
+impl<T, U, E> fmt::Debug for Pipeline<T, U, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name.as_deref().unwrap_or("<unnamed>");
+        f.debug_struct("Pipeline")
+            .field("name", &name)
+            .field("stages", &self.stages)
+            .finish()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum EtlError {
+    BadNumber(String),
+    OutOfRange(i64),
+}
+
+fn main() {
+    println!("🔗 === FUNCTION PIPELINE COMBINATORS ===");
+
+    // ========================================================================
+    // 1. FROM apply_operation TO A PIPELINE
+    // ========================================================================
+    println!("\n1️⃣ One stage - same as apply_operation(&nums, |x| x * 2):");
+
+    let doubler: Pipeline<i32, i32, ()> = Pipeline::new().named("doubler").map("x * 2", |x| x * 2);
+    let doubled = doubler.run(vec![1, 2, 3, 4, 5]).unwrap();
+    println!("  {:?}", doubler);
+    println!("  Doubled: {:?}", doubled);
+    assert_eq!(doubled, vec![2, 4, 6, 8, 10]);
+
+    // ========================================================================
+    // 2. CHAINING MAP / FILTER / TAP
+    // ========================================================================
+    println!("\n2️⃣ Chaining stages:");
+
+    let seen = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&seen);
+    let odd_squares: Pipeline<i32, i32, ()> = Pipeline::new()
+        .named("odd-squares")
+        .tap("count input", move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })
+        .filter("odd", |x| x % 2 == 1)
+        .map("square", |x| x * x);
+
+    let result = odd_squares.run(1..=10).unwrap();
+    println!("  {:?}", odd_squares);
+    println!("  Odd squares: {:?}", result);
+    println!("  Items seen by tap: {}", seen.load(Ordering::SeqCst));
+    assert_eq!(result, vec![1, 9, 25, 49, 81]);
+    assert_eq!(seen.load(Ordering::SeqCst), 10);
+
+    // ========================================================================
+    // 3. try_map SHORT-CIRCUITS ON THE FIRST ERROR
+    // ========================================================================
+    println!("\n3️⃣ try_map with errors:");
+
+    let parse: Pipeline<&str, i64, EtlError> = Pipeline::new()
+        .named("parse")
+        .map("trim", |s: &str| s.trim())
+        .filter("non-empty", |s| !s.is_empty())
+        .try_map("to i64", |s| {
+            s.parse::<i64>()
+                .map_err(|_| EtlError::BadNumber(s.to_string()))
+        });
+
+    let good = parse.run(vec![" 10", "20 ", "", "30"]);
+    println!("  Clean input:  {:?}", good);
+    assert_eq!(good, Ok(vec![10, 20, 30]));
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let processed_counter = Arc::clone(&processed);
+    let counting_parse = parse.clone().tap("count parsed", move |_| {
+        processed_counter.fetch_add(1, Ordering::SeqCst);
+    });
+    let bad = counting_parse.run(vec!["1", "two", "3"]);
+    println!("  Dirty input:  {:?}", bad);
+    println!(
+        "  Items parsed before stopping: {}",
+        processed.load(Ordering::SeqCst)
+    );
+    assert_eq!(bad, Err(EtlError::BadNumber("two".to_string())));
+    assert_eq!(processed.load(Ordering::SeqCst), 1); // "3" never ran
+
+    // ========================================================================
+    // 4. COMPOSING PIPELINES WITH then
+    // ========================================================================
+    println!("\n4️⃣ Composing reusable stages with then:");
+
+    let validate: Pipeline<i64, i64, EtlError> =
+        Pipeline::new().named("validate").try_map("0..=100", |n| {
+            if (0..=100).contains(&n) {
+                Ok(n)
+            } else {
+                Err(EtlError::OutOfRange(n))
+            }
+        });
+    let to_percent: Pipeline<i64, String, EtlError> =
+        Pipeline::new().map("format %", |n| format!("{}%", n));
+
+    let etl = parse.clone().then(validate).then(to_percent).named("etl");
+    println!("  {:?}", etl);
+    println!("  Stage count: {}", etl.stages.len());
+    assert_eq!(
+        etl.stages,
+        vec![
+            "map(trim)",
+            "filter(non-empty)",
+            "try_map(to i64)",
+            "try_map(0..=100)",
+            "map(format %)"
+        ]
+    );
+
+    let percents = etl.run(vec!["5", "50", "100"]);
+    println!("  Valid rows:   {:?}", percents);
+    assert_eq!(
+        percents,
+        Ok(vec![
+            "5%".to_string(),
+            "50%".to_string(),
+            "100%".to_string()
+        ])
+    );
+
+    let out_of_range = etl.run(vec!["5", "500"]);
+    println!("  Invalid rows: {:?}", out_of_range);
+    assert_eq!(out_of_range, Err(EtlError::OutOfRange(500)));
+
+    // ========================================================================
+    // 5. PARALLEL EXECUTION OVER CHUNKS
+    // ========================================================================
+    println!("\n5️⃣ Parallel execution with std threads:");
+
+    let heavy: Pipeline<u64, u64, ()> = Pipeline::new()
+        .named("heavy")
+        .filter("multiple of 3", |n| n % 3 == 0)
+        .map("sum of 1..=n", |n| (1..=n).sum());
+
+    let input: Vec<u64> = (1..=1_000).collect();
+    let sequential = heavy.run(input.clone()).unwrap();
+    let parallel = heavy.run_parallel(input, 4).unwrap();
+    println!(
+        "  Sequential: {} items, first five {:?}",
+        sequential.len(),
+        &sequential[..5]
+    );
+    println!(
+        "  Parallel:   {} items, first five {:?}",
+        parallel.len(),
+        &parallel[..5]
+    );
+    assert_eq!(sequential, parallel); // Same items, same order
+
+    // Errors from worker threads come back just like in run()
+    let parallel_err = etl.run_parallel(vec!["1", "2", "300", "4", "-5", "6"], 3);
+    println!("  Parallel error: {:?}", parallel_err);
+    assert_eq!(parallel_err, Err(EtlError::OutOfRange(300)));
+
+    // Edge cases: empty input and more workers than items
+    assert_eq!(heavy.run_parallel(Vec::new(), 8), Ok(Vec::new()));
+    assert_eq!(heavy.run_parallel(vec![3, 6], 8), Ok(vec![6, 21]));
+
+    println!("\n📋 === PIPELINE SUMMARY ===");
+    println!("✅ Stages are closures stored behind Arc<dyn Fn> - build once, run many times");
+    println!("✅ map / filter / tap / try_map cover transform, drop, observe, and fail");
+    println!("✅ try_map stops at the first error, just like the ? operator");
+    println!("✅ then() glues reusable pipelines together");
+    println!("✅ Debug prints the list of stages, so pipelines are easy to inspect");
+    println!("✅ run_parallel uses std threads and keeps the original order");
+}
@@ -0,0 +1,791 @@
+/*
+PRIORITY SCHEDULER - Fair Shares, Aging, Deadlines and Cancellation
+===================================================================
+
+WHAT IS THIS?
+- while-let.rs "Pattern 2" pops `high_priority` until it's empty, THEN pops
+  `normal_priority` - fine for 2 + 3 tasks, but if urgent work keeps arriving
+  the normal tasks never run at all (starvation)
+- This file is a single-worker scheduler with N priority levels and a
+  pluggable policy for choosing the next level:
+  * Strict        - always the highest non-empty level (the while-let behaviour)
+  * WeightedFair  - each level gets a share of the worker proportional to its weight
+  * Aging         - a level that waits long enough gets promoted above the others
+- Tasks can have a deadline ("must start by tick N") and can be cancelled by id
+- Time is a tick counter advanced by `tick()`, so every run is reproducible
+
+REAL-WORLD ANALOGY:
+- A hospital A&E: critical patients go first, but a sprained ankle
+  can't wait forever - so either each queue gets a fixed share of the
+  doctors (weighted fair), or the longer you wait the higher you move up
+  the list (aging)
+- A patient who leaves before being seen is "cancelled"; a test that must
+  happen within the hour and didn't is "missed"
+
+POLICIES (who goes next when every level has work):
+┌──────────────┬───────────────────────────────────┬───────────────────────┐
+│ Policy       │ Picks                             │ Low priority gets     │
+├──────────────┼───────────────────────────────────┼───────────────────────┤
+│ Strict       │ highest non-empty level           │ nothing (starves)     │
+│ WeightedFair │ lowest "pass" (stride scheduling) │ weight / sum(weights) │
+│ Aging        │ level - waited / every (lowest)   │ a turn every so often │
+└──────────────┴───────────────────────────────────┴───────────────────────┘
+
+KEY CONCEPTS:
+- Level 0 is the highest priority; inside a level, earliest deadline first, then FIFO
+- Stride scheduling: a level's pass grows by cost / weight each time it runs,
+  so heavy levels run often and light levels still come round
+- A level that was idle re-joins at the current pass, so it can't save up credit
+- Tasks run to completion once started (no preemption) unless cancelled
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+// ============================================================================
+// TASKS
+// ============================================================================
+
+type TaskId = u64;
+
+#[derive(Debug, Clone)]
+struct Task {
+    name: String,
+    level: usize,
+    cost: u64,             // Ticks of work
+    deadline: Option<u64>, // Latest tick the task may START at
+}
+
+impl Task {
+    fn new(name: &str, level: usize) -> Self {
+        Task {
+            name: name.to_string(),
+            level,
+            cost: 1,
+            deadline: None,
+        }
+    }
+
+    fn cost(mut self, ticks: u64) -> Self {
+        self.cost = ticks;
+        self
+    }
+
+    fn deadline(mut self, tick: u64) -> Self {
+        self.deadline = Some(tick);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Outcome {
+    Completed { started: u64, finished: u64 },
+    Missed { at: u64 },
+    Cancelled { ran: u64 }, // Ticks of work done before the cancel
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Queued,
+    Running { remaining: u64 },
+    Finished(Outcome),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Started(TaskId),
+    Completed(TaskId),
+    Missed(TaskId),
+    Cancelled(TaskId),
+}
+
+#[derive(Debug, PartialEq)]
+enum SchedulerError {
+    NoSuchLevel { level: usize, levels: usize },
+    ZeroCost,
+    DeadlineInPast { deadline: u64, now: u64 },
+    UnknownTask(TaskId),
+    AlreadyFinished(TaskId),
+}
+
+impl fmt::Display for SchedulerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulerError::NoSuchLevel { level, levels } => {
+                write!(f, "level {} does not exist (levels 0..{})", level, levels)
+            }
+            SchedulerError::ZeroCost => write!(f, "a task must cost at least one tick"),
+            SchedulerError::DeadlineInPast { deadline, now } => {
+                write!(f, "deadline {} is already past (now {})", deadline, now)
+            }
+            SchedulerError::UnknownTask(id) => write!(f, "no task with id {}", id),
+            SchedulerError::AlreadyFinished(id) => write!(f, "task {} already finished", id),
+        }
+    }
+}
+
+// ============================================================================
+// POLICY AND PER-LEVEL STATE
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum Policy {
+    Strict,
+    WeightedFair(Vec<u64>), // One weight per level
+    Aging { every: u64 },   // Promote a waiting level by one step per `every` ticks
+}
+
+const STRIDE_ONE: u64 = 1 << 20;
+
+type QueueKey = (u64, TaskId); // (deadline or MAX, id) = EDF, then FIFO
+
+struct Queued {
+    task: Task,
+    submitted: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+struct LevelStats {
+    submitted: u64,
+    completed: u64,
+    missed: u64,
+    cancelled: u64,
+    work: u64,        // Ticks the worker spent on this level
+    total_wait: u64,  // Sum of (started - submitted) over started tasks
+    longest_gap: u64, // Longest time the level had work but wasn't picked
+}
+
+struct Level {
+    queue: BTreeMap<QueueKey, Queued>,
+    stride: u64,
+    pass: u64,
+    waiting_since: u64, // Last time this level was served or became non-empty
+    stats: LevelStats,
+}
+
+struct Running {
+    id: TaskId,
+    task: Task,
+    submitted: u64,
+    started: u64,
+    remaining: u64,
+}
+
+struct Record {
+    task: Task,
+    submitted: u64,
+    outcome: Outcome,
+}
+
+// ============================================================================
+// SCHEDULER
+// ============================================================================
+
+struct Scheduler {
+    policy: Policy,
+    levels: Vec<Level>,
+    index: HashMap<TaskId, QueueKey>, // Queued tasks only; level is in the Task
+    running: Option<Running>,
+    finished: HashMap<TaskId, Record>,
+    now: u64,
+    next_id: TaskId,
+    virtual_pass: u64, // Pass of the level picked last
+    idle_ticks: u64,
+    log: Vec<(u64, Event)>,
+}
+
+impl Scheduler {
+    fn new(levels: usize, policy: Policy) -> Self {
+        assert!(levels > 0, "need at least one level");
+        let strides: Vec<u64> = match &policy {
+            Policy::WeightedFair(weights) => {
+                assert_eq!(weights.len(), levels, "one weight per level");
+                assert!(weights.iter().all(|&w| w > 0), "weights must be positive");
+                weights.iter().map(|&w| STRIDE_ONE / w).collect()
+            }
+            Policy::Aging { every } => {
+                assert!(*every > 0, "aging interval must be positive");
+                vec![STRIDE_ONE; levels]
+            }
+            _ => vec![STRIDE_ONE; levels],
+        };
+        Scheduler {
+            policy,
+            levels: strides
+                .into_iter()
+                .map(|stride| Level {
+                    queue: BTreeMap::new(),
+                    stride,
+                    pass: 0,
+                    waiting_since: 0,
+                    stats: LevelStats::default(),
+                })
+                .collect(),
+            index: HashMap::new(),
+            running: None,
+            finished: HashMap::new(),
+            now: 0,
+            next_id: 1,
+            virtual_pass: 0,
+            idle_ticks: 0,
+            log: Vec::new(),
+        }
+    }
+
+    fn submit(&mut self, task: Task) -> Result<TaskId, SchedulerError> {
+        if task.level >= self.levels.len() {
+            return Err(SchedulerError::NoSuchLevel {
+                level: task.level,
+                levels: self.levels.len(),
+            });
+        }
+        if task.cost == 0 {
+            return Err(SchedulerError::ZeroCost);
+        }
+        if let Some(deadline) = task.deadline
+            && deadline < self.now
+        {
+            return Err(SchedulerError::DeadlineInPast {
+                deadline,
+                now: self.now,
+            });
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let key = (task.deadline.unwrap_or(u64::MAX), id);
+        let level = &mut self.levels[task.level];
+        if level.queue.is_empty() {
+            // Re-join at the current pass: no credit saved up while idle
+            level.pass = level.pass.max(self.virtual_pass);
+            level.waiting_since = self.now;
+        }
+        level.stats.submitted += 1;
+        level.queue.insert(
+            key,
+            Queued {
+                task,
+                submitted: self.now,
+            },
+        );
+        self.index.insert(id, key);
+        Ok(id)
+    }
+
+    fn cancel(&mut self, id: TaskId) -> Result<(), SchedulerError> {
+        if let Some(key) = self.index.remove(&id) {
+            let level = self.levels.iter_mut().find(|l| l.queue.contains_key(&key));
+            let level = level.expect("index and queues agree");
+            let queued = level.queue.remove(&key).expect("just found");
+            level.stats.cancelled += 1;
+            self.finish(
+                id,
+                queued.task,
+                queued.submitted,
+                Outcome::Cancelled { ran: 0 },
+            );
+            return Ok(());
+        }
+        if self.running.as_ref().is_some_and(|r| r.id == id) {
+            let running = self.running.take().expect("just checked");
+            self.levels[running.task.level].stats.cancelled += 1;
+            let ran = running.task.cost - running.remaining;
+            self.finish(
+                id,
+                running.task,
+                running.submitted,
+                Outcome::Cancelled { ran },
+            );
+            return Ok(());
+        }
+        if self.finished.contains_key(&id) {
+            Err(SchedulerError::AlreadyFinished(id))
+        } else {
+            Err(SchedulerError::UnknownTask(id))
+        }
+    }
+
+    fn status(&self, id: TaskId) -> Option<Status> {
+        if self.index.contains_key(&id) {
+            return Some(Status::Queued);
+        }
+        if let Some(running) = &self.running
+            && running.id == id
+        {
+            return Some(Status::Running {
+                remaining: running.remaining,
+            });
+        }
+        self.finished.get(&id).map(|r| Status::Finished(r.outcome))
+    }
+
+    // ------------------------------------------------------------------------
+    // One tick: expire deadlines, pick a task if the worker is free, do 1 unit
+    // ------------------------------------------------------------------------
+
+    fn tick(&mut self) {
+        self.expire_deadlines();
+        if self.running.is_none() {
+            self.start_next();
+        }
+
+        match &mut self.running {
+            Some(running) => {
+                running.remaining -= 1;
+                self.levels[running.task.level].stats.work += 1;
+            }
+            None => self.idle_ticks += 1,
+        }
+        self.now += 1;
+
+        if self.running.as_ref().is_some_and(|r| r.remaining == 0) {
+            let running = self.running.take().expect("just checked");
+            let outcome = Outcome::Completed {
+                started: running.started,
+                finished: self.now,
+            };
+            self.levels[running.task.level].stats.completed += 1;
+            self.finish(running.id, running.task, running.submitted, outcome);
+        }
+    }
+
+    fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    fn run_until_idle(&mut self, limit: u64) -> bool {
+        for _ in 0..limit {
+            if self.is_idle() {
+                return true;
+            }
+            self.tick();
+        }
+        self.is_idle()
+    }
+
+    fn is_idle(&self) -> bool {
+        self.running.is_none() && self.index.is_empty()
+    }
+
+    fn expire_deadlines(&mut self) {
+        let now = self.now;
+        let mut missed = Vec::new();
+        for level in &mut self.levels {
+            // Keys are ordered by deadline, so expired tasks are at the front
+            while let Some(entry) = level.queue.first_entry()
+                && entry.key().0 < now
+            {
+                let id = entry.key().1;
+                missed.push((id, entry.remove()));
+                level.stats.missed += 1;
+            }
+        }
+        for (id, queued) in missed {
+            self.index.remove(&id);
+            self.finish(
+                id,
+                queued.task,
+                queued.submitted,
+                Outcome::Missed { at: now },
+            );
+        }
+    }
+
+    fn pick_level(&self) -> Option<usize> {
+        let mut active = self
+            .levels
+            .iter()
+            .enumerate()
+            .filter(|(_, level)| !level.queue.is_empty());
+        match &self.policy {
+            Policy::Strict => active.next().map(|(i, _)| i),
+            Policy::WeightedFair(_) => active
+                .min_by_key(|(i, level)| (level.pass, *i))
+                .map(|(i, _)| i),
+            Policy::Aging { every } => active
+                .min_by_key(|(i, level)| {
+                    let steps = (self.now - level.waiting_since) / every;
+                    (*i as i64 - steps as i64, *i)
+                })
+                .map(|(i, _)| i),
+        }
+    }
+
+    fn start_next(&mut self) {
+        let Some(index) = self.pick_level() else {
+            return;
+        };
+        let now = self.now;
+        let level = &mut self.levels[index];
+        let (key, queued) = level.queue.pop_first().expect("picked a non-empty level");
+
+        level.stats.longest_gap = level.stats.longest_gap.max(now - level.waiting_since);
+        level.stats.total_wait += now - queued.submitted;
+        level.waiting_since = now;
+        self.virtual_pass = level.pass;
+        level.pass += level.stride * queued.task.cost;
+
+        self.index.remove(&key.1);
+        self.log.push((now, Event::Started(key.1)));
+        self.running = Some(Running {
+            id: key.1,
+            remaining: queued.task.cost,
+            task: queued.task,
+            submitted: queued.submitted,
+            started: now,
+        });
+    }
+
+    fn finish(&mut self, id: TaskId, task: Task, submitted: u64, outcome: Outcome) {
+        let event = match outcome {
+            Outcome::Completed { .. } => Event::Completed(id),
+            Outcome::Missed { .. } => Event::Missed(id),
+            Outcome::Cancelled { .. } => Event::Cancelled(id),
+        };
+        self.log.push((self.now, event));
+        self.finished.insert(
+            id,
+            Record {
+                task,
+                submitted,
+                outcome,
+            },
+        );
+    }
+}
+
+// ============================================================================
+// SIMULATION HELPERS
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+// More work than one worker can do: level 0 alone needs every tick
+fn overload(seed: u64, ticks: u64) -> Vec<(u64, Task)> {
+    let mut rng = XorShift(seed);
+    let mut arrivals = Vec::new();
+    for at in 0..ticks {
+        arrivals.push((at, Task::new(&format!("hi-{}", at), 0)));
+        if rng.below(2) == 0 {
+            let cost = 1 + rng.below(2);
+            arrivals.push((at, Task::new(&format!("mid-{}", at), 1).cost(cost)));
+        }
+        if rng.below(3) == 0 {
+            let cost = 1 + rng.below(3);
+            arrivals.push((at, Task::new(&format!("lo-{}", at), 2).cost(cost)));
+        }
+    }
+    arrivals
+}
+
+fn simulate(policy: Policy, arrivals: &[(u64, Task)], ticks: u64) -> Scheduler {
+    let mut scheduler = Scheduler::new(3, policy);
+    let mut pending = arrivals.iter().peekable();
+    for _ in 0..ticks {
+        while let Some((_, task)) = pending.next_if(|(at, _)| *at == scheduler.now) {
+            scheduler.submit(task.clone()).unwrap();
+        }
+        scheduler.tick();
+    }
+    scheduler
+}
+
+fn print_levels(title: &str, scheduler: &Scheduler) {
+    let busy = scheduler.now - scheduler.idle_ticks;
+    println!("  {}:", title);
+    println!("    level  submitted  completed  work share  avg wait  longest gap");
+    for (i, level) in scheduler.levels.iter().enumerate() {
+        let s = &level.stats;
+        let started = s.completed
+            + s.cancelled
+            + u64::from(
+                scheduler
+                    .running
+                    .as_ref()
+                    .is_some_and(|r| r.task.level == i),
+            );
+        let avg_wait = if started == 0 {
+            "-".to_string()
+        } else {
+            format!("{:.1}", s.total_wait as f64 / started as f64)
+        };
+        let gap = if s.work == 0 {
+            "never run".to_string()
+        } else {
+            s.longest_gap.to_string()
+        };
+        println!(
+            "    {:>5}  {:>9}  {:>9}  {:>9.1}%  {:>8}  {:>11}",
+            i,
+            s.submitted,
+            s.completed,
+            100.0 * s.work as f64 / busy.max(1) as f64,
+            avg_wait,
+            gap
+        );
+    }
+}
+
+fn main() {
+    println!("🗓️  === PRIORITY SCHEDULER ===");
+
+    // ========================================================================
+    // 1. THE while-let.rs STACKS, SCHEDULED
+    // ========================================================================
+    println!("\n⚡ === while-let.rs PATTERN 2 ===");
+
+    let mut scheduler = Scheduler::new(2, Policy::Strict);
+    for name in ["urgent1", "urgent2"] {
+        scheduler.submit(Task::new(name, 0)).unwrap();
+    }
+    for name in ["normal1", "normal2", "normal3"] {
+        scheduler.submit(Task::new(name, 1)).unwrap();
+    }
+    assert!(scheduler.run_until_idle(100));
+    let order: Vec<&str> = scheduler
+        .log
+        .iter()
+        .filter_map(|(_, event)| match event {
+            Event::Started(id) => Some(scheduler.finished[id].task.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    println!("  Strict order: {}", order.join(" -> "));
+    // Same levels as the original, but FIFO inside a level instead of Vec::pop's LIFO
+    assert_eq!(
+        order,
+        ["urgent1", "urgent2", "normal1", "normal2", "normal3"]
+    );
+
+    // ========================================================================
+    // 2. STARVATION UNDER LOAD, THREE POLICIES
+    // ========================================================================
+    println!("\n⚖️  === 2800 TICKS OF OVERLOAD ===");
+
+    const TICKS: u64 = 2_800;
+    let arrivals = overload(0x5c4e_d01e, TICKS);
+    let weights = vec![4, 2, 1];
+    let strict = simulate(Policy::Strict, &arrivals, TICKS);
+    let fair = simulate(Policy::WeightedFair(weights.clone()), &arrivals, TICKS);
+    let aging = simulate(Policy::Aging { every: 8 }, &arrivals, TICKS);
+    print_levels("Strict", &strict);
+    print_levels("WeightedFair [4, 2, 1]", &fair);
+    print_levels("Aging (every 8 ticks)", &aging);
+
+    // Strict: level 0 alone fills the worker, levels 1 and 2 never run
+    assert_eq!(strict.levels[0].stats.work, TICKS);
+    assert_eq!(strict.levels[1].stats.work + strict.levels[2].stats.work, 0);
+
+    // WeightedFair: every level is backlogged, so shares follow the weights
+    let total: u64 = weights.iter().sum();
+    for (level, weight) in fair.levels.iter().zip(&weights) {
+        let share = level.stats.work as f64 / TICKS as f64;
+        let expected = *weight as f64 / total as f64;
+        assert!(
+            (share - expected).abs() < 0.01,
+            "share {} vs {}",
+            share,
+            expected
+        );
+    }
+
+    // Aging: every level makes progress; a busy level is never passed over
+    // for more than a few `every` periods (3 levels x 8 ticks + one task here)
+    for level in &aging.levels {
+        assert!(level.stats.completed > 0);
+        assert!(
+            level.stats.longest_gap < 40,
+            "gap {}",
+            level.stats.longest_gap
+        );
+    }
+    assert_eq!(strict.idle_ticks + fair.idle_ticks + aging.idle_ticks, 0);
+
+    // ========================================================================
+    // 3. DEADLINES
+    // ========================================================================
+    println!("\n⏰ === DEADLINES ===");
+
+    let mut scheduler = Scheduler::new(2, Policy::Strict);
+    let backup = scheduler.submit(Task::new("backup", 0).cost(4)).unwrap();
+    let reply_a = scheduler
+        .submit(Task::new("reply-a", 1).deadline(2))
+        .unwrap();
+    let report = scheduler.submit(Task::new("report", 1).cost(2)).unwrap();
+    let reply_b = scheduler
+        .submit(Task::new("reply-b", 1).deadline(6))
+        .unwrap();
+    assert!(scheduler.run_until_idle(100));
+    for (at, event) in &scheduler.log {
+        println!("  t={:<2} {:?}", at, event);
+    }
+    // reply-a had to start by t=2, but backup holds the worker until t=4
+    assert_eq!(
+        scheduler.status(reply_a),
+        Some(Status::Finished(Outcome::Missed { at: 3 }))
+    );
+    // Earliest deadline first: reply-b jumps ahead of report, which was queued before it
+    assert_eq!(
+        scheduler.status(reply_b),
+        Some(Status::Finished(Outcome::Completed {
+            started: 4,
+            finished: 5
+        }))
+    );
+    assert_eq!(
+        scheduler.status(report),
+        Some(Status::Finished(Outcome::Completed {
+            started: 5,
+            finished: 7
+        }))
+    );
+    assert!(matches!(
+        scheduler.status(backup),
+        Some(Status::Finished(Outcome::Completed { .. }))
+    ));
+    assert_eq!(scheduler.levels[1].stats.missed, 1);
+
+    // ========================================================================
+    // 4. CANCELLATION AND ERRORS
+    // ========================================================================
+    println!("\n🛑 === CANCELLATION ===");
+
+    let mut scheduler = Scheduler::new(3, Policy::Aging { every: 4 });
+    let long = scheduler.submit(Task::new("reindex", 0).cost(5)).unwrap();
+    let queued = scheduler.submit(Task::new("thumbnail", 2)).unwrap();
+    scheduler.run(2);
+    assert_eq!(
+        scheduler.status(long),
+        Some(Status::Running { remaining: 3 })
+    );
+    assert_eq!(scheduler.status(queued), Some(Status::Queued));
+
+    scheduler.cancel(queued).unwrap();
+    scheduler.cancel(long).unwrap();
+    println!("  cancelled a queued task and a running one after 2 ticks of work");
+    assert_eq!(
+        scheduler.status(queued),
+        Some(Status::Finished(Outcome::Cancelled { ran: 0 }))
+    );
+    assert_eq!(
+        scheduler.status(long),
+        Some(Status::Finished(Outcome::Cancelled { ran: 2 }))
+    );
+    assert!(scheduler.is_idle());
+
+    let errors = [
+        scheduler.cancel(long).unwrap_err(),
+        scheduler.cancel(999).unwrap_err(),
+        scheduler.submit(Task::new("nowhere", 3)).unwrap_err(),
+        scheduler.submit(Task::new("free", 0).cost(0)).unwrap_err(),
+        scheduler
+            .submit(Task::new("too late", 0).deadline(1))
+            .unwrap_err(),
+    ];
+    for e in &errors {
+        println!("  ❌ {}", e);
+    }
+    assert_eq!(errors[0], SchedulerError::AlreadyFinished(long));
+    assert_eq!(errors[1], SchedulerError::UnknownTask(999));
+    assert_eq!(
+        errors[2],
+        SchedulerError::NoSuchLevel {
+            level: 3,
+            levels: 3
+        }
+    );
+    assert_eq!(errors[3], SchedulerError::ZeroCost);
+    assert_eq!(
+        errors[4],
+        SchedulerError::DeadlineInPast {
+            deadline: 1,
+            now: 2
+        }
+    );
+
+    // ========================================================================
+    // 5. DETERMINISM AND RANDOMISED INVARIANTS
+    // ========================================================================
+    println!("\n🎲 === DETERMINISTIC SIMULATION ===");
+
+    let again = simulate(Policy::WeightedFair(weights.clone()), &arrivals, TICKS);
+    assert_eq!(again.log, fair.log);
+    println!(
+        "  same arrivals, same policy -> identical {}-event log",
+        fair.log.len()
+    );
+
+    let mut rng = XorShift(0x0000_7e57);
+    for round in 0..300 {
+        let policy = match round % 3 {
+            0 => Policy::Strict,
+            1 => Policy::WeightedFair(vec![1 + rng.below(5), 1 + rng.below(5), 1 + rng.below(5)]),
+            _ => Policy::Aging {
+                every: 1 + rng.below(10),
+            },
+        };
+        let mut scheduler = Scheduler::new(3, policy);
+        let mut ids = Vec::new();
+        for _ in 0..100 {
+            for _ in 0..rng.below(3) {
+                let mut task = Task::new("t", rng.below(3) as usize).cost(1 + rng.below(4));
+                if rng.below(2) == 0 {
+                    task = task.deadline(scheduler.now + rng.below(30));
+                }
+                ids.push(scheduler.submit(task).unwrap());
+            }
+            if !ids.is_empty() && rng.below(10) == 0 {
+                let id = ids[rng.below(ids.len() as u64) as usize];
+                let _ = scheduler.cancel(id); // May already be finished
+            }
+            scheduler.tick();
+        }
+        assert!(scheduler.run_until_idle(10_000));
+
+        // Every task ended exactly one way, and the stats agree with the records
+        assert_eq!(scheduler.finished.len(), ids.len());
+        for level in &scheduler.levels {
+            let s = &level.stats;
+            assert_eq!(s.submitted, s.completed + s.missed + s.cancelled);
+        }
+        let work: u64 = scheduler.levels.iter().map(|l| l.stats.work).sum();
+        assert_eq!(work + scheduler.idle_ticks, scheduler.now);
+
+        for record in scheduler.finished.values() {
+            match record.outcome {
+                Outcome::Completed { started, finished } => {
+                    assert!(started >= record.submitted);
+                    assert_eq!(finished - started, record.task.cost);
+                    assert!(record.task.deadline.is_none_or(|d| started <= d));
+                }
+                Outcome::Missed { at } => assert!(at > record.task.deadline.unwrap()),
+                Outcome::Cancelled { ran } => assert!(ran < record.task.cost),
+            }
+        }
+    }
+    println!("  300 random runs: every task completed, missed or cancelled exactly once");
+    println!("  no task started after its deadline, work + idle == elapsed ticks");
+
+    println!("\n📋 === PRIORITY SCHEDULER SUMMARY ===");
+    println!("✅ N levels; inside a level earliest deadline first, then FIFO");
+    println!("✅ Strict reproduces while-let.rs - and its starvation");
+    println!("✅ WeightedFair gives each busy level its weighted share of the worker");
+    println!("✅ Aging promotes levels that wait, so every level makes progress");
+    println!("✅ Deadlines expire unstarted tasks; cancel() works queued or running");
+    println!("✅ tick() is the only clock, so simulations replay exactly");
+}
@@ -0,0 +1,735 @@
+/*
+RUN-LENGTH ENCODING - Generic Runs and a PackBits Byte Codec
+============================================================
+
+WHAT IS THIS?
+- while-let.rs "Pattern 1" walks [1, 2, 2, 3, 3, 3, 4, 4, 4, 4] with `peekable()`
+  and prints "Number 3 appears 3 time(s)" - that IS run-length encoding,
+  it just never produces anything you could store or decode
+- This file turns it into a codec:
+  * `encode` turns ANY iterator of PartialEq items into (item, count) runs, lazily
+  * `decode` expands runs back
+  * `pack` / `unpack` store bytes in the PackBits format (used by TIFF and old Macs)
+  * `PackWriter` / `UnpackReader` do the same while streaming through Write / Read
+
+REAL-WORLD ANALOGY:
+- Reading a knitting pattern aloud: "knit 40, purl 2, knit 40" instead of
+  saying "knit" forty times
+- But "knit, purl, knit, knit, purl" gets LONGER if you add counts to every
+  stitch - so PackBits says "here come 5 stitches as-is" for the messy parts
+
+PACKBITS FORMAT (one header byte, read as i8, then data):
+┌──────────────┬─────────────────────────────┬──────────────────────────────┐
+│ Header n     │ Meaning                     │ Example                      │
+├──────────────┼─────────────────────────────┼──────────────────────────────┤
+│ 0 ..= 127    │ copy the next n+1 bytes     │ 02 'a' 'b' 'c'  -> "abc"     │
+│ -1 ..= -127  │ repeat the next byte 1-n    │ FD 'z'          -> "zzzz"    │
+│              │ times (2 ..= 128)           │                              │
+│ -128         │ no-op, skipped              │                              │
+└──────────────┴─────────────────────────────┴──────────────────────────────┘
+  Runs longer than 128 are split; worst case (no runs at all) costs
+  1 extra byte per 128 bytes of input
+
+KEY CONCEPTS:
+- `Runs` is an iterator adapter with a one-item look-ahead slot, like `peekable()`
+- One `Packer` state machine serves both `pack` and `PackWriter`, so the
+  streamed output is byte-for-byte what `pack` produces, however it is chunked
+- Truncated input is an error (RleError, or UnexpectedEof when streaming), never a panic
+*/
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::iter;
+
+// ============================================================================
+// GENERIC RUNS
+// ============================================================================
+
+struct Runs<I: Iterator> {
+    iter: I,
+    next: Option<I::Item>, // First item of the following run
+}
+
+impl<I> Iterator for Runs<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<(I::Item, usize)> {
+        let value = self.next.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+        for item in self.iter.by_ref() {
+            if item == value {
+                count += 1;
+            } else {
+                self.next = Some(item); // Start of the next run
+                break;
+            }
+        }
+        Some((value, count))
+    }
+}
+
+fn encode<I>(items: I) -> Runs<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: PartialEq,
+{
+    Runs {
+        iter: items.into_iter(),
+        next: None,
+    }
+}
+
+fn decode<T, I>(runs: I) -> impl Iterator<Item = T>
+where
+    T: Clone,
+    I: IntoIterator<Item = (T, usize)>,
+{
+    runs.into_iter()
+        .flat_map(|(value, count)| iter::repeat_n(value, count))
+}
+
+// ============================================================================
+// PACKBITS: ENCODER
+// ============================================================================
+
+const MAX_RUN: usize = 128;
+const MAX_LITERAL: usize = 128;
+const MIN_RUN: usize = 3; // Shorter runs are cheaper inside a literal
+
+#[derive(Default)]
+struct Packer {
+    literal: Vec<u8>,
+    run: Option<(u8, usize)>,
+}
+
+impl Packer {
+    fn push(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match &mut self.run {
+            Some((b, len)) if *b == byte && *len < MAX_RUN => *len += 1,
+            _ => {
+                self.end_run(out);
+                self.run = Some((byte, 1));
+            }
+        }
+    }
+
+    fn end_run(&mut self, out: &mut Vec<u8>) {
+        let Some((byte, len)) = self.run.take() else {
+            return;
+        };
+        if len >= MIN_RUN {
+            self.flush_literal(out);
+            out.push((1 - len as i32) as i8 as u8);
+            out.push(byte);
+        } else {
+            self.literal.extend(iter::repeat_n(byte, len));
+            if self.literal.len() >= MAX_LITERAL {
+                let rest = self.literal.split_off(MAX_LITERAL);
+                self.flush_literal(out);
+                self.literal = rest;
+            }
+        }
+    }
+
+    fn flush_literal(&mut self, out: &mut Vec<u8>) {
+        if !self.literal.is_empty() {
+            out.push((self.literal.len() - 1) as u8);
+            out.append(&mut self.literal);
+        }
+    }
+
+    fn finish(&mut self, out: &mut Vec<u8>) {
+        self.end_run(out);
+        self.flush_literal(out);
+    }
+}
+
+fn pack(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() / 2);
+    let mut packer = Packer::default();
+    for &byte in data {
+        packer.push(byte, &mut out);
+    }
+    packer.finish(&mut out);
+    out
+}
+
+// ============================================================================
+// PACKBITS: DECODER
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+enum RleError {
+    TruncatedLiteral { offset: usize, missing: usize },
+    MissingRepeatByte { offset: usize },
+}
+
+impl fmt::Display for RleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RleError::TruncatedLiteral { offset, missing } => write!(
+                f,
+                "literal packet at byte {} is {} bytes short",
+                offset, missing
+            ),
+            RleError::MissingRepeatByte { offset } => {
+                write!(f, "repeat packet at byte {} has no byte to repeat", offset)
+            }
+        }
+    }
+}
+
+impl From<RleError> for io::Error {
+    fn from(e: RleError) -> io::Error {
+        io::Error::new(io::ErrorKind::UnexpectedEof, e.to_string())
+    }
+}
+
+fn unpack(data: &[u8]) -> Result<Vec<u8>, RleError> {
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut pos = 0;
+    while let Some(&header) = data.get(pos) {
+        let offset = pos;
+        pos += 1;
+        match header as i8 {
+            -128 => {}
+            n @ 0..=127 => {
+                let len = n as usize + 1;
+                let available = data.len() - pos;
+                if available < len {
+                    return Err(RleError::TruncatedLiteral {
+                        offset,
+                        missing: len - available,
+                    });
+                }
+                out.extend_from_slice(&data[pos..pos + len]);
+                pos += len;
+            }
+            n => {
+                let &byte = data
+                    .get(pos)
+                    .ok_or(RleError::MissingRepeatByte { offset })?;
+                out.extend(iter::repeat_n(byte, (1 - n as i32) as usize));
+                pos += 1;
+            }
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// STREAMING ADAPTERS
+// ============================================================================
+
+// Compresses everything written to it; call finish() to write the last packet
+struct PackWriter<W: Write> {
+    inner: Option<W>, // None once finished
+    packer: Packer,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> PackWriter<W> {
+    fn new(inner: W) -> Self {
+        PackWriter {
+            inner: Some(inner),
+            packer: Packer::default(),
+            buffer: Vec::new(),
+        }
+    }
+
+    fn drain(&mut self) -> io::Result<()> {
+        if let Some(inner) = &mut self.inner {
+            inner.write_all(&self.buffer)?;
+        }
+        self.buffer.clear();
+        Ok(())
+    }
+
+    // Pending bytes can't be written until we know how the run ends - so this
+    // must be called (Drop does it too, but has to ignore errors)
+    fn finish(mut self) -> io::Result<W> {
+        self.packer.finish(&mut self.buffer);
+        self.drain()?;
+        let mut inner = self.inner.take().expect("only finish() takes it");
+        inner.flush()?;
+        Ok(inner)
+    }
+}
+
+impl<W: Write> Write for PackWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        for &byte in data {
+            self.packer.push(byte, &mut self.buffer);
+        }
+        self.drain()?;
+        Ok(data.len())
+    }
+
+    // Only flushes complete packets; the open run/literal stays pending
+    fn flush(&mut self) -> io::Result<()> {
+        self.drain()?;
+        self.inner.as_mut().map_or(Ok(()), |inner| inner.flush())
+    }
+}
+
+impl<W: Write> Drop for PackWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            self.packer.finish(&mut self.buffer);
+            let _ = self.drain();
+        }
+    }
+}
+
+enum Unpacking {
+    Header,
+    Literal(usize),    // Bytes left to copy
+    RepeatByte(usize), // Header read, byte to repeat not yet
+    Repeat(u8, usize), // Byte and copies left
+}
+
+// Decompresses a PackBits stream while it is being read
+struct UnpackReader<R: Read> {
+    inner: R,
+    state: Unpacking,
+    offset: usize, // Bytes consumed from `inner`, for error messages
+    packet_start: usize,
+}
+
+impl<R: Read> UnpackReader<R> {
+    fn new(inner: R) -> Self {
+        UnpackReader {
+            inner,
+            state: Unpacking::Header,
+            offset: 0,
+            packet_start: 0,
+        }
+    }
+
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        let mut byte = [0u8];
+        loop {
+            match self.inner.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => {
+                    self.offset += 1;
+                    return Ok(Some(byte[0]));
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // Produce up to buf.len() bytes; Ok(0) only at a clean packet boundary
+    fn step(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.state {
+            Unpacking::Header => {
+                // Skip no-op headers here, so staying in Header means end of input
+                loop {
+                    self.packet_start = self.offset;
+                    match self.next_byte()? {
+                        None => return Ok(0),
+                        Some(0x80) => continue,
+                        Some(h) => {
+                            self.state = match h as i8 {
+                                n @ 0..=127 => Unpacking::Literal(n as usize + 1),
+                                n => Unpacking::RepeatByte((1 - n as i32) as usize),
+                            };
+                            return Ok(0);
+                        }
+                    }
+                }
+            }
+            Unpacking::RepeatByte(count) => {
+                let byte = self.next_byte()?.ok_or(RleError::MissingRepeatByte {
+                    offset: self.packet_start,
+                })?;
+                self.state = Unpacking::Repeat(byte, count);
+                Ok(0)
+            }
+            Unpacking::Repeat(byte, left) => {
+                let n = left.min(buf.len());
+                buf[..n].fill(byte);
+                self.state = if n == left {
+                    Unpacking::Header
+                } else {
+                    Unpacking::Repeat(byte, left - n)
+                };
+                Ok(n)
+            }
+            Unpacking::Literal(left) => {
+                let want = left.min(buf.len());
+                let n = loop {
+                    match self.inner.read(&mut buf[..want]) {
+                        Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        other => break other?,
+                    }
+                };
+                if n == 0 {
+                    return Err(RleError::TruncatedLiteral {
+                        offset: self.packet_start,
+                        missing: left,
+                    }
+                    .into());
+                }
+                self.offset += n;
+                self.state = if n == left {
+                    Unpacking::Header
+                } else {
+                    Unpacking::Literal(left - n)
+                };
+                Ok(n)
+            }
+        }
+    }
+
+    fn at_boundary(&self) -> bool {
+        matches!(self.state, Unpacking::Header)
+    }
+}
+
+impl<R: Read> Read for UnpackReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let was_header = self.at_boundary();
+            match self.step(&mut buf[filled..]) {
+                Ok(0) if was_header && self.at_boundary() => break, // End of input
+                Ok(n) => filled += n,
+                // Hand over what we have; the error comes back on the next call
+                Err(_) if filled > 0 => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(filled)
+    }
+}
+
+// ============================================================================
+// REPORTS AND TEST DATA
+// ============================================================================
+
+struct Report {
+    name: &'static str,
+    input: usize,
+    output: usize,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = 100.0 * self.output as f64 / self.input.max(1) as f64;
+        let factor = self.input as f64 / self.output.max(1) as f64;
+        write!(
+            f,
+            "{:<22} {:>6} -> {:>6} bytes  {:>6.1}%  {:>6.2}x",
+            self.name, self.input, self.output, percent, factor
+        )
+    }
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+// Mostly-runs data with some noise, like a scanned page
+fn runny_bytes(rng: &mut XorShift, len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(len);
+    while out.len() < len {
+        let byte = rng.below(4) as u8;
+        let run = match rng.below(3) {
+            0 => 1,
+            1 => 1 + rng.below(4) as usize,
+            _ => 1 + rng.below(300) as usize,
+        };
+        out.extend(iter::repeat_n(byte, run.min(len - out.len())));
+    }
+    out
+}
+
+// Reads with awkward buffer sizes to exercise the streaming state machine
+fn read_in_chunks<R: Read>(mut reader: R, rng: &mut XorShift) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buf = [0u8; 200];
+    loop {
+        let size = 1 + rng.below(200) as usize;
+        match reader.read(&mut buf[..size])? {
+            0 => return Ok(out),
+            n => out.extend_from_slice(&buf[..n]),
+        }
+    }
+}
+
+fn main() {
+    println!("🗜️  === RUN-LENGTH ENCODING ===");
+
+    // ========================================================================
+    // 1. GENERIC RUNS (while-let.rs PATTERN 1)
+    // ========================================================================
+    println!("\n🔢 === encode / decode ANY PartialEq ITEMS ===");
+
+    let numbers = vec![1, 2, 2, 3, 3, 3, 4, 4, 4, 4];
+    let runs: Vec<(i32, usize)> = encode(numbers.iter().copied()).collect();
+    for (number, count) in &runs {
+        println!("  Number {} appears {} time(s)", number, count);
+    }
+    assert_eq!(runs, [(1, 1), (2, 2), (3, 3), (4, 4)]);
+    assert_eq!(decode(runs).collect::<Vec<_>>(), numbers);
+
+    let text: String = encode("WWWWWWBBBWWWW".chars())
+        .map(|(c, n)| format!("{}{}", n, c))
+        .collect();
+    println!("  \"WWWWWWBBBWWWW\" -> {}", text);
+    assert_eq!(text, "6W3B4W");
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Weather {
+        Sun,
+        Rain,
+    }
+    let week = [Weather::Sun, Weather::Sun, Weather::Rain, Weather::Sun];
+    let weather_runs: Vec<_> = encode(week.iter().cloned()).collect();
+    println!("  {:?}", weather_runs);
+    assert_eq!(weather_runs.len(), 3);
+
+    // PartialEq, not Eq: NaN is not equal to itself, so NaNs never form a run
+    let floats = [1.5, 1.5, f64::NAN, f64::NAN];
+    assert_eq!(encode(floats).count(), 3);
+
+    // Lazy: works on an endless source
+    let first: Vec<(u64, usize)> = encode((0u64..).map(|n| n / 3)).take(2).collect();
+    assert_eq!(first, [(0, 3), (1, 3)]);
+
+    // ========================================================================
+    // 2. THE PACKBITS BYTE FORMAT
+    // ========================================================================
+    println!("\n📦 === PACKBITS ===");
+
+    // The example from Apple's Technical Note TN1023
+    let unpacked: Vec<u8> = [
+        0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0xAA, 0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0x22, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    ]
+    .to_vec();
+    let expected = [
+        0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A, 0xFD, 0xAA, 0x03, 0x80, 0x00, 0x2A, 0x22, 0xF7, 0xAA,
+    ];
+    let packed = pack(&unpacked);
+    println!("  {:02X?}", unpacked);
+    println!("  -> {:02X?}", packed);
+    assert_eq!(packed, expected);
+    assert_eq!(unpack(&packed).unwrap(), unpacked);
+
+    // Long runs split at 128; a -128 header is skipped
+    let long = vec![b'z'; 300];
+    assert_eq!(pack(&long), [0x81, b'z', 0x81, b'z', 0xD5, b'z']);
+    assert_eq!(unpack(&[0x80, 0x01, b'o', b'k', 0x80]).unwrap(), b"ok");
+    assert_eq!(pack(&[]), []);
+
+    // Broken input is reported, not a panic
+    let errors = [
+        unpack(&[0x05, b'a', b'b']).unwrap_err(),
+        unpack(&[0x00, b'x', 0xFE]).unwrap_err(),
+    ];
+    for e in &errors {
+        println!("  ❌ {}", e);
+    }
+    assert_eq!(
+        errors[0],
+        RleError::TruncatedLiteral {
+            offset: 0,
+            missing: 4
+        }
+    );
+    assert_eq!(errors[1], RleError::MissingRepeatByte { offset: 2 });
+
+    // ========================================================================
+    // 3. STREAMING THROUGH FILES
+    // ========================================================================
+    println!("\n💾 === Read / Write ADAPTERS ===");
+
+    let mut rng = XorShift(0x0005_ca1e);
+    let original = runny_bytes(&mut rng, 200_000);
+    let dir = std::env::temp_dir();
+    let raw_path = dir.join("rust-learning-rle.raw");
+    let packed_path = dir.join("rust-learning-rle.pkb");
+    std::fs::write(&raw_path, &original).unwrap();
+
+    // Compress: file -> PackWriter -> file
+    let mut source = BufReader::new(File::open(&raw_path).unwrap());
+    let mut writer = PackWriter::new(BufWriter::new(File::create(&packed_path).unwrap()));
+    let copied = io::copy(&mut source, &mut writer).unwrap();
+    writer.finish().unwrap();
+    let packed_size = std::fs::metadata(&packed_path).unwrap().len();
+
+    // Decompress: file -> UnpackReader -> memory
+    let mut reader = UnpackReader::new(BufReader::new(File::open(&packed_path).unwrap()));
+    let mut restored = Vec::new();
+    reader.read_to_end(&mut restored).unwrap();
+    println!(
+        "  {} bytes -> {} bytes on disk -> {} bytes back",
+        copied,
+        packed_size,
+        restored.len()
+    );
+    assert_eq!(restored, original);
+    assert_eq!(std::fs::read(&packed_path).unwrap(), pack(&original));
+
+    // A truncated file fails with UnexpectedEof, after handing over what it could
+    let cut = &pack(&original)[..1_000];
+    let mut partial = Vec::new();
+    let error = UnpackReader::new(cut)
+        .read_to_end(&mut partial)
+        .unwrap_err();
+    println!(
+        "  truncated file: {} ({} bytes recovered)",
+        error,
+        partial.len()
+    );
+    assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    assert!(original.starts_with(&partial));
+    std::fs::remove_file(&raw_path).ok();
+    std::fs::remove_file(&packed_path).ok();
+
+    // ========================================================================
+    // 4. COMPRESSION RATIOS
+    // ========================================================================
+    println!("\n📊 === COMPRESSION RATIOS ===");
+
+    let mut rng = XorShift(0x0ba5_eba1);
+    let mut bitmap = Vec::new(); // 1 byte per pixel: a white page with a black box
+    for y in 0..200 {
+        for x in 0..320 {
+            bitmap.push(if (60..140).contains(&y) && (100..220).contains(&x) {
+                0
+            } else {
+                255
+            });
+        }
+    }
+    let prose = "It was the best of times, it was the worst of times. ".repeat(100);
+    let samples: [(&str, Vec<u8>); 5] = [
+        ("zeros", vec![0; 64_000]),
+        ("bitmap 320x200", bitmap),
+        ("scanned-page noise", runny_bytes(&mut rng, 64_000)),
+        ("english prose", prose.into_bytes()),
+        (
+            "random bytes",
+            (0..64_000).map(|_| rng.next() as u8).collect(),
+        ),
+    ];
+    for (name, data) in &samples {
+        let packed = pack(data);
+        assert_eq!(&unpack(&packed).unwrap(), data);
+        println!(
+            "  {}",
+            Report {
+                name,
+                input: data.len(),
+                output: packed.len(),
+            }
+        );
+        // Worst case: one header per 128 literal bytes
+        assert!(packed.len() <= data.len() + data.len().div_ceil(MAX_LITERAL));
+    }
+    assert_eq!(pack(&samples[0].1).len(), 1_000); // 500 two-byte packets
+    assert!(pack(&samples[4].1).len() > 64_000); // RLE can't shrink noise
+
+    // ========================================================================
+    // 5. ROUND-TRIP PROPERTIES
+    // ========================================================================
+    println!("\n🎲 === ROUND-TRIP PROPERTIES ===");
+
+    let mut rng = XorShift(0x0000_7e57);
+    for round in 0..500 {
+        let len = rng.below(2_000) as usize;
+        let data = if round % 2 == 0 {
+            runny_bytes(&mut rng, len)
+        } else {
+            (0..len).map(|_| rng.below(3) as u8).collect()
+        };
+
+        // Generic runs: maximal, and decode inverts encode
+        let runs: Vec<(u8, usize)> = encode(data.iter().copied()).collect();
+        assert!(runs.windows(2).all(|w| w[0].0 != w[1].0));
+        assert_eq!(decode(runs).collect::<Vec<_>>(), data);
+
+        // Byte format round trip
+        let packed = pack(&data);
+        assert_eq!(unpack(&packed).unwrap(), data);
+
+        // Streaming, with random write sizes, gives exactly the same bytes
+        let mut writer = PackWriter::new(Vec::new());
+        let mut rest = &data[..];
+        while !rest.is_empty() {
+            let n = (1 + rng.below(50) as usize).min(rest.len());
+            writer.write_all(&rest[..n]).unwrap();
+            rest = &rest[n..];
+        }
+        assert_eq!(writer.finish().unwrap(), packed);
+
+        // ...and reading with random buffer sizes gives the data back
+        assert_eq!(
+            read_in_chunks(UnpackReader::new(&packed[..]), &mut rng).unwrap(),
+            data
+        );
+
+        // No-op 0x80 headers between packets are skipped by both decoders, even
+        // when a read() call starts on one
+        let split = rng.below(data.len() as u64 + 1) as usize;
+        let mut padded = vec![0x80];
+        padded.extend(pack(&data[..split]));
+        padded.extend([0x80, 0x80]);
+        padded.extend(pack(&data[split..]));
+        padded.push(0x80);
+        assert_eq!(unpack(&padded).unwrap(), data);
+        assert_eq!(
+            read_in_chunks(UnpackReader::new(&padded[..]), &mut rng).unwrap(),
+            data
+        );
+
+        // Every truncation is either a clean shorter stream or an error, never a panic
+        let cut = rng.below(packed.len() as u64 + 1) as usize;
+        if let Ok(prefix) = unpack(&packed[..cut]) {
+            assert!(data.starts_with(&prefix));
+        }
+    }
+    println!("  500 random inputs: encode/decode, pack/unpack and both stream adapters agree");
+
+    let mut restored = Vec::new();
+    UnpackReader::new(&[0x80, 0x01, b'o', b'k'][..])
+        .read_to_end(&mut restored)
+        .unwrap();
+    assert_eq!(restored, b"ok");
+
+    // Dropping a writer without finish() still writes the tail
+    let mut sink = Vec::new();
+    {
+        let mut writer = PackWriter::new(&mut sink);
+        writer.write_all(b"aaaab").unwrap();
+    }
+    assert_eq!(unpack(&sink).unwrap(), b"aaaab");
+
+    println!("\n📋 === RUN-LENGTH ENCODING SUMMARY ===");
+    println!("✅ encode() groups any PartialEq iterator into (item, count) runs, lazily");
+    println!("✅ PackBits mixes literal and repeat packets, so noise grows < 1%");
+    println!("✅ PackWriter / UnpackReader stream through any Write / Read");
+    println!("✅ Truncated input is an error, never a panic");
+    println!("✅ Randomised round trips check every path against the others");
+}
@@ -0,0 +1,961 @@
+/*
+SERVICE SUPERVISOR - A State Machine for Start/Stop/Restart
+===========================================================
+
+WHAT IS THIS?
+- while-let.rs has a `Command` enum (Start/Stop/Restart/Status/Exit) whose
+  processor only prints what it WOULD do - and it `pop()`s the Vec, so the
+  commands run last-to-first
+- This file supervises real (pluggable) services: every service is in exactly
+  one state, commands are checked against a transition table, and they are
+  handled first-in, first-out
+- Services that crash are restarted with exponential backoff, up to a limit
+
+REAL-WORLD ANALOGY:
+- A stage manager with a clipboard: every act is "waiting", "warming up",
+  "on stage", "wrapping up" or "injured"
+- "Send the band on" is refused if the band is already on stage,
+  and an injured act gets a short break, then a longer one, before they give up on it
+
+STATE MACHINE:
+┌──────────┬───────────────────────────┬──────────────────────────────────────┐
+│ From     │ To                        │ Caused by                            │
+├──────────┼───────────────────────────┼──────────────────────────────────────┤
+│ Stopped  │ Starting                  │ Start / Restart                      │
+│ Starting │ Running, Failed, Stopping │ ready / crash or timeout / Stop      │
+│ Running  │ Stopping, Failed, Stopped │ Stop or Restart / crash / clean exit │
+│ Stopping │ Stopped                   │ service exited (or was killed)       │
+│ Failed   │ Starting, Stopped         │ backoff elapsed or Start / Stop      │
+└──────────┴───────────────────────────┴──────────────────────────────────────┘
+  Anything else - e.g. Start while Running - is rejected, not silently ignored
+
+KEY CONCEPTS:
+- One `allowed(from, to)` table; every command goes through it
+- Commands sit in a VecDeque: push_back to submit, pop_front to handle (FIFO)
+- Time is a plain millisecond counter passed to tick(), so the demo is deterministic
+- A `Service` trait hides the real process; FakeService scripts delays and crashes
+*/
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::rc::Rc;
+
+// ============================================================================
+// SERVICES
+// ============================================================================
+
+// What a service reports when polled
+#[derive(Debug, Clone, PartialEq)]
+enum Probe {
+    Starting,
+    Up,
+    Stopping,
+    Down,
+    Crashed(String),
+}
+
+trait Service {
+    // Begin starting; readiness is reported later through poll()
+    fn start(&mut self, now: u64) -> Result<(), String>;
+    // Ask politely; the service reports Down once it has exited
+    fn stop(&mut self, now: u64);
+    // Used when a service ignores stop() or never becomes ready
+    fn kill(&mut self);
+    fn poll(&mut self, now: u64) -> Probe;
+}
+
+// Scripted behaviour; `calls` is shared so the demo can see what the supervisor did
+struct FakeService {
+    start_delay: u64,
+    stop_delay: Option<u64>, // None = ignores stop() and must be killed
+    failed_starts: u32,      // The next N start() calls return Err
+    crash_after: Vec<u64>,   // Uptime at which each successive run crashes
+    exit_after: Option<u64>, // Uptime at which a run exits cleanly
+    phase: Probe,
+    since: u64,
+    calls: Rc<RefCell<Vec<String>>>,
+}
+
+impl FakeService {
+    fn new(calls: &Rc<RefCell<Vec<String>>>) -> Self {
+        FakeService {
+            start_delay: 20,
+            stop_delay: Some(10),
+            failed_starts: 0,
+            crash_after: Vec::new(),
+            exit_after: None,
+            phase: Probe::Down,
+            since: 0,
+            calls: Rc::clone(calls),
+        }
+    }
+
+    fn start_delay(mut self, ms: u64) -> Self {
+        self.start_delay = ms;
+        self
+    }
+
+    fn hangs_on_stop(mut self) -> Self {
+        self.stop_delay = None;
+        self
+    }
+
+    fn failed_starts(mut self, n: u32) -> Self {
+        self.failed_starts = n;
+        self
+    }
+
+    fn crashes_after(mut self, uptimes: &[u64]) -> Self {
+        self.crash_after = uptimes.to_vec();
+        self
+    }
+
+    fn exits_after(mut self, uptime: u64) -> Self {
+        self.exit_after = Some(uptime);
+        self
+    }
+
+    fn record(&self, call: String) {
+        self.calls.borrow_mut().push(call);
+    }
+}
+
+impl Service for FakeService {
+    fn start(&mut self, now: u64) -> Result<(), String> {
+        self.record(format!("start@{}", now));
+        if self.failed_starts > 0 {
+            self.failed_starts -= 1;
+            return Err("address already in use".to_string());
+        }
+        self.phase = Probe::Starting;
+        self.since = now;
+        Ok(())
+    }
+
+    fn stop(&mut self, now: u64) {
+        self.record(format!("stop@{}", now));
+        self.phase = Probe::Stopping;
+        self.since = now;
+    }
+
+    fn kill(&mut self) {
+        self.record("kill".to_string());
+        self.phase = Probe::Down;
+    }
+
+    fn poll(&mut self, now: u64) -> Probe {
+        let elapsed = now - self.since;
+        match self.phase {
+            Probe::Starting if elapsed >= self.start_delay => {
+                self.phase = Probe::Up;
+                self.since = now;
+            }
+            Probe::Up if self.crash_after.first().is_some_and(|&t| elapsed >= t) => {
+                self.crash_after.remove(0);
+                self.phase = Probe::Down;
+                return Probe::Crashed("segmentation fault".to_string());
+            }
+            Probe::Up if self.exit_after.is_some_and(|t| elapsed >= t) => {
+                self.phase = Probe::Down;
+            }
+            Probe::Stopping if self.stop_delay.is_some_and(|t| elapsed >= t) => {
+                self.phase = Probe::Down;
+            }
+            _ => {}
+        }
+        self.phase.clone()
+    }
+}
+
+// ============================================================================
+// STATES, COMMANDS AND ERRORS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum State {
+    Stopped,
+    Starting {
+        since: u64,
+    },
+    Running {
+        since: u64,
+    },
+    Stopping {
+        since: u64,
+        then_start: bool,
+    },
+    Failed {
+        reason: String,
+        retry_at: Option<u64>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StateKind {
+    Stopped,
+    Starting,
+    Running,
+    Stopping,
+    Failed,
+}
+
+impl State {
+    fn kind(&self) -> StateKind {
+        match self {
+            State::Stopped => StateKind::Stopped,
+            State::Starting { .. } => StateKind::Starting,
+            State::Running { .. } => StateKind::Running,
+            State::Stopping { .. } => StateKind::Stopping,
+            State::Failed { .. } => StateKind::Failed,
+        }
+    }
+}
+
+impl fmt::Display for StateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+// The whole state machine in one place
+fn allowed(from: StateKind, to: StateKind) -> bool {
+    use StateKind::*;
+    matches!(
+        (from, to),
+        (Stopped, Starting)
+            | (Starting, Running | Failed | Stopping)
+            | (Running, Stopping | Failed | Stopped)
+            | (Stopping, Stopped)
+            | (Failed, Starting | Stopped)
+    )
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Start(String),
+    Stop(String),
+    Restart(String),
+    Status(String),
+    Exit,
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Start(name) => write!(f, "start {}", name),
+            Command::Stop(name) => write!(f, "stop {}", name),
+            Command::Restart(name) => write!(f, "restart {}", name),
+            Command::Status(name) => write!(f, "status {}", name),
+            Command::Exit => write!(f, "exit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SupervisorError {
+    UnknownService(String),
+    InvalidTransition {
+        service: String,
+        from: StateKind,
+        to: StateKind,
+    },
+    ShuttingDown,
+}
+
+impl fmt::Display for SupervisorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupervisorError::UnknownService(name) => write!(f, "no service named '{}'", name),
+            SupervisorError::InvalidTransition { service, from, to } => {
+                write!(f, "{} cannot go from {} to {}", service, from, to)
+            }
+            SupervisorError::ShuttingDown => write!(f, "supervisor is shutting down"),
+        }
+    }
+}
+
+// ============================================================================
+// RESTART POLICY
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RestartOn {
+    Never,
+    Failure, // Crash, failed start or timeout
+    Always,  // ...and clean exits too
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RestartPolicy {
+    on: RestartOn,
+    max_restarts: u32,    // Consecutive restarts before giving up
+    initial_backoff: u64, // ms; doubles after every restart
+    max_backoff: u64,
+    stable_after: u64, // Running this long resets the restart count
+}
+
+impl RestartPolicy {
+    fn never() -> Self {
+        RestartPolicy {
+            on: RestartOn::Never,
+            max_restarts: 0,
+            initial_backoff: 0,
+            max_backoff: 0,
+            stable_after: 0,
+        }
+    }
+
+    fn on_failure(max_restarts: u32, initial_backoff: u64) -> Self {
+        RestartPolicy {
+            on: RestartOn::Failure,
+            max_restarts,
+            initial_backoff,
+            max_backoff: initial_backoff * 16,
+            stable_after: 1_000,
+        }
+    }
+
+    fn always(max_restarts: u32, initial_backoff: u64) -> Self {
+        RestartPolicy {
+            on: RestartOn::Always,
+            ..RestartPolicy::on_failure(max_restarts, initial_backoff)
+        }
+    }
+
+    // Delay before restart number `restarts + 1`, or None to give up
+    fn next_delay(&self, restarts: u32) -> Option<u64> {
+        if self.on == RestartOn::Never || restarts >= self.max_restarts {
+            return None;
+        }
+        let factor = 2u64.saturating_pow(restarts);
+        Some(
+            self.initial_backoff
+                .saturating_mul(factor)
+                .min(self.max_backoff),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ServiceSpec {
+    policy: RestartPolicy,
+    start_timeout: u64,
+    stop_timeout: u64,
+}
+
+impl ServiceSpec {
+    fn new(policy: RestartPolicy) -> Self {
+        ServiceSpec {
+            policy,
+            start_timeout: 200,
+            stop_timeout: 100,
+        }
+    }
+}
+
+// ============================================================================
+// SUPERVISOR
+// ============================================================================
+
+struct Managed {
+    service: Box<dyn Service>,
+    spec: ServiceSpec,
+    state: State,
+    restarts: u32, // Consecutive, reset once stable
+    total_restarts: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Transition {
+    at: u64,
+    service: String,
+    from: StateKind,
+    to: StateKind,
+}
+
+#[derive(Debug, Clone)]
+struct ServiceStatus {
+    name: String,
+    state: State,
+    restarts: u32,
+    now: u64,
+}
+
+impl fmt::Display for ServiceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<11} {:<9}", self.name, self.state.kind().to_string())?;
+        match &self.state {
+            State::Running { since } => write!(f, " up {}ms", self.now - since)?,
+            State::Starting { since } | State::Stopping { since, .. } => {
+                write!(f, " for {}ms", self.now - since)?
+            }
+            State::Failed { reason, retry_at } => {
+                write!(f, " {}", reason)?;
+                match retry_at {
+                    // Commands run before supervise(), so a retry can already be due
+                    Some(at) if *at <= self.now => write!(f, ", retry due")?,
+                    Some(at) => write!(f, ", retry in {}ms", at - self.now)?,
+                    None => write!(f, ", not retrying")?,
+                }
+            }
+            State::Stopped => {}
+        }
+        write!(f, " (restarts: {})", self.restarts)
+    }
+}
+
+#[derive(Default)]
+struct Supervisor {
+    services: BTreeMap<String, Managed>,
+    commands: VecDeque<Command>,
+    log: Vec<Transition>,
+    shutting_down: bool,
+    now: u64,
+}
+
+impl Supervisor {
+    fn add(&mut self, name: &str, service: Box<dyn Service>, spec: ServiceSpec) {
+        self.services.insert(
+            name.to_string(),
+            Managed {
+                service,
+                spec,
+                state: State::Stopped,
+                restarts: 0,
+                total_restarts: 0,
+            },
+        );
+    }
+
+    fn submit(&mut self, command: Command) {
+        self.commands.push_back(command);
+    }
+
+    // Handle queued commands in order, then let every service make progress
+    fn tick(&mut self, now: u64) -> Vec<(Command, Result<String, SupervisorError>)> {
+        self.now = now;
+        let mut outcomes = Vec::new();
+        while let Some(command) = self.commands.pop_front() {
+            let outcome = self.handle(&command);
+            outcomes.push((command, outcome));
+        }
+        let names: Vec<String> = self.services.keys().cloned().collect();
+        for name in names {
+            self.supervise(&name);
+        }
+        outcomes
+    }
+
+    fn run_until(&mut self, end: u64, step: u64) {
+        while self.now + step <= end {
+            let now = self.now + step;
+            self.tick(now);
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.shutting_down
+            && self
+                .services
+                .values()
+                .all(|m| m.state.kind() == StateKind::Stopped)
+    }
+
+    fn status(&self, name: &str) -> Option<ServiceStatus> {
+        self.services.get(name).map(|m| ServiceStatus {
+            name: name.to_string(),
+            state: m.state.clone(),
+            restarts: m.total_restarts,
+            now: self.now,
+        })
+    }
+
+    fn report(&self) -> Vec<ServiceStatus> {
+        self.services
+            .keys()
+            .filter_map(|name| self.status(name))
+            .collect()
+    }
+
+    fn state_of(&self, name: &str) -> StateKind {
+        self.services[name].state.kind()
+    }
+
+    // The only place a state changes
+    fn set_state(&mut self, name: &str, to: State) -> Result<(), SupervisorError> {
+        let managed = self
+            .services
+            .get_mut(name)
+            .ok_or_else(|| SupervisorError::UnknownService(name.to_string()))?;
+        let from = managed.state.kind();
+        if !allowed(from, to.kind()) {
+            return Err(SupervisorError::InvalidTransition {
+                service: name.to_string(),
+                from,
+                to: to.kind(),
+            });
+        }
+        self.log.push(Transition {
+            at: self.now,
+            service: name.to_string(),
+            from,
+            to: to.kind(),
+        });
+        managed.state = to;
+        Ok(())
+    }
+
+    fn managed(&mut self, name: &str) -> &mut Managed {
+        self.services
+            .get_mut(name)
+            .expect("caller checked the name")
+    }
+
+    fn enter_starting(&mut self, name: &str) -> Result<(), SupervisorError> {
+        let now = self.now;
+        self.set_state(name, State::Starting { since: now })?;
+        if let Err(reason) = self.managed(name).service.start(now) {
+            self.fail(name, format!("start failed: {}", reason));
+        }
+        Ok(())
+    }
+
+    fn enter_stopping(&mut self, name: &str, then_start: bool) -> Result<(), SupervisorError> {
+        let now = self.now;
+        self.set_state(
+            name,
+            State::Stopping {
+                since: now,
+                then_start,
+            },
+        )?;
+        self.managed(name).service.stop(now);
+        Ok(())
+    }
+
+    // Record the failure and schedule a restart if the policy allows one
+    fn fail(&mut self, name: &str, reason: String) {
+        let now = self.now;
+        let shutting_down = self.shutting_down;
+        let managed = self.managed(name);
+        let retry_at = match managed.spec.policy.next_delay(managed.restarts) {
+            Some(delay) if !shutting_down => Some(now + delay),
+            _ => None,
+        };
+        self.set_state(name, State::Failed { reason, retry_at })
+            .expect("every live state may fail");
+    }
+
+    fn handle(&mut self, command: &Command) -> Result<String, SupervisorError> {
+        if self.shutting_down {
+            return Err(SupervisorError::ShuttingDown);
+        }
+        if let Command::Start(name)
+        | Command::Stop(name)
+        | Command::Restart(name)
+        | Command::Status(name) = command
+            && !self.services.contains_key(name)
+        {
+            return Err(SupervisorError::UnknownService(name.clone()));
+        }
+
+        match command {
+            Command::Start(name) => {
+                self.enter_starting(name)?;
+                self.managed(name).restarts = 0; // A manual start gets a fresh budget
+                Ok(format!("{} is {}", name, self.state_of(name)))
+            }
+            Command::Stop(name) => {
+                if self.state_of(name) == StateKind::Failed {
+                    self.set_state(name, State::Stopped)?; // Nothing to stop; cancel the retry
+                } else {
+                    self.enter_stopping(name, false)?;
+                }
+                Ok(format!("{} is {}", name, self.state_of(name)))
+            }
+            Command::Restart(name) => {
+                match self.state_of(name) {
+                    StateKind::Running | StateKind::Starting => self.enter_stopping(name, true)?,
+                    _ => self.enter_starting(name)?,
+                }
+                Ok(format!("{} is {}", name, self.state_of(name)))
+            }
+            Command::Status(name) => Ok(self.status(name).expect("checked above").to_string()),
+            Command::Exit => {
+                self.shutting_down = true;
+                let names: Vec<String> = self.services.keys().cloned().collect();
+                let mut stopping = 0;
+                for name in names {
+                    match self.state_of(&name) {
+                        StateKind::Starting | StateKind::Running => {
+                            self.enter_stopping(&name, false)?;
+                            stopping += 1;
+                        }
+                        StateKind::Failed => self.set_state(&name, State::Stopped)?,
+                        StateKind::Stopping => {
+                            // A pending restart must not bring it back up
+                            if let State::Stopping { then_start, .. } =
+                                &mut self.managed(&name).state
+                            {
+                                *then_start = false;
+                            }
+                        }
+                        StateKind::Stopped => {}
+                    }
+                }
+                // Commands queued behind Exit still reach handle() and get ShuttingDown
+                Ok(format!("shutting down, stopping {} services", stopping))
+            }
+        }
+    }
+
+    // Move one service forward based on what it reports and the clock
+    fn supervise(&mut self, name: &str) {
+        let now = self.now;
+        let shutting_down = self.shutting_down;
+        let managed = self.managed(name);
+        let spec = managed.spec;
+        let state = managed.state.clone();
+
+        match state {
+            State::Starting { since } => match managed.service.poll(now) {
+                Probe::Up => {
+                    self.set_state(name, State::Running { since: now }).unwrap();
+                }
+                Probe::Crashed(reason) => self.fail(name, reason),
+                _ if now - since >= spec.start_timeout => {
+                    managed.service.kill();
+                    self.fail(name, format!("not ready within {}ms", spec.start_timeout));
+                }
+                _ => {}
+            },
+            State::Running { since } => match managed.service.poll(now) {
+                Probe::Crashed(reason) => self.fail(name, reason),
+                Probe::Down if spec.policy.on == RestartOn::Always => {
+                    self.fail(name, "exited".to_string())
+                }
+                Probe::Down => self.set_state(name, State::Stopped).unwrap(),
+                _ => {
+                    if now - since >= spec.policy.stable_after {
+                        managed.restarts = 0;
+                    }
+                }
+            },
+            State::Stopping { since, then_start } => {
+                let probe = managed.service.poll(now);
+                let exited = matches!(probe, Probe::Down | Probe::Crashed(_));
+                if !exited && now - since < spec.stop_timeout {
+                    return;
+                }
+                if !exited {
+                    managed.service.kill();
+                }
+                self.set_state(name, State::Stopped).unwrap();
+                if then_start {
+                    self.enter_starting(name).unwrap();
+                }
+            }
+            State::Failed {
+                retry_at: Some(at), ..
+            } if now >= at && !shutting_down => {
+                managed.restarts += 1;
+                managed.total_restarts += 1;
+                self.enter_starting(name).unwrap();
+            }
+            State::Failed { .. } | State::Stopped => {}
+        }
+    }
+
+    fn history(&self, name: &str) -> Vec<StateKind> {
+        let mut states = vec![StateKind::Stopped];
+        states.extend(self.log.iter().filter(|t| t.service == name).map(|t| t.to));
+        states
+    }
+
+    fn times_entering(&self, name: &str, kind: StateKind) -> Vec<u64> {
+        self.log
+            .iter()
+            .filter(|t| t.service == name && t.to == kind)
+            .map(|t| t.at)
+            .collect()
+    }
+}
+
+fn print_outcomes(outcomes: &[(Command, Result<String, SupervisorError>)]) {
+    for (command, outcome) in outcomes {
+        match outcome {
+            Ok(message) => println!("  ✅ {:<18} -> {}", command.to_string(), message),
+            Err(e) => println!("  ❌ {:<18} -> {}", command.to_string(), e),
+        }
+    }
+}
+
+fn main() {
+    use StateKind::*;
+
+    println!("🎛️  === SERVICE SUPERVISOR ===");
+    let calls = Rc::new(RefCell::new(Vec::new()));
+
+    // ========================================================================
+    // 1. THE while-let.rs COMMANDS, IN FIFO ORDER
+    // ========================================================================
+    println!("\n📜 === while-let.rs COMMANDS, FIRST IN FIRST OUT ===");
+
+    let mut sup = Supervisor::default();
+    let spec = ServiceSpec::new(RestartPolicy::on_failure(3, 50));
+    sup.add("web-server", Box::new(FakeService::new(&calls)), spec);
+    sup.add("database", Box::new(FakeService::new(&calls)), spec);
+    sup.add("cache", Box::new(FakeService::new(&calls)), spec);
+
+    let commands = [
+        Command::Start("web-server".to_string()),
+        Command::Status("web-server".to_string()),
+        Command::Stop("database".to_string()),
+        Command::Restart("cache".to_string()),
+        Command::Status("cache".to_string()),
+        Command::Exit,
+    ];
+    for command in commands.iter().cloned() {
+        sup.submit(command);
+    }
+    let outcomes = sup.tick(0);
+    print_outcomes(&outcomes);
+    let handled: Vec<Command> = outcomes.iter().map(|(c, _)| c.clone()).collect();
+    assert_eq!(handled, commands); // Same order they were submitted
+    assert!(matches!(
+        outcomes[2].1,
+        Err(SupervisorError::InvalidTransition {
+            from: Stopped,
+            to: Stopping,
+            ..
+        })
+    ));
+
+    sup.run_until(200, 10);
+    assert!(sup.is_finished());
+    println!("  web-server went {:?}", sup.history("web-server"));
+    assert_eq!(
+        sup.history("web-server"),
+        [Stopped, Starting, Stopping, Stopped]
+    );
+    assert_eq!(sup.history("database"), [Stopped]);
+
+    // ========================================================================
+    // 2. INVALID TRANSITIONS ARE REJECTED
+    // ========================================================================
+    println!("\n🚫 === INVALID TRANSITIONS ===");
+
+    let mut sup = Supervisor::default();
+    sup.add("api", Box::new(FakeService::new(&calls)), spec);
+    sup.submit(Command::Start("api".to_string()));
+    sup.submit(Command::Start("api".to_string())); // Already Starting
+    print_outcomes(&sup.tick(0));
+    sup.run_until(50, 10);
+    assert_eq!(sup.state_of("api"), Running);
+
+    sup.submit(Command::Start("api".to_string())); // Already Running
+    sup.submit(Command::Stop("api".to_string()));
+    sup.submit(Command::Restart("api".to_string())); // Still Stopping
+    sup.submit(Command::Status("api".to_string()));
+    sup.submit(Command::Stop("mailer".to_string()));
+    let outcomes = sup.tick(60);
+    print_outcomes(&outcomes);
+    let errors: Vec<_> = outcomes.into_iter().filter_map(|(_, r)| r.err()).collect();
+    assert_eq!(
+        errors,
+        [
+            SupervisorError::InvalidTransition {
+                service: "api".to_string(),
+                from: Running,
+                to: Starting
+            },
+            SupervisorError::InvalidTransition {
+                service: "api".to_string(),
+                from: Stopping,
+                to: Starting
+            },
+            SupervisorError::UnknownService("mailer".to_string()),
+        ]
+    );
+    for (from, to) in [
+        (Stopped, Running),
+        (Stopping, Starting),
+        (Failed, Running),
+        (Running, Starting),
+    ] {
+        assert!(!allowed(from, to));
+    }
+
+    // ========================================================================
+    // 3. RESTART POLICIES WITH BACKOFF
+    // ========================================================================
+    println!("\n🔁 === RESTART WITH EXPONENTIAL BACKOFF ===");
+
+    let mut sup = Supervisor::default();
+    let crashy = FakeService::new(&calls)
+        .start_delay(0)
+        .crashes_after(&[30, 30, 30, 30, 30]);
+    sup.add(
+        "worker",
+        Box::new(crashy),
+        ServiceSpec::new(RestartPolicy::on_failure(3, 100)),
+    );
+    let never = FakeService::new(&calls).start_delay(0).crashes_after(&[30]);
+    sup.add(
+        "one-shot",
+        Box::new(never),
+        ServiceSpec::new(RestartPolicy::never()),
+    );
+    sup.submit(Command::Start("worker".to_string()));
+    sup.submit(Command::Start("one-shot".to_string()));
+    sup.tick(0);
+    sup.run_until(2_000, 10);
+
+    let crashes = sup.times_entering("worker", Failed);
+    let restarts = sup.times_entering("worker", Starting);
+    let delays: Vec<u64> = restarts[1..]
+        .iter()
+        .zip(&crashes)
+        .map(|(r, c)| r - c)
+        .collect();
+    println!("  worker crashed at {:?}", crashes);
+    println!("  restart delays {:?}ms, then gave up", delays);
+    assert_eq!(delays, [100, 200, 400]);
+    println!("  {}", sup.status("worker").unwrap());
+    println!("  {}", sup.status("one-shot").unwrap());
+    assert!(matches!(
+        sup.services["worker"].state,
+        State::Failed { retry_at: None, .. }
+    ));
+    assert_eq!(
+        sup.history("one-shot"),
+        [Stopped, Starting, Running, Failed]
+    );
+
+    // A long enough healthy run resets the restart budget
+    let mut sup = Supervisor::default();
+    let recovering = FakeService::new(&calls)
+        .start_delay(0)
+        .crashes_after(&[10, 1_500, 10]);
+    sup.add(
+        "indexer",
+        Box::new(recovering),
+        ServiceSpec::new(RestartPolicy::on_failure(1, 100)),
+    );
+    sup.submit(Command::Start("indexer".to_string()));
+    sup.tick(0);
+    sup.run_until(3_000, 10);
+    assert_eq!(sup.times_entering("indexer", Starting).len(), 3); // 1 start + 2 restarts
+    println!("  indexer (max 1 restart) restarted twice thanks to a stable run in between");
+
+    // A Status command can run after a retry fell due but before supervise() restarts it
+    let mut sup = Supervisor::default();
+    let flaky = FakeService::new(&calls).start_delay(0).crashes_after(&[10]);
+    sup.add(
+        "mailer",
+        Box::new(flaky),
+        ServiceSpec::new(RestartPolicy::on_failure(3, 15)),
+    );
+    sup.submit(Command::Start("mailer".to_string()));
+    sup.tick(0);
+    sup.tick(10); // Crashes, retry at 25
+    sup.submit(Command::Status("mailer".to_string()));
+    let outcomes = sup.tick(40);
+    let report = outcomes[0].1.as_ref().unwrap();
+    println!("  {}", report);
+    assert!(report.contains("retry due"));
+
+    // Always also restarts clean exits; a failing start() counts as a failure
+    let mut sup = Supervisor::default();
+    let exiting = FakeService::new(&calls).exits_after(50).failed_starts(1);
+    sup.add(
+        "cron",
+        Box::new(exiting),
+        ServiceSpec::new(RestartPolicy::always(2, 10)),
+    );
+    sup.submit(Command::Start("cron".to_string()));
+    sup.tick(0);
+    sup.run_until(400, 10);
+    println!("  cron: {:?}", sup.history("cron"));
+    assert_eq!(
+        sup.history("cron")[..6],
+        [Stopped, Starting, Failed, Starting, Running, Failed]
+    );
+
+    // ========================================================================
+    // 4. TIMEOUTS
+    // ========================================================================
+    println!("\n⏱️  === START AND STOP TIMEOUTS ===");
+
+    calls.borrow_mut().clear();
+    let mut sup = Supervisor::default();
+    let slow = FakeService::new(&calls).start_delay(10_000);
+    sup.add(
+        "slow-boot",
+        Box::new(slow),
+        ServiceSpec::new(RestartPolicy::never()),
+    );
+    let stubborn = FakeService::new(&calls).hangs_on_stop();
+    sup.add(
+        "stubborn",
+        Box::new(stubborn),
+        ServiceSpec::new(RestartPolicy::never()),
+    );
+    sup.submit(Command::Start("slow-boot".to_string()));
+    sup.submit(Command::Start("stubborn".to_string()));
+    sup.tick(0);
+    sup.run_until(100, 10);
+    sup.submit(Command::Stop("stubborn".to_string()));
+    sup.run_until(300, 10);
+    println!("  {}", sup.status("slow-boot").unwrap());
+    println!("  {}", sup.status("stubborn").unwrap());
+    println!("  calls: {:?}", calls.borrow());
+    assert_eq!(sup.times_entering("slow-boot", Failed), [200]);
+    assert_eq!(sup.times_entering("stubborn", Stopped), [210]);
+    assert_eq!(calls.borrow().iter().filter(|c| *c == "kill").count(), 2);
+
+    // ========================================================================
+    // 5. STATUS REPORT AND GRACEFUL EXIT
+    // ========================================================================
+    println!("\n📊 === STATUS REPORT ===");
+
+    let mut sup = Supervisor::default();
+    sup.add("web-server", Box::new(FakeService::new(&calls)), spec);
+    sup.add(
+        "database",
+        Box::new(FakeService::new(&calls).start_delay(80)),
+        spec,
+    );
+    let flaky = FakeService::new(&calls).start_delay(0).crashes_after(&[20]);
+    sup.add("cache", Box::new(flaky), spec);
+    for name in ["database", "web-server", "cache"] {
+        sup.submit(Command::Start(name.to_string()));
+    }
+    sup.tick(0);
+    sup.run_until(60, 10);
+    for status in sup.report() {
+        println!("  {}", status);
+    }
+    assert_eq!(sup.state_of("web-server"), Running);
+    assert_eq!(sup.state_of("database"), Starting);
+    assert_eq!(sup.state_of("cache"), Failed);
+
+    sup.submit(Command::Exit);
+    sup.submit(Command::Start("web-server".to_string())); // Refused: after Exit
+    let outcomes = sup.tick(70);
+    print_outcomes(&outcomes);
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[1].1, Err(SupervisorError::ShuttingDown));
+    sup.run_until(200, 10);
+    assert!(sup.is_finished());
+    assert_eq!(sup.times_entering("cache", Starting).len(), 1); // No restart during shutdown
+    println!("  all services stopped: {}", sup.is_finished());
+
+    println!("\n📋 === SERVICE SUPERVISOR SUMMARY ===");
+    println!("✅ Commands are queued in a VecDeque and handled first-in, first-out");
+    println!("✅ Every state change goes through one allowed(from, to) table");
+    println!("✅ Invalid commands return an error naming the from/to states");
+    println!("✅ Crashes restart after 1x, 2x, 4x... backoff until the budget runs out");
+    println!("✅ The Service trait lets a scripted fake stand in for real processes");
+}
@@ -0,0 +1,623 @@
+/*
+TEXT ANALYTICS - A Streaming `wc` With Word Frequencies and Readability
+=======================================================================
+
+WHAT IS THIS?
+- iterator.rs splits one sentence with split_whitespace() to get word lengths
+- split_whitespace() keeps punctuation ("dog." != "dog") and is case-sensitive ("The" != "the")
+- This file tokenizes properly, counts words, n-grams and sentences, filters stop words,
+  and computes readability scores - all while reading input one line at a time
+
+REAL-WORLD ANALOGY:
+- Like a librarian skimming a book with a tally sheet:
+  they never hold the whole book in their head, just the running counts
+
+USAGE:
+    ./run_basic.sh text-stats.rs                  # No arguments: demo on built-in text
+    rustc text-stats.rs && ./text-stats FILE...   # Analyze files
+    ./text-stats -n 5 - < FILE                    # '-' reads stdin, show top 5
+    ./text-stats a.txt - b.txt                    # '-' is stdin among other files
+    ./text-stats --keep-stop-words FILE           # Don't filter "the", "and", ...
+
+TOKENIZATION RULES:
+┌────────────────────────┬────────────────────────┬──────────────────────────────────┐
+│         Input          │        Tokens          │              Why                 │
+├────────────────────────┼────────────────────────┼──────────────────────────────────┤
+│ "The dog."             │ the, dog               │ Punctuation splits, case folded  │
+│ "don't" / "don’t"      │ don't                  │ Inner apostrophes are kept       │
+│ "'quoted'"             │ quoted                 │ Outer apostrophes are quotes     │
+│ "Straße ÉCOLE"         │ straße, école          │ char::is_alphanumeric is Unicode │
+│ "state-of-the-art"     │ state, of, the, art    │ Hyphens separate words           │
+│ "3.14 is pi."          │ 3.14, is, pi           │ A dot between digits is no stop  │
+└────────────────────────┴────────────────────────┴──────────────────────────────────┘
+
+READABILITY (English heuristics, syllables estimated from vowel groups):
+- Flesch Reading Ease  = 206.835 - 1.015 * words/sentences - 84.6 * syllables/words
+  (higher = easier; 60-70 is plain English)
+- Flesch-Kincaid Grade = 0.39 * words/sentences + 11.8 * syllables/words - 15.59
+  (approximate US school grade)
+*/
+
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "had", "has", "have",
+    "he", "her", "his", "i", "in", "is", "it", "its", "of", "on", "or", "she", "so", "that", "the",
+    "their", "they", "this", "to", "was", "we", "were", "with", "you",
+];
+
+// ============================================================================
+// TOKENIZER
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    SentenceEnd,
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’'
+}
+
+// Split one line into case-folded words and sentence boundaries
+fn tokenize(line: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut chars = line.chars().peekable();
+
+    // Apostrophes are only added when a letter follows, so a word never ends with one
+    fn flush(word: &mut String, tokens: &mut Vec<Token>) {
+        if !word.is_empty() {
+            tokens.push(Token::Word(std::mem::take(word)));
+        }
+    }
+
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() {
+            word.extend(c.to_lowercase());
+        } else if c == '.'
+            && word.ends_with(|d: char| d.is_numeric())
+            && chars.peek().is_some_and(|next| next.is_numeric())
+        {
+            word.push('.'); // A decimal point: "3.14" is one token, not a sentence end
+        } else if is_apostrophe(c) && !word.is_empty() {
+            // Keep it only when a letter follows: "don't" yes, "dogs' " no
+            match chars.peek() {
+                Some(next) if next.is_alphanumeric() => word.push('\''),
+                _ => flush(&mut word, &mut tokens),
+            }
+        } else {
+            flush(&mut word, &mut tokens);
+            if matches!(c, '.' | '!' | '?') {
+                // "Wait..." or "What?!" is one sentence end, not three
+                while matches!(chars.peek(), Some('.' | '!' | '?')) {
+                    chars.next();
+                }
+                tokens.push(Token::SentenceEnd);
+            }
+        }
+    }
+    flush(&mut word, &mut tokens);
+    tokens
+}
+
+// Count vowel groups, minus a silent final 'e' - good enough for English prose
+fn estimate_syllables(word: &str) -> usize {
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+    let chars: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+    if chars.is_empty() {
+        return 0;
+    }
+
+    let mut groups = 0;
+    let mut previous_vowel = false;
+    for &c in &chars {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            groups += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    let n = chars.len();
+    let silent_e = n > 2 && chars[n - 1] == 'e' && !is_vowel(chars[n - 2]) && chars[n - 2] != 'l';
+    if silent_e && groups > 1 {
+        groups -= 1;
+    }
+    groups.max(1)
+}
+
+// ============================================================================
+// STREAMING STATISTICS
+// ============================================================================
+
+struct TextStats {
+    max_ngram: usize,
+    lines: usize,
+    bytes: usize,
+    chars: usize,
+    words: usize,
+    sentences: usize,
+    syllables: usize,
+    word_counts: HashMap<String, usize>,
+    ngram_counts: HashMap<Vec<String>, usize>,
+    window: VecDeque<String>, // Last few words of the current sentence
+    open_sentence: bool,      // Saw words since the last sentence end
+}
+
+impl TextStats {
+    fn new(max_ngram: usize) -> Self {
+        TextStats {
+            max_ngram: max_ngram.max(1),
+            lines: 0,
+            bytes: 0,
+            chars: 0,
+            words: 0,
+            sentences: 0,
+            syllables: 0,
+            word_counts: HashMap::new(),
+            ngram_counts: HashMap::new(),
+            window: VecDeque::new(),
+            open_sentence: false,
+        }
+    }
+
+    // `line` includes its newline (if any), exactly as read_line returns it
+    fn feed_line(&mut self, line: &str) {
+        self.feed_bytes(line.as_bytes());
+    }
+
+    // Bytes are counted as read; invalid UTF-8 is decoded as U+FFFD instead of failing
+    fn feed_bytes(&mut self, raw: &[u8]) {
+        if raw.ends_with(b"\n") {
+            self.lines += 1;
+        }
+        self.bytes += raw.len();
+        let line = String::from_utf8_lossy(raw);
+        self.chars += line.chars().count();
+
+        for token in tokenize(&line) {
+            match token {
+                Token::Word(word) => self.add_word(word),
+                Token::SentenceEnd => self.end_sentence(),
+            }
+        }
+    }
+
+    fn add_word(&mut self, word: String) {
+        self.words += 1;
+        self.syllables += estimate_syllables(&word);
+        self.open_sentence = true;
+
+        // N-grams don't cross sentence boundaries, but they do cross line breaks
+        self.window.push_back(word.clone());
+        if self.window.len() > self.max_ngram {
+            self.window.pop_front();
+        }
+        for n in 2..=self.window.len() {
+            let gram: Vec<String> = self
+                .window
+                .iter()
+                .skip(self.window.len() - n)
+                .cloned()
+                .collect();
+            *self.ngram_counts.entry(gram).or_insert(0) += 1;
+        }
+
+        *self.word_counts.entry(word).or_insert(0) += 1;
+    }
+
+    fn end_sentence(&mut self) {
+        if self.open_sentence {
+            self.sentences += 1;
+        }
+        self.open_sentence = false;
+        self.window.clear();
+    }
+
+    // Stream any reader line by line - memory use depends on vocabulary, not file size
+    fn feed_reader<R: BufRead>(&mut self, mut reader: R) -> io::Result<()> {
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            self.feed_bytes(&line);
+            line.clear();
+        }
+        Ok(())
+    }
+
+    // Combine counts from another input (used for the "total" line)
+    fn merge(&mut self, other: &TextStats) {
+        self.lines += other.lines;
+        self.bytes += other.bytes;
+        self.chars += other.chars;
+        self.words += other.words;
+        self.sentences += other.sentence_count();
+        self.syllables += other.syllables;
+        for (word, count) in &other.word_counts {
+            *self.word_counts.entry(word.clone()).or_insert(0) += count;
+        }
+        for (gram, count) in &other.ngram_counts {
+            *self.ngram_counts.entry(gram.clone()).or_insert(0) += count;
+        }
+    }
+
+    // A final sentence without a full stop still counts
+    fn sentence_count(&self) -> usize {
+        self.sentences + usize::from(self.open_sentence)
+    }
+
+    // Most frequent first; ties broken alphabetically so output is stable
+    fn top_words(&self, n: usize, skip_stop_words: bool) -> Vec<(&str, usize)> {
+        let mut entries: Vec<(&str, usize)> = self
+            .word_counts
+            .iter()
+            .filter(|(word, _)| !skip_stop_words || !STOP_WORDS.contains(&word.as_str()))
+            .map(|(word, count)| (word.as_str(), *count))
+            .collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        entries.truncate(n);
+        entries
+    }
+
+    fn top_ngrams(&self, size: usize, n: usize) -> Vec<(String, usize)> {
+        let mut entries: Vec<(String, usize)> = self
+            .ngram_counts
+            .iter()
+            .filter(|(gram, _)| gram.len() == size)
+            .map(|(gram, count)| (gram.join(" "), *count))
+            .collect();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        entries.truncate(n);
+        entries
+    }
+
+    // None when there are no words - a score for empty input is meaningless
+    fn flesch_reading_ease(&self) -> Option<f64> {
+        let (w, s, y) = self.ratios()?;
+        Some(206.835 - 1.015 * (w / s) - 84.6 * (y / w))
+    }
+
+    fn flesch_kincaid_grade(&self) -> Option<f64> {
+        let (w, s, y) = self.ratios()?;
+        Some(0.39 * (w / s) + 11.8 * (y / w) - 15.59)
+    }
+
+    fn ratios(&self) -> Option<(f64, f64, f64)> {
+        if self.words == 0 {
+            return None;
+        }
+        let sentences = self.sentence_count().max(1);
+        Some((self.words as f64, sentences as f64, self.syllables as f64))
+    }
+
+    fn print_report(&self, label: &str, top_n: usize, skip_stop_words: bool) {
+        println!(
+            "{:>7} {:>7} {:>7} {:>7} {:>7}  {}",
+            self.lines,
+            self.words,
+            self.sentence_count(),
+            self.chars,
+            self.bytes,
+            label
+        );
+        println!("  (lines, words, sentences, chars, bytes)");
+
+        println!(
+            "  Unique words: {}{}",
+            self.word_counts.len(),
+            if skip_stop_words {
+                " (top list skips stop words)"
+            } else {
+                ""
+            }
+        );
+        for (rank, (word, count)) in self.top_words(top_n, skip_stop_words).iter().enumerate() {
+            println!("  {:>3}. {:<16} {}", rank + 1, word, count);
+        }
+
+        for size in 2..=self.max_ngram {
+            let grams = self.top_ngrams(size, top_n.min(5));
+            if !grams.is_empty() {
+                let shown: Vec<String> = grams
+                    .iter()
+                    .map(|(g, c)| format!("\"{}\" x{}", g, c))
+                    .collect();
+                println!("  Top {}-grams: {}", size, shown.join(", "));
+            }
+        }
+
+        match (self.flesch_reading_ease(), self.flesch_kincaid_grade()) {
+            (Some(ease), Some(grade)) => {
+                println!("  Reading ease: {:.1}, grade level: {:.1}", ease, grade)
+            }
+            _ => println!("  Readability: n/a (no words)"),
+        }
+    }
+}
+
+// ============================================================================
+// COMMAND LINE
+// ============================================================================
+
+struct Options {
+    top_n: usize,
+    skip_stop_words: bool,
+    inputs: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        top_n: 10,
+        skip_stop_words: true,
+        inputs: Vec::new(),
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-n" => {
+                let value = iter.next().ok_or("-n needs a number")?;
+                options.top_n = value
+                    .parse()
+                    .map_err(|_| format!("-n: '{}' is not a number", value))?;
+            }
+            "--keep-stop-words" => options.skip_stop_words = false,
+            // Everything after "--" is a file name, even "-n"
+            "--" => options.inputs.extend(iter.by_ref().cloned()),
+            flag if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option '{}'", flag));
+            }
+            _ => options.inputs.push(arg.clone()),
+        }
+    }
+    if options.inputs.is_empty() {
+        return Err("no input files (use - to read stdin)".to_string());
+    }
+    Ok(options)
+}
+
+// Like wc, an unreadable file is reported and skipped; returns how many failed
+fn run_command(options: &Options) -> usize {
+    let mut total = TextStats::new(3);
+    let many = options.inputs.len() > 1;
+    let mut failures = 0;
+
+    for input in &options.inputs {
+        let mut stats = TextStats::new(3);
+        let result = if input == "-" {
+            stats.feed_reader(io::stdin().lock())
+        } else {
+            File::open(input).and_then(|file| stats.feed_reader(BufReader::new(file)))
+        };
+        if let Err(e) = result {
+            eprintln!("text-stats: {}: {}", input, e);
+            failures += 1;
+            continue;
+        }
+        stats.print_report(input, options.top_n, options.skip_stop_words);
+
+        if many {
+            total.merge(&stats);
+        }
+    }
+
+    if many {
+        total.print_report("total", options.top_n, options.skip_stop_words);
+    }
+    failures
+}
+
+const SAMPLE: &str = "The quick brown fox jumps over the lazy dog. The dog doesn't mind!
+The fox’s friends say it's 'just a game'... Really?! Yes.
+Straße and ÉCOLE are words too; state-of-the-art tokenizers know that.
+The quick brown fox jumps again, and the lazy dog sleeps
+";
+
+fn main() {
+    // Arguments mean `wc` mode; with none, run the demo and its checks
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        match parse_args(&args) {
+            Ok(options) if run_command(&options) == 0 => {}
+            Ok(_) => std::process::exit(1),
+            Err(message) => {
+                eprintln!("text-stats: {}", message);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    println!("📖 === TEXT ANALYTICS TOOLKIT ===");
+
+    // ========================================================================
+    // 1. TOKENIZATION
+    // ========================================================================
+    println!("\n🔤 === TOKENIZATION ===");
+
+    let words = |line: &str| -> Vec<String> {
+        tokenize(line)
+            .into_iter()
+            .filter_map(|t| match t {
+                Token::Word(w) => Some(w),
+                Token::SentenceEnd => None,
+            })
+            .collect()
+    };
+
+    let examples = [
+        ("The lazy dog.", vec!["the", "lazy", "dog"]),
+        ("don't don’t", vec!["don't", "don't"]),
+        ("'quoted' dogs'", vec!["quoted", "dogs"]),
+        ("Straße ÉCOLE", vec!["straße", "école"]),
+        ("state-of-the-art", vec!["state", "of", "the", "art"]),
+    ];
+    for (input, expected) in examples {
+        let got = words(input);
+        println!("  {:<20} -> {:?}", format!("{:?}", input), got);
+        assert_eq!(got, expected);
+    }
+
+    // split_whitespace from iterator.rs for comparison
+    let naive: Vec<&str> = "The lazy dog.".split_whitespace().collect();
+    println!("  split_whitespace gives {:?} - note \"dog.\"", naive);
+
+    let ends = tokenize("Wait... What?! Ok.")
+        .iter()
+        .filter(|t| **t == Token::SentenceEnd)
+        .count();
+    assert_eq!(ends, 3);
+
+    // A decimal point is part of the number, not the end of a sentence
+    assert_eq!(
+        tokenize("Pi is 3.14. Done"),
+        vec![
+            Token::Word("pi".to_string()),
+            Token::Word("is".to_string()),
+            Token::Word("3.14".to_string()),
+            Token::SentenceEnd,
+            Token::Word("done".to_string()),
+        ]
+    );
+
+    println!(
+        "  Syllables: fox={}, lazy={}, jumps={}, readability={}",
+        estimate_syllables("fox"),
+        estimate_syllables("lazy"),
+        estimate_syllables("jumps"),
+        estimate_syllables("readability")
+    );
+    assert_eq!(estimate_syllables("fox"), 1);
+    assert_eq!(estimate_syllables("lazy"), 2);
+    assert_eq!(estimate_syllables("make"), 1);
+
+    // ========================================================================
+    // 2. STREAMING STATISTICS
+    // ========================================================================
+    println!("\n🌊 === STREAMING STATISTICS ===");
+
+    // Any BufRead works: a file, stdin, or an in-memory byte slice
+    let mut stats = TextStats::new(3);
+    stats.feed_reader(SAMPLE.as_bytes()).unwrap();
+    stats.print_report("<sample>", 5, true);
+
+    assert_eq!(stats.lines, 4);
+    assert_eq!(stats.bytes, SAMPLE.len());
+    assert_eq!(stats.chars, SAMPLE.chars().count());
+    assert_eq!(stats.word_counts["the"], 7);
+    assert_eq!(stats.word_counts["fox"], 2);
+    assert_eq!(stats.word_counts["fox's"], 1);
+    assert_eq!(stats.sentence_count(), 7); // Last line has no full stop but still counts
+
+    // Per-file stats merge into a total, like the last line of `wc a b`
+    let mut total = TextStats::new(3);
+    total.merge(&stats);
+    total.merge(&stats);
+    assert_eq!(total.words, 2 * stats.words);
+    assert_eq!(total.sentence_count(), 14);
+    assert_eq!(total.word_counts["the"], 14);
+
+    // ========================================================================
+    // 3. FREQUENCIES AND N-GRAMS
+    // ========================================================================
+    println!("\n🔢 === FREQUENCIES AND N-GRAMS ===");
+
+    let with_stop = stats.top_words(3, false);
+    let without_stop = stats.top_words(3, true);
+    println!("  Top 3 with stop words:    {:?}", with_stop);
+    println!("  Top 3 without stop words: {:?}", without_stop);
+    assert_eq!(with_stop[0], ("the", 7));
+    assert!(without_stop.iter().all(|(w, _)| !STOP_WORDS.contains(w)));
+
+    let bigrams = stats.top_ngrams(2, 3);
+    println!("  Top bigrams: {:?}", bigrams);
+    assert_eq!(bigrams[0], ("brown fox".to_string(), 2));
+
+    // N-grams stop at sentence ends: "dog the" never appears
+    assert!(
+        !stats
+            .ngram_counts
+            .contains_key(&vec!["dog".to_string(), "the".to_string()])
+    );
+
+    // ...but continue across line breaks inside a sentence
+    let mut wrapped = TextStats::new(2);
+    wrapped.feed_reader("new\nline".as_bytes()).unwrap();
+    assert_eq!(wrapped.top_ngrams(2, 1), vec![("new line".to_string(), 1)]);
+
+    // ========================================================================
+    // 4. READABILITY
+    // ========================================================================
+    println!("\n📏 === READABILITY ===");
+
+    let mut simple = TextStats::new(2);
+    simple.feed_line("The cat sat on the mat. The dog ran.\n");
+    let mut dense = TextStats::new(2);
+    dense.feed_line(
+        "Comprehensive institutional accountability necessitates considerable organizational transparency.\n",
+    );
+    let simple_ease = simple.flesch_reading_ease().unwrap();
+    let dense_ease = dense.flesch_reading_ease().unwrap();
+    println!("  Simple text ease: {:.1}", simple_ease);
+    println!("  Dense text ease:  {:.1}", dense_ease);
+    assert!(simple_ease > dense_ease);
+    assert!(simple.flesch_kincaid_grade().unwrap() < dense.flesch_kincaid_grade().unwrap());
+
+    // Empty input: counts are zero and scores are None rather than NaN
+    let empty = TextStats::new(2);
+    assert_eq!(empty.sentence_count(), 0);
+    assert!(empty.flesch_reading_ease().is_none());
+
+    // ========================================================================
+    // 5. COMMAND-LINE MODE
+    // ========================================================================
+    println!("\n💻 === COMMAND-LINE MODE ===");
+
+    let path = env::temp_dir().join("text_stats_demo.txt");
+    std::fs::write(&path, SAMPLE).unwrap();
+    let options = parse_args(&[
+        "-n".to_string(),
+        "3".to_string(),
+        path.to_string_lossy().to_string(),
+    ])
+    .unwrap();
+    assert_eq!(run_command(&options), 0);
+
+    // A missing file is reported and skipped; the others are still counted
+    let options = parse_args(&[
+        "no-such-file.txt".to_string(),
+        path.to_string_lossy().to_string(),
+    ])
+    .unwrap();
+    assert_eq!(run_command(&options), 1);
+    std::fs::remove_file(&path).ok();
+
+    // Invalid UTF-8 is replaced, not fatal; bytes are still the raw count
+    let mut latin1 = TextStats::new(2);
+    latin1.feed_reader(&b"caf\xe9 ok\n"[..]).unwrap();
+    assert_eq!((latin1.words, latin1.bytes, latin1.lines), (2, 8, 1));
+
+    assert!(parse_args(&["-n".to_string()]).is_err());
+    assert!(parse_args(&["-n".to_string(), "ten".to_string()]).is_err());
+
+    // Unknown flags are errors, not file names; "--" ends the options
+    let unknown = parse_args(&["-x".to_string(), "notes.txt".to_string()]);
+    println!("  -x notes.txt: {}", unknown.as_ref().err().unwrap());
+    assert!(unknown.is_err());
+    let after_dashes = parse_args(&["--".to_string(), "-x".to_string()]).unwrap();
+    assert_eq!(after_dashes.inputs, ["-x"]);
+
+    // Stdin is read only when asked for with '-'
+    assert!(parse_args(&["-n".to_string(), "3".to_string()]).is_err());
+    assert_eq!(parse_args(&["-".to_string()]).unwrap().inputs, ["-"]);
+
+    println!("\n📋 === TEXT ANALYTICS SUMMARY ===");
+    println!("✅ Tokens are Unicode-aware, case folded, and keep inner apostrophes");
+    println!("✅ Input is streamed line by line - memory grows with vocabulary only");
+    println!("✅ N-grams respect sentence boundaries but not line breaks");
+    println!("✅ Stop words are filtered only from the top-N list, not from the counts");
+    println!("✅ Pass file names (or - for stdin) to use it like wc");
+}
@@ -0,0 +1,764 @@
+/*
+TIME SERIES - Sensor Data Indexed by Real Timestamps
+====================================================
+
+WHAT IS THIS?
+- ranges.rs fakes 24 hourly `DataPoint { hour, temperature, humidity }` values
+  and samples them with step_by(6)
+- Real sensors report at real times, skip readings, and sometimes spike
+- This file stores (timestamp, value) pairs in time order and provides the usual
+  dashboard operations: resampling, smoothing, gap handling, anomaly flags, CSV
+
+REAL-WORLD ANALOGY:
+- Like a weather station logbook: each line has the time and the readings
+- Resampling = summarizing the log per day ("high 24°C, low 12°C")
+- Smoothing = squinting at the chart so the noise disappears
+- Gaps = pages where the station was offline
+
+OPERATIONS:
+┌───────────────────────────┬───────────────────────────────────────────────────────┐
+│         Method            │                      Result                           │
+├───────────────────────────┼───────────────────────────────────────────────────────┤
+│ resample(bucket)          │ min / max / mean / count per bucket (hour → day)      │
+│ moving_average(window)    │ Mean of the trailing time window at every point       │
+│ exponential_smoothing(α)  │ s = α·x + (1-α)·s_prev - recent values weigh more     │
+│ gaps(interval)            │ Spans where readings are missing                      │
+│ interpolate(interval)     │ Fills gaps with straight-line estimates               │
+│ anomalies(window, z)      │ Points more than z standard deviations from the       │
+│                           │ trailing window's mean                                │
+│ from_csv / to_csv         │ timestamp column (ISO-8601 or Unix seconds) + values  │
+└───────────────────────────┴───────────────────────────────────────────────────────┘
+
+KEY CONCEPTS:
+- Timestamps are i64 seconds since the Unix epoch (UTC); windows are in seconds
+- Points stay sorted by timestamp; inserting an existing timestamp replaces the value
+- Every operation returns a new value - the original series is never modified
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+const HOUR: i64 = 3_600;
+const DAY: i64 = 24 * HOUR;
+
+// ============================================================================
+// TIMESTAMP HELPERS
+// ============================================================================
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 for a civil date (Howard Hinnant's algorithm)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+fn format_timestamp(ts: i64) -> String {
+    let (y, m, d) = civil_from_days(ts.div_euclid(DAY));
+    let secs = ts.rem_euclid(DAY);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        y,
+        m,
+        d,
+        secs / HOUR,
+        secs % HOUR / 60,
+        secs % 60
+    )
+}
+
+// Accepts "2024-03-01T06:00:00Z" or plain Unix seconds like "1709272800"
+fn parse_timestamp(text: &str) -> Option<i64> {
+    if let Ok(secs) = text.parse::<i64>() {
+        return Some(secs);
+    }
+
+    let text = text.strip_suffix('Z')?;
+    let (date, time) = text.split_once('T')?;
+    let date: Vec<i64> = date
+        .split('-')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let time: Vec<i64> = time
+        .split(':')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    if date.len() != 3 || time.len() != 3 {
+        return None;
+    }
+    let (year, month, day) = (date[0], date[1], date[2]);
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    if time[0] > 23 || time[1] > 59 || time[2] > 59 {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * DAY + time[0] * HOUR + time[1] * 60 + time[2])
+}
+
+// ============================================================================
+// TIME SERIES
+// ============================================================================
+
+// interpolate() refuses to create more points than this in one call
+const MAX_FILLED: usize = 1_000_000;
+
+#[derive(Debug, PartialEq)]
+enum SeriesError {
+    NotPositive { name: &'static str, value: i64 },
+    TooManyPoints { requested: u64, limit: usize },
+}
+
+impl fmt::Display for SeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeriesError::NotPositive { name, value } => {
+                write!(f, "{} must be positive, got {}", name, value)
+            }
+            SeriesError::TooManyPoints { requested, limit } => write!(
+                f,
+                "interpolation would add {} points (limit {})",
+                requested, limit
+            ),
+        }
+    }
+}
+
+fn positive(name: &'static str, value: i64) -> Result<(), SeriesError> {
+    if value > 0 {
+        Ok(())
+    } else {
+        Err(SeriesError::NotPositive { name, value })
+    }
+}
+
+// Grid points strictly between t0 and t1 (t0 < t1), i.e. readings a gap is missing
+fn missing_between(t0: i64, t1: i64, interval: i64) -> u64 {
+    (t1.abs_diff(t0) - 1) / interval as u64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TimeSeries {
+    points: Vec<(i64, f64)>, // Always sorted by timestamp, no duplicates
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Bucket {
+    start: i64,
+    count: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Gap {
+    after: i64,  // Last reading before the gap
+    before: i64, // First reading after the gap
+    missing: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Anomaly {
+    timestamp: i64,
+    value: f64,
+    z_score: f64,
+}
+
+impl TimeSeries {
+    fn new() -> Self {
+        TimeSeries { points: Vec::new() }
+    }
+
+    fn from_points(points: impl IntoIterator<Item = (i64, f64)>) -> Self {
+        let mut series = TimeSeries::new();
+        for (ts, value) in points {
+            series.insert(ts, value);
+        }
+        series
+    }
+
+    // Binary search keeps inserts O(log n) to find + O(n) to shift
+    fn insert(&mut self, timestamp: i64, value: f64) {
+        match self.points.binary_search_by_key(&timestamp, |&(ts, _)| ts) {
+            Ok(index) => self.points[index].1 = value,
+            Err(index) => self.points.insert(index, (timestamp, value)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.points.iter().map(|&(_, v)| v)
+    }
+
+    // Group points into fixed-size buckets aligned to the epoch (days start at 00:00 UTC).
+    // Buckets with no readings are left out rather than reported as zero.
+    fn resample(&self, bucket: i64) -> Result<Vec<Bucket>, SeriesError> {
+        positive("bucket", bucket)?;
+        let mut buckets: Vec<Bucket> = Vec::new();
+        for &(ts, value) in &self.points {
+            let start = ts.div_euclid(bucket) * bucket;
+            match buckets.last_mut() {
+                Some(b) if b.start == start => {
+                    b.mean = (b.mean * b.count as f64 + value) / (b.count + 1) as f64;
+                    b.count += 1;
+                    b.min = b.min.min(value);
+                    b.max = b.max.max(value);
+                }
+                _ => buckets.push(Bucket {
+                    start,
+                    count: 1,
+                    min: value,
+                    max: value,
+                    mean: value,
+                }),
+            }
+        }
+        Ok(buckets)
+    }
+
+    // Mean of all points in (t - window, t] for every point t
+    fn moving_average(&self, window: i64) -> Result<TimeSeries, SeriesError> {
+        positive("window", window)?;
+        let mut result = Vec::with_capacity(self.len());
+        let mut start = 0;
+        let mut sum = 0.0;
+        for (end, &(ts, value)) in self.points.iter().enumerate() {
+            sum += value;
+            // Slide the left edge forward until it's inside the window
+            while self.points[start].0 <= ts.saturating_sub(window) {
+                sum -= self.points[start].1;
+                start += 1;
+            }
+            result.push((ts, sum / (end - start + 1) as f64));
+        }
+        Ok(TimeSeries { points: result })
+    }
+
+    fn exponential_smoothing(&self, alpha: f64) -> TimeSeries {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mut smoothed: Option<f64> = None;
+        let points = self
+            .points
+            .iter()
+            .map(|&(ts, value)| {
+                let s = match smoothed {
+                    Some(prev) => alpha * value + (1.0 - alpha) * prev,
+                    None => value, // First value seeds the average
+                };
+                smoothed = Some(s);
+                (ts, s)
+            })
+            .collect();
+        TimeSeries { points }
+    }
+
+    // Any step longer than `interval` means readings are missing
+    fn gaps(&self, interval: i64) -> Result<Vec<Gap>, SeriesError> {
+        positive("interval", interval)?;
+        Ok(self
+            .points
+            .windows(2)
+            .filter(|pair| pair[1].0.abs_diff(pair[0].0) > interval as u64)
+            .map(|pair| Gap {
+                after: pair[0].0,
+                before: pair[1].0,
+                missing: missing_between(pair[0].0, pair[1].0, interval) as usize,
+            })
+            .collect())
+    }
+
+    // Fill each gap with straight-line values on the `interval` grid. The result is
+    // built in one pass, and refused up front if it would exceed MAX_FILLED points.
+    fn interpolate(&self, interval: i64) -> Result<TimeSeries, SeriesError> {
+        positive("interval", interval)?;
+        let added: u64 = self
+            .points
+            .windows(2)
+            .map(|pair| missing_between(pair[0].0, pair[1].0, interval))
+            .fold(0, u64::saturating_add);
+        if added > MAX_FILLED as u64 {
+            return Err(SeriesError::TooManyPoints {
+                requested: added,
+                limit: MAX_FILLED,
+            });
+        }
+
+        let mut points = Vec::with_capacity(self.len() + added as usize);
+        for pair in self.points.windows(2) {
+            let ((t0, v0), (t1, v1)) = (pair[0], pair[1]);
+            points.push((t0, v0));
+            let span = t1.abs_diff(t0) as f64;
+            let mut t = t0;
+            // Every filled t is below t1, so stepping exactly `missing` times can't overflow
+            for _ in 0..missing_between(t0, t1, interval) {
+                t += interval;
+                let fraction = t.abs_diff(t0) as f64 / span;
+                points.push((t, v0 + (v1 - v0) * fraction));
+            }
+        }
+        points.extend(self.points.last().copied());
+        Ok(TimeSeries { points })
+    }
+
+    // Compare each point to the `window` points before it. Points with fewer than
+    // `window` predecessors, or a perfectly flat history, are never flagged.
+    fn anomalies(&self, window: usize, threshold: f64) -> Vec<Anomaly> {
+        let mut found = Vec::new();
+        for i in window..self.points.len() {
+            let history: Vec<f64> = self.points[i - window..i].iter().map(|&(_, v)| v).collect();
+            let mean = history.iter().sum::<f64>() / window as f64;
+            let variance = history.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / window as f64;
+            let std_dev = variance.sqrt();
+            if std_dev == 0.0 {
+                continue;
+            }
+
+            let (ts, value) = self.points[i];
+            let z_score = (value - mean) / std_dev;
+            if z_score.abs() > threshold {
+                found.push(Anomaly {
+                    timestamp: ts,
+                    value,
+                    z_score,
+                });
+            }
+        }
+        found
+    }
+}
+
+// ============================================================================
+// CSV IMPORT / EXPORT
+// ============================================================================
+
+#[derive(Debug, PartialEq)]
+enum CsvError {
+    MissingTimestampColumn,
+    WrongFieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    BadTimestamp {
+        line: usize,
+        text: String,
+    },
+    BadValue {
+        line: usize,
+        column: String,
+        text: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::MissingTimestampColumn => write!(f, "first column must be 'timestamp'"),
+            CsvError::WrongFieldCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+            CsvError::BadTimestamp { line, text } => {
+                write!(f, "line {}: bad timestamp '{}'", line, text)
+            }
+            CsvError::BadValue { line, column, text } => {
+                write!(
+                    f,
+                    "line {}: column '{}' has bad value '{}'",
+                    line, column, text
+                )
+            }
+        }
+    }
+}
+
+// One series per value column. Empty cells are missing readings, not zeros.
+fn from_csv(text: &str) -> Result<BTreeMap<String, TimeSeries>, CsvError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+    let header: Vec<&str> = match lines.next() {
+        Some((_, h)) => h.split(',').map(|c| c.trim()).collect(),
+        None => return Err(CsvError::MissingTimestampColumn),
+    };
+    if header[0] != "timestamp" {
+        return Err(CsvError::MissingTimestampColumn);
+    }
+
+    let mut columns: BTreeMap<String, TimeSeries> = header[1..]
+        .iter()
+        .map(|name| (name.to_string(), TimeSeries::new()))
+        .collect();
+
+    for (index, raw) in lines {
+        let line = index + 1;
+        let fields: Vec<&str> = raw.split(',').map(|f| f.trim()).collect();
+        if fields.len() != header.len() {
+            return Err(CsvError::WrongFieldCount {
+                line,
+                expected: header.len(),
+                found: fields.len(),
+            });
+        }
+
+        let ts = parse_timestamp(fields[0]).ok_or_else(|| CsvError::BadTimestamp {
+            line,
+            text: fields[0].to_string(),
+        })?;
+
+        for (name, text) in header[1..].iter().zip(&fields[1..]) {
+            if text.is_empty() {
+                continue;
+            }
+            let value = text.parse::<f64>().map_err(|_| CsvError::BadValue {
+                line,
+                column: name.to_string(),
+                text: text.to_string(),
+            })?;
+            columns.get_mut(*name).unwrap().insert(ts, value);
+        }
+    }
+    Ok(columns)
+}
+
+// Union of all timestamps; a series without a reading at that time gets an empty cell
+fn to_csv(columns: &BTreeMap<String, TimeSeries>) -> String {
+    let mut rows: BTreeMap<i64, Vec<Option<f64>>> = BTreeMap::new();
+    for (i, series) in columns.values().enumerate() {
+        for &(ts, value) in &series.points {
+            rows.entry(ts).or_insert_with(|| vec![None; columns.len()])[i] = Some(value);
+        }
+    }
+
+    let mut out = String::from("timestamp");
+    for name in columns.keys() {
+        out.push(',');
+        out.push_str(name);
+    }
+    out.push('\n');
+
+    for (ts, values) in rows {
+        out.push_str(&format_timestamp(ts));
+        for value in values {
+            out.push(',');
+            if let Some(v) = value {
+                out.push_str(&v.to_string()); // Shortest form that parses back exactly
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// The ranges.rs DataPoint, now with a real timestamp instead of an hour number
+#[derive(Debug)]
+struct DataPoint {
+    timestamp: i64,
+    temperature: f64,
+    humidity: f64,
+}
+
+// Same idea as ranges.rs, but with a smooth daily cycle so the days join up
+fn simulate_weather(start: i64, hours: i64) -> Vec<DataPoint> {
+    (0..hours)
+        .map(|hour| {
+            let phase = (hour % 24) as f64 / 24.0 * std::f64::consts::TAU;
+            DataPoint {
+                timestamp: start + hour * HOUR,
+                temperature: 20.0 - 6.0 * phase.cos() + (hour as f64 * 0.7).sin(),
+                humidity: 50.0 + 20.0 * phase.cos(),
+            }
+        })
+        .collect()
+}
+
+fn approx(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn main() {
+    println!("📈 === TIME SERIES FOR SENSOR DATA ===");
+
+    let start = parse_timestamp("2024-03-01T00:00:00Z").unwrap();
+    assert_eq!(format_timestamp(start), "2024-03-01T00:00:00Z");
+    assert_eq!(parse_timestamp("1709251200"), Some(start));
+    assert_eq!(parse_timestamp("2024-02-29T23:59:59Z"), Some(start - 1));
+    assert_eq!(parse_timestamp("2024-13-01T00:00:00Z"), None);
+    assert_eq!(parse_timestamp("2024-02-31T00:00:00Z"), None); // No 31st in February
+    assert_eq!(parse_timestamp("2023-02-29T00:00:00Z"), None); // 2023 is not a leap year
+    assert_eq!(parse_timestamp("1900-02-29T00:00:00Z"), None); // Centuries skip the leap day...
+    assert!(parse_timestamp("2000-02-29T00:00:00Z").is_some()); // ...unless divisible by 400
+    assert!(parse_timestamp("2024-04-30T00:00:00Z").is_some());
+    assert_eq!(parse_timestamp("2024-04-31T00:00:00Z"), None);
+
+    // ========================================================================
+    // 1. FROM DataPoint TO TIME SERIES
+    // ========================================================================
+    println!("\n🌡️  === FROM DataPoint TO TIME SERIES ===");
+
+    let weather = simulate_weather(start, 72); // Three days, hourly
+    let temperature = TimeSeries::from_points(weather.iter().map(|d| (d.timestamp, d.temperature)));
+    let humidity = TimeSeries::from_points(weather.iter().map(|d| (d.timestamp, d.humidity)));
+    println!(
+        "{} temperature readings, {} humidity readings",
+        temperature.len(),
+        humidity.len()
+    );
+
+    // step_by(6) from ranges.rs still works on the points
+    for &(ts, value) in temperature.points.iter().step_by(6).take(4) {
+        println!("  {}  {:.1}°C", format_timestamp(ts), value);
+    }
+
+    // Out-of-order inserts end up sorted; duplicates replace
+    let mut unordered = TimeSeries::new();
+    unordered.insert(300, 3.0);
+    unordered.insert(100, 1.0);
+    unordered.insert(200, 2.0);
+    unordered.insert(100, 1.5);
+    assert_eq!(unordered.points, vec![(100, 1.5), (200, 2.0), (300, 3.0)]);
+
+    // ========================================================================
+    // 2. RESAMPLING HOURLY -> DAILY
+    // ========================================================================
+    println!("\n📅 === RESAMPLING HOURLY -> DAILY ===");
+
+    let daily = temperature.resample(DAY).unwrap();
+    for b in &daily {
+        println!(
+            "  {}  n={:<2} min {:>5.1}  max {:>5.1}  mean {:>5.1}",
+            &format_timestamp(b.start)[..10],
+            b.count,
+            b.min,
+            b.max,
+            b.mean
+        );
+    }
+    assert_eq!(daily.len(), 3);
+    assert!(
+        daily
+            .iter()
+            .all(|b| b.count == 24 && b.min <= b.mean && b.mean <= b.max)
+    );
+    let expected_mean = temperature.values().take(24).sum::<f64>() / 24.0;
+    assert!(approx(daily[0].mean, expected_mean));
+
+    // ========================================================================
+    // 3. SMOOTHING
+    // ========================================================================
+    println!("\n〰️  === SMOOTHING ===");
+
+    let noisy =
+        TimeSeries::from_points((0..8).map(|i| (i * HOUR, if i % 2 == 0 { 10.0 } else { 20.0 })));
+    let moving = noisy.moving_average(2 * HOUR).unwrap();
+    let smooth = noisy.exponential_smoothing(0.3);
+    println!("  raw:        {:?}", noisy.values().collect::<Vec<_>>());
+    println!(
+        "  2h average: {:?}",
+        moving
+            .values()
+            .map(|v| (v * 10.0).round() / 10.0)
+            .collect::<Vec<_>>()
+    );
+    println!(
+        "  EWMA α=0.3: {:?}",
+        smooth
+            .values()
+            .map(|v| (v * 10.0).round() / 10.0)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        moving.values().collect::<Vec<_>>(),
+        vec![10.0, 15.0, 15.0, 15.0, 15.0, 15.0, 15.0, 15.0]
+    );
+    assert!(approx(smooth.points[1].1, 13.0)); // 0.3 * 20 + 0.7 * 10
+
+    // ========================================================================
+    // 4. GAPS AND INTERPOLATION
+    // ========================================================================
+    println!("\n🕳️  === GAPS AND INTERPOLATION ===");
+
+    // Sensor went offline between 03:00 and 07:00
+    let patchy = TimeSeries::from_points(
+        temperature
+            .points
+            .iter()
+            .take(12)
+            .copied()
+            .filter(|&(ts, _)| !(start + 4 * HOUR..start + 7 * HOUR).contains(&ts)),
+    );
+    let gaps = patchy.gaps(HOUR).unwrap();
+    for gap in &gaps {
+        println!(
+            "  Gap: {} -> {} ({} readings missing)",
+            format_timestamp(gap.after),
+            format_timestamp(gap.before),
+            gap.missing
+        );
+    }
+    assert_eq!(
+        gaps,
+        vec![Gap {
+            after: start + 3 * HOUR,
+            before: start + 7 * HOUR,
+            missing: 3
+        }]
+    );
+
+    let repaired = patchy.interpolate(HOUR).unwrap();
+    assert_eq!(repaired.len(), 12);
+    assert!(repaired.gaps(HOUR).unwrap().is_empty());
+    let (t3, v3) = repaired.points[3];
+    let (t7, v7) = repaired.points[7];
+    let (t5, v5) = repaired.points[5];
+    println!(
+        "  Interpolated 05:00 between {:.2} and {:.2}: {:.2}",
+        v3, v7, v5
+    );
+    assert!(approx(
+        v5,
+        v3 + (v7 - v3) * (t5 - t3) as f64 / (t7 - t3) as f64
+    ));
+
+    // Bad intervals are errors, not panics or endless loops
+    assert_eq!(
+        patchy.resample(0),
+        Err(SeriesError::NotPositive {
+            name: "bucket",
+            value: 0
+        })
+    );
+    assert!(patchy.moving_average(0).is_err());
+    assert!(patchy.gaps(-HOUR).is_err());
+    let error = patchy.interpolate(0).unwrap_err();
+    println!("  ❌ interpolate(0): {}", error);
+
+    // A one-second grid over the whole i64 range is refused before any work is done
+    let extremes = TimeSeries::from_points([(i64::MIN, 0.0), (i64::MAX, 1.0)]);
+    let error = extremes.interpolate(1).unwrap_err();
+    println!("  ❌ {}", error);
+    assert!(matches!(error, SeriesError::TooManyPoints { .. }));
+    let coarse = extremes.interpolate(i64::MAX / 2).unwrap();
+    assert_eq!(coarse.len(), 6); // Four steps right up to i64::MAX without overflowing
+    assert!(coarse.points.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(extremes.gaps(i64::MAX).unwrap().len(), 1);
+
+    // ========================================================================
+    // 5. ANOMALY FLAGS
+    // ========================================================================
+    println!("\n🚨 === ANOMALY FLAGS ===");
+
+    let mut spiky = temperature.clone();
+    spiky.insert(start + 30 * HOUR, 60.0); // A sensor glitch
+    let anomalies = spiky.anomalies(24, 3.0);
+    for a in &anomalies {
+        println!(
+            "  {}  {:.1}°C  z = {:.1}",
+            format_timestamp(a.timestamp),
+            a.value,
+            a.z_score
+        );
+    }
+    assert_eq!(anomalies.len(), 1);
+    assert_eq!(anomalies[0].timestamp, start + 30 * HOUR);
+    assert!(temperature.anomalies(24, 3.0).is_empty());
+
+    // Flat history can't produce a z-score, so nothing is flagged
+    let flat = TimeSeries::from_points((0..10).map(|i| (i, 5.0)));
+    assert!(flat.anomalies(3, 2.0).is_empty());
+
+    // ========================================================================
+    // 6. CSV IMPORT AND EXPORT
+    // ========================================================================
+    println!("\n💾 === CSV IMPORT AND EXPORT ===");
+
+    let csv = "timestamp,temperature,humidity
+2024-03-01T00:00:00Z,21.5,48.0
+2024-03-01T01:00:00Z,21.0,
+1709258400,20.25,51.5
+";
+    let columns = from_csv(csv).unwrap();
+    println!("  Columns: {:?}", columns.keys().collect::<Vec<_>>());
+    assert_eq!(columns["temperature"].len(), 3);
+    assert_eq!(columns["humidity"].len(), 2); // Empty cell = missing reading
+
+    let exported = to_csv(&columns);
+    print!("{}", exported);
+    assert_eq!(from_csv(&exported).unwrap(), columns); // Round trip
+
+    // Values are written in full, so long decimals survive the trip too
+    let precise: BTreeMap<String, TimeSeries> = [(
+        "temperature".to_string(),
+        TimeSeries::from_points([
+            (start, 20.125),
+            (start + HOUR, 1.0 / 3.0),
+            (start + 2 * HOUR, -0.1),
+        ]),
+    )]
+    .into_iter()
+    .collect();
+    assert_eq!(from_csv(&to_csv(&precise)).unwrap(), precise);
+
+    for bad in [
+        "time,temp\n1,2\n",
+        "timestamp,temp\n1,2,3\n",
+        "timestamp,temp\nyesterday,2\n",
+        "timestamp,temp\n1,warm\n",
+    ] {
+        println!("  ❌ {}", from_csv(bad).unwrap_err());
+    }
+    assert_eq!(
+        from_csv("timestamp,temp\n1,warm\n"),
+        Err(CsvError::BadValue {
+            line: 2,
+            column: "temp".to_string(),
+            text: "warm".to_string()
+        })
+    );
+
+    println!("\n📋 === TIME SERIES SUMMARY ===");
+    println!("✅ Points are (Unix seconds, value), kept sorted with binary search");
+    println!("✅ resample() builds daily min/max/mean from hourly data");
+    println!("✅ Moving averages use time windows, not point counts");
+    println!("✅ gaps() finds missing readings, interpolate() fills them");
+    println!("✅ anomalies() flags readings far from the trailing window");
+    println!("✅ CSV import/export round-trips, errors name the line");
+}
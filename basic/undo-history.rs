@@ -0,0 +1,773 @@
+/*
+UNDO HISTORY - Commands That Know How to Revert Themselves
+==========================================================
+
+WHAT IS THIS?
+- while-let.rs "undoes" by popping `Action { description, timestamp }`
+  records off a Vec: nothing is actually reverted, and once popped they're gone
+- The command pattern stores each edit as an object with `apply` and `revert`,
+  so undo really changes the document back - and redo can change it forward again
+- Several commands can be grouped into one transaction (one undo step), the
+  history keeps at most N steps, and it can be saved to disk and loaded again
+- A tiny text editor is the client: it only ever edits through the history
+
+REAL-WORLD ANALOGY:
+- A recipe card for every change: "add 'Hello' at position 0" comes with its
+  opposite, "remove 5 characters at position 0"
+- Undo reads the last card backwards and moves it to the redo pile;
+  making a NEW change throws the redo pile away (that future no longer exists)
+- "Rename variable" is a stapled bundle of cards: undo removes the whole bundle
+
+STACKS:
+┌────────────────────┬──────────────────────────┬──────────────────────────┐
+│ Operation          │ Undo stack               │ Redo stack               │
+├────────────────────┼──────────────────────────┼──────────────────────────┤
+│ execute(cmd)       │ push (drop oldest if     │ cleared                  │
+│                    │ deeper than the limit)   │                          │
+│ undo()             │ pop, revert in reverse   │ push                     │
+│ redo()             │ push                     │ pop, apply in order      │
+│ begin()..commit()  │ one entry for the group  │ cleared                  │
+│ rollback()         │ unchanged (group undone) │ unchanged                │
+└────────────────────┴──────────────────────────┴──────────────────────────┘
+
+KEY CONCEPTS:
+- `Command` is a trait with an associated `Target` type; `History<C>` works for any of them
+- A command that fails inside a transaction rolls back the ones before it
+- Delete remembers the text it removed - that is what makes it revertible
+- The saved file records a fingerprint of the document, so a history is never
+  replayed against a document it doesn't belong to
+*/
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// ============================================================================
+// COMMANDS AND ERRORS
+// ============================================================================
+
+trait Command {
+    type Target;
+
+    fn apply(&mut self, target: &mut Self::Target) -> Result<(), String>;
+    // Only ever called right after a successful apply (or redo) of the same command
+    fn revert(&mut self, target: &mut Self::Target);
+    fn describe(&self) -> String;
+}
+
+// One line of text per command, for saving the history
+trait Persist: Sized {
+    fn encode(&self) -> String;
+    fn decode(line: &str) -> Result<Self, String>;
+}
+
+#[derive(Debug, PartialEq)]
+enum HistoryError {
+    Rejected(String), // The command itself refused to apply
+    TransactionOpen,
+    NoTransaction,
+    Io(String),
+    Corrupt { line: usize, reason: String },
+    DocumentMismatch,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Rejected(reason) => write!(f, "command rejected: {}", reason),
+            HistoryError::TransactionOpen => write!(f, "finish the open transaction first"),
+            HistoryError::NoTransaction => write!(f, "no transaction is open"),
+            HistoryError::Io(e) => write!(f, "I/O error: {}", e),
+            HistoryError::Corrupt { line, reason } => {
+                write!(f, "history file line {}: {}", line, reason)
+            }
+            HistoryError::DocumentMismatch => {
+                write!(f, "history was saved for a different document")
+            }
+        }
+    }
+}
+
+// ============================================================================
+// HISTORY
+// ============================================================================
+
+// One undo step: a single command or a whole transaction
+struct Entry<C> {
+    label: String,
+    commands: Vec<C>,
+}
+
+struct Transaction<C> {
+    entry: Entry<C>,
+    nesting: usize, // Inner begin/commit pairs join the outermost transaction
+}
+
+struct History<C: Command> {
+    undo: VecDeque<Entry<C>>, // Oldest at the front, so trimming is cheap
+    redo: Vec<Entry<C>>,
+    depth: usize,
+    open: Option<Transaction<C>>,
+}
+
+impl<C: Command> History<C> {
+    fn new(depth: usize) -> Self {
+        History {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            depth,
+            open: None,
+        }
+    }
+
+    fn execute(&mut self, target: &mut C::Target, mut command: C) -> Result<(), HistoryError> {
+        if let Err(reason) = command.apply(target) {
+            // A failure inside a transaction takes the whole transaction down
+            if self.open.is_some() {
+                self.rollback(target)?;
+            }
+            return Err(HistoryError::Rejected(reason));
+        }
+        match &mut self.open {
+            Some(tx) => tx.entry.commands.push(command),
+            None => self.push(Entry {
+                label: command.describe(),
+                commands: vec![command],
+            }),
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, entry: Entry<C>) {
+        self.redo.clear();
+        self.undo.push_back(entry);
+        while self.undo.len() > self.depth {
+            self.undo.pop_front(); // Forget the oldest step
+        }
+    }
+
+    fn begin(&mut self, label: &str) {
+        match &mut self.open {
+            Some(tx) => tx.nesting += 1,
+            None => {
+                self.open = Some(Transaction {
+                    entry: Entry {
+                        label: label.to_string(),
+                        commands: Vec::new(),
+                    },
+                    nesting: 0,
+                })
+            }
+        }
+    }
+
+    fn commit(&mut self) -> Result<(), HistoryError> {
+        let tx = self.open.as_mut().ok_or(HistoryError::NoTransaction)?;
+        if tx.nesting > 0 {
+            tx.nesting -= 1;
+            return Ok(());
+        }
+        let entry = self.open.take().expect("checked above").entry;
+        if !entry.commands.is_empty() {
+            self.push(entry);
+        }
+        Ok(())
+    }
+
+    // Revert everything the open transaction did and forget it
+    fn rollback(&mut self, target: &mut C::Target) -> Result<(), HistoryError> {
+        let tx = self.open.take().ok_or(HistoryError::NoTransaction)?;
+        for mut command in tx.entry.commands.into_iter().rev() {
+            command.revert(target);
+        }
+        Ok(())
+    }
+
+    fn undo(&mut self, target: &mut C::Target) -> Result<Option<String>, HistoryError> {
+        if self.open.is_some() {
+            return Err(HistoryError::TransactionOpen);
+        }
+        let Some(mut entry) = self.undo.pop_back() else {
+            return Ok(None);
+        };
+        for command in entry.commands.iter_mut().rev() {
+            command.revert(target);
+        }
+        let label = entry.label.clone();
+        self.redo.push(entry);
+        Ok(Some(label))
+    }
+
+    fn redo(&mut self, target: &mut C::Target) -> Result<Option<String>, HistoryError> {
+        if self.open.is_some() {
+            return Err(HistoryError::TransactionOpen);
+        }
+        let Some(mut entry) = self.redo.pop() else {
+            return Ok(None);
+        };
+        for i in 0..entry.commands.len() {
+            if let Err(reason) = entry.commands[i].apply(target) {
+                // Leave the document and both stacks exactly as they were
+                for command in entry.commands[..i].iter_mut().rev() {
+                    command.revert(target);
+                }
+                self.redo.push(entry);
+                return Err(HistoryError::Rejected(reason));
+            }
+        }
+        let label = entry.label.clone();
+        self.undo.push_back(entry);
+        Ok(Some(label))
+    }
+
+    fn labels(&self) -> (Vec<&str>, Vec<&str>) {
+        (
+            self.undo.iter().map(|e| e.label.as_str()).collect(),
+            self.redo.iter().map(|e| e.label.as_str()).collect(),
+        )
+    }
+}
+
+// ============================================================================
+// SAVING AND LOADING
+// ============================================================================
+
+// File layout (one command per line, fields separated by tabs):
+//   undo-history v1
+//   fingerprint <hex>      depth <n>
+//   undo <count> <label>   followed by <count> command lines, oldest entry first
+//   redo <count> <label>   bottom of the redo stack first
+impl<C: Command + Persist> History<C> {
+    fn save(&self, path: &Path, fingerprint: u64) -> Result<(), HistoryError> {
+        if self.open.is_some() {
+            return Err(HistoryError::TransactionOpen);
+        }
+        let mut out = format!(
+            "undo-history v1\nfingerprint\t{:016x}\tdepth\t{}\n",
+            fingerprint, self.depth
+        );
+        let stacks = [
+            ("undo", self.undo.iter().collect::<Vec<_>>()),
+            ("redo", self.redo.iter().collect()),
+        ];
+        for (kind, entries) in stacks {
+            for entry in entries {
+                out.push_str(&format!(
+                    "{}\t{}\t{}\n",
+                    kind,
+                    entry.commands.len(),
+                    escape(&entry.label)
+                ));
+                for command in &entry.commands {
+                    out.push_str(&command.encode());
+                    out.push('\n');
+                }
+            }
+        }
+        fs::write(path, out).map_err(|e| HistoryError::Io(e.to_string()))
+    }
+
+    fn load(path: &Path, fingerprint: u64) -> Result<Self, HistoryError> {
+        let text = fs::read_to_string(path).map_err(|e| HistoryError::Io(e.to_string()))?;
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+        let corrupt = |line: usize, reason: &str| HistoryError::Corrupt {
+            line,
+            reason: reason.to_string(),
+        };
+
+        match lines.next() {
+            Some((_, "undo-history v1")) => {}
+            _ => return Err(corrupt(1, "not an undo history file")),
+        }
+        let (n, header) = lines.next().ok_or(corrupt(2, "missing header"))?;
+        let depth = match header.split('\t').collect::<Vec<_>>()[..] {
+            ["fingerprint", hex, "depth", depth] => {
+                if u64::from_str_radix(hex, 16).ok() != Some(fingerprint) {
+                    return Err(HistoryError::DocumentMismatch);
+                }
+                depth.parse().map_err(|_| corrupt(n, "bad depth"))?
+            }
+            _ => return Err(corrupt(n, "bad header")),
+        };
+
+        let mut history = History::new(depth);
+        while let Some((n, line)) = lines.next() {
+            let (kind, count, label) = match line.splitn(3, '\t').collect::<Vec<_>>()[..] {
+                [kind @ ("undo" | "redo"), count, label] => {
+                    let count: usize =
+                        count.parse().map_err(|_| corrupt(n, "bad command count"))?;
+                    (kind, count, unescape(label).map_err(|e| corrupt(n, &e))?)
+                }
+                _ => return Err(corrupt(n, "expected an undo or redo entry")),
+            };
+            let mut commands = Vec::with_capacity(count);
+            for _ in 0..count {
+                let (n, line) = lines
+                    .next()
+                    .ok_or(corrupt(n + 1, "entry is missing commands"))?;
+                commands.push(C::decode(line).map_err(|e| corrupt(n, &e))?);
+            }
+            let entry = Entry { label, commands };
+            if kind == "undo" {
+                history.undo.push_back(entry);
+            } else {
+                history.redo.push(entry);
+            }
+        }
+        Ok(history)
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            other => {
+                return Err(format!(
+                    "bad escape \\{}",
+                    other.map(String::from).unwrap_or_default()
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
+
+// FNV-1a: good enough to tell documents apart, not a security check
+fn fingerprint(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// ============================================================================
+// THE REFERENCE CLIENT: A TEXT BUFFER
+// ============================================================================
+
+// Positions are in characters, not bytes, so "é" counts as one
+fn byte_index(text: &str, at: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .nth(at)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Edit {
+    Insert {
+        at: usize,
+        text: String,
+    },
+    Delete {
+        at: usize,
+        len: usize,
+        removed: String,
+    }, // `removed` is filled in by apply
+}
+
+impl Command for Edit {
+    type Target = String;
+
+    fn apply(&mut self, doc: &mut String) -> Result<(), String> {
+        match self {
+            Edit::Insert { at, text } => {
+                let i = byte_index(doc, *at).ok_or(format!("position {} is past the end", at))?;
+                doc.insert_str(i, text);
+            }
+            Edit::Delete { at, len, removed } => {
+                let start =
+                    byte_index(doc, *at).ok_or(format!("position {} is past the end", at))?;
+                let end = at
+                    .checked_add(*len)
+                    .and_then(|end| byte_index(doc, end))
+                    .ok_or(format!("cannot delete {} characters at {}", len, at))?;
+                *removed = doc[start..end].to_string();
+                doc.replace_range(start..end, "");
+            }
+        }
+        Ok(())
+    }
+
+    fn revert(&mut self, doc: &mut String) {
+        match self {
+            Edit::Insert { at, text } => {
+                let start = byte_index(doc, *at).expect("applied before");
+                doc.replace_range(start..start + text.len(), "");
+            }
+            Edit::Delete { at, removed, .. } => {
+                let i = byte_index(doc, *at).expect("applied before");
+                doc.insert_str(i, removed);
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Edit::Insert { at, text } => format!("insert {:?} at {}", text, at),
+            Edit::Delete { at, len, .. } => format!("delete {} at {}", len, at),
+        }
+    }
+}
+
+impl Persist for Edit {
+    fn encode(&self) -> String {
+        match self {
+            Edit::Insert { at, text } => format!("insert\t{}\t{}", at, escape(text)),
+            Edit::Delete { at, len, removed } => {
+                format!("delete\t{}\t{}\t{}", at, len, escape(removed))
+            }
+        }
+    }
+
+    fn decode(line: &str) -> Result<Self, String> {
+        let number = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|_| format!("'{}' is not a position", s))
+        };
+        match line.split('\t').collect::<Vec<_>>()[..] {
+            ["insert", at, text] => Ok(Edit::Insert {
+                at: number(at)?,
+                text: unescape(text)?,
+            }),
+            ["delete", at, len, removed] => Ok(Edit::Delete {
+                at: number(at)?,
+                len: number(len)?,
+                removed: unescape(removed)?,
+            }),
+            _ => Err(format!("unknown command '{}'", line)),
+        }
+    }
+}
+
+struct Editor {
+    doc: String,
+    history: History<Edit>,
+}
+
+impl Editor {
+    fn new(depth: usize) -> Self {
+        Editor {
+            doc: String::new(),
+            history: History::new(depth),
+        }
+    }
+
+    fn insert(&mut self, at: usize, text: &str) -> Result<(), HistoryError> {
+        let edit = Edit::Insert {
+            at,
+            text: text.to_string(),
+        };
+        self.history.execute(&mut self.doc, edit)
+    }
+
+    fn delete(&mut self, at: usize, len: usize) -> Result<(), HistoryError> {
+        let edit = Edit::Delete {
+            at,
+            len,
+            removed: String::new(),
+        };
+        self.history.execute(&mut self.doc, edit)
+    }
+
+    // Two commands, one undo step
+    fn replace(&mut self, at: usize, len: usize, text: &str) -> Result<(), HistoryError> {
+        self.history
+            .begin(&format!("replace {} at {} with {:?}", len, at, text));
+        self.delete(at, len)?; // On failure the transaction is already rolled back
+        self.insert(at, text)?;
+        self.history.commit()
+    }
+
+    // Every occurrence, as one undo step
+    fn replace_all(&mut self, from: &str, to: &str) -> Result<usize, HistoryError> {
+        if from.is_empty() {
+            return Ok(0); // Would match between every pair of characters forever
+        }
+        self.history
+            .begin(&format!("replace all {:?} with {:?}", from, to));
+        let mut count = 0;
+        let mut search_from = 0;
+        while let Some(found) = self.doc[search_from..].find(from) {
+            let byte = search_from + found;
+            let at = self.doc[..byte].chars().count();
+            self.replace(at, from.chars().count(), to)?;
+            search_from = byte + to.len();
+            count += 1;
+        }
+        self.history.commit()?;
+        Ok(count)
+    }
+
+    fn undo(&mut self) -> Result<Option<String>, HistoryError> {
+        self.history.undo(&mut self.doc)
+    }
+
+    fn redo(&mut self) -> Result<Option<String>, HistoryError> {
+        self.history.redo(&mut self.doc)
+    }
+
+    fn save(&self, dir: &Path) -> Result<(), HistoryError> {
+        fs::write(dir.join("document.txt"), &self.doc)
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        self.history
+            .save(&dir.join("document.history"), fingerprint(&self.doc))
+    }
+
+    fn open(dir: &Path) -> Result<Self, HistoryError> {
+        let doc = fs::read_to_string(dir.join("document.txt"))
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        let history = History::load(&dir.join("document.history"), fingerprint(&doc))?;
+        Ok(Editor { doc, history })
+    }
+}
+
+// ============================================================================
+// RANDOM EDITS (for the property check)
+// ============================================================================
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n.max(1)
+    }
+}
+
+fn random_edit(rng: &mut XorShift, editor: &mut Editor) {
+    let len = editor.doc.chars().count() as u64;
+    let words = ["a", "bé", "cat\n", "\t", "ünï", "\\"];
+    let at = rng.below(len + 1) as usize;
+    let result = match rng.below(3) {
+        0 => editor.insert(at, words[rng.below(words.len() as u64) as usize]),
+        1 => editor.delete(at, rng.below(4) as usize),
+        _ => editor.replace(at, rng.below(3) as usize, "Z"),
+    };
+    // Deleting past the end is rejected - and must leave no trace
+    if let Err(e) = result {
+        assert!(matches!(e, HistoryError::Rejected(_)));
+    }
+}
+
+fn main() {
+    println!("↩️  === UNDO HISTORY ===");
+
+    // ========================================================================
+    // 1. THE while-let.rs ACTIONS, ACTUALLY REVERTED
+    // ========================================================================
+    println!("\n📝 === UNDO AND REDO ===");
+
+    let mut editor = Editor::new(100);
+    editor.insert(0, "fn main() {\n}\n").unwrap(); // Created file
+    editor.insert(12, "    let x = 5;\n").unwrap(); // Edited line
+    editor.insert(0, "fn helper() {}\n\n").unwrap(); // Added function
+    editor.replace_all("x", "answer").unwrap(); // Renamed a variable
+    let final_text = editor.doc.clone();
+    println!("  document:\n{}", indent(&editor.doc));
+
+    for _ in 0..2 {
+        println!("  ↩️  undo: {}", editor.undo().unwrap().unwrap());
+    }
+    assert_eq!(editor.doc, "fn main() {\n    let x = 5;\n}\n");
+    println!("  ↪️  redo: {}", editor.redo().unwrap().unwrap());
+    assert!(editor.doc.starts_with("fn helper"));
+    let (undo, redo) = editor.history.labels();
+    println!("  undo stack: {:?}", undo);
+    println!("  redo stack: {:?}", redo);
+    assert_eq!(redo.len(), 1);
+
+    editor.redo().unwrap();
+    assert_eq!(editor.doc, final_text);
+    assert_eq!(editor.redo(), Ok(None)); // Nothing left to redo
+
+    // A new edit discards the redo stack
+    editor.undo().unwrap();
+    editor.insert(0, "// v2\n").unwrap();
+    assert_eq!(editor.redo(), Ok(None));
+    println!("  a new edit after undo cleared the redo stack");
+
+    // ========================================================================
+    // 2. TRANSACTIONS
+    // ========================================================================
+    println!("\n📦 === TRANSACTIONS ===");
+
+    let mut editor = Editor::new(100);
+    editor
+        .insert(0, "let total = price * qty; print(price);")
+        .unwrap();
+    let count = editor.replace_all("price", "unit_cost").unwrap();
+    println!("  replaced {} occurrences: {}", count, editor.doc);
+    assert_eq!(editor.history.labels().0.len(), 2); // One entry for the whole rename
+    editor.undo().unwrap();
+    assert_eq!(editor.doc, "let total = price * qty; print(price);");
+    println!("  one undo reverts the whole rename");
+
+    // A failing command rolls back the rest of its transaction
+    editor.history.begin("broken macro");
+    editor.insert(0, "/* start */ ").unwrap();
+    let error = editor.delete(1_000, 1).unwrap_err();
+    println!("  ❌ {} -> transaction rolled back", error);
+    assert!(editor.delete(0, usize::MAX).is_err()); // Too long to even add up
+    assert_eq!(editor.doc, "let total = price * qty; print(price);");
+    assert!(editor.history.open.is_none());
+    assert_eq!(editor.history.commit(), Err(HistoryError::NoTransaction));
+
+    // Nested transactions join the outer one; undo waits for the outer commit
+    editor.history.begin("format");
+    editor.insert(0, "// formatted\n").unwrap();
+    editor.replace(13, 3, "const").unwrap(); // begin/commit inside: nested
+    assert_eq!(editor.undo(), Err(HistoryError::TransactionOpen));
+    editor.history.commit().unwrap();
+    assert_eq!(editor.undo().unwrap().as_deref(), Some("format"));
+    assert_eq!(editor.doc, "let total = price * qty; print(price);");
+
+    // Explicit rollback
+    editor.history.begin("experiment");
+    editor.delete(0, 4).unwrap();
+    editor.history.rollback(&mut editor.doc).unwrap();
+    assert_eq!(editor.doc, "let total = price * qty; print(price);");
+    println!("  nested transactions and explicit rollback leave the document intact");
+
+    // ========================================================================
+    // 3. HISTORY DEPTH
+    // ========================================================================
+    println!("\n📏 === DEPTH LIMIT (3) ===");
+
+    let mut editor = Editor::new(3);
+    for word in ["one ", "two ", "three ", "four ", "five "] {
+        let end = editor.doc.chars().count();
+        editor.insert(end, word).unwrap();
+    }
+    let mut undone = 0;
+    while editor.undo().unwrap().is_some() {
+        undone += 1;
+    }
+    println!(
+        "  after 5 edits, {} could be undone: {:?}",
+        undone, editor.doc
+    );
+    assert_eq!(undone, 3);
+    assert_eq!(editor.doc, "one two ");
+
+    // ========================================================================
+    // 4. SAVING AND LOADING
+    // ========================================================================
+    println!("\n💾 === PERSISTENCE ===");
+
+    let dir = std::env::temp_dir().join("rust-learning-undo-history");
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut editor = Editor::new(50);
+    editor.insert(0, "Dear Ana,\n\tThanks!\n").unwrap();
+    editor.replace_all("Ana", "Zoë").unwrap();
+    editor.insert(0, "Draft\\1\n").unwrap();
+    editor.undo().unwrap(); // Leaves one entry on the redo stack
+    editor.save(&dir).unwrap();
+    println!("  saved to {}", dir.display());
+
+    let mut reopened = Editor::open(&dir).unwrap();
+    assert_eq!(reopened.doc, editor.doc);
+    assert_eq!(reopened.history.labels(), editor.history.labels());
+    reopened.redo().unwrap();
+    assert!(reopened.doc.starts_with("Draft\\1\n"));
+    reopened.undo().unwrap();
+    reopened.undo().unwrap();
+    reopened.undo().unwrap();
+    assert_eq!(reopened.doc, "");
+    println!("  reopened: undo/redo continue where they left off");
+
+    // A lone '\r' at the end of saved text must not be mistaken for a line ending
+    let mut crlf = Editor::new(10);
+    crlf.insert(0, "abc\r\ndef").unwrap();
+    crlf.delete(0, 4).unwrap();
+    crlf.save(&dir).unwrap();
+    let mut reopened = Editor::open(&dir).unwrap();
+    reopened.undo().unwrap();
+    assert_eq!(reopened.doc, "abc\r\ndef");
+
+    // The document changed behind the history's back
+    fs::write(dir.join("document.txt"), "someone else's text").unwrap();
+    let error = Editor::open(&dir).err().unwrap();
+    println!("  ❌ {}", error);
+    assert_eq!(error, HistoryError::DocumentMismatch);
+
+    // A damaged file reports the line
+    editor.save(&dir).unwrap();
+    let history_path = dir.join("document.history");
+    let damaged = fs::read_to_string(&history_path)
+        .unwrap()
+        .replace("insert\t0", "insert\tzero");
+    fs::write(&history_path, damaged).unwrap();
+    let error = Editor::open(&dir).err().unwrap();
+    println!("  ❌ {}", error);
+    assert!(matches!(error, HistoryError::Corrupt { line: 4, .. }));
+    fs::remove_dir_all(&dir).ok();
+
+    // ========================================================================
+    // 5. RANDOMISED CHECK
+    // ========================================================================
+    println!("\n🎲 === RANDOMISED CHECK ===");
+
+    let mut rng = XorShift(0x0bad_c0de);
+    for _ in 0..200 {
+        let mut editor = Editor::new(1_000);
+        let mut versions = vec![editor.doc.clone()];
+        for _ in 0..30 {
+            let steps = editor.history.undo.len();
+            random_edit(&mut rng, &mut editor);
+            // Rejected edits add no step; accepted ones always do, even if nothing changed
+            if editor.history.undo.len() > steps {
+                versions.push(editor.doc.clone());
+            } else {
+                assert_eq!(Some(&editor.doc), versions.last());
+            }
+        }
+        // Undo walks back through every version, redo walks forward again
+        for expected in versions.iter().rev().skip(1) {
+            editor.undo().unwrap();
+            assert_eq!(&editor.doc, expected);
+        }
+        assert_eq!(editor.undo(), Ok(None));
+        for expected in versions.iter().skip(1) {
+            editor.redo().unwrap();
+            assert_eq!(&editor.doc, expected);
+        }
+    }
+    println!("  200 random sessions of 30 edits: undo and redo visit every version");
+
+    println!("\n📋 === UNDO HISTORY SUMMARY ===");
+    println!("✅ Every command knows how to apply and revert itself");
+    println!("✅ Undo moves entries to the redo stack; a new edit clears it");
+    println!("✅ Transactions make many commands one step, and roll back on failure");
+    println!("✅ The history forgets its oldest steps beyond the depth limit");
+    println!("✅ History is saved with a document fingerprint and reloaded intact");
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    | {}\n", line))
+        .collect()
+}
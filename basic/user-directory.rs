@@ -0,0 +1,409 @@
+/*
+USER DIRECTORY - Multi-Key Lookup With Uniqueness and "Did You Mean?"
+=====================================================================
+
+WHAT IS THIS?
+- map-combinator.rs `find_user` is a hard-coded match: "john" => 1, "jane" => 2
+- A real directory stores users once and finds them by any of their keys:
+  id, username, display name (case-insensitive) or email
+- Inserts enforce that no two users share a key and report WHICH key clashed;
+  lookups return Option so the map-combinator style keeps working
+
+REAL-WORLD ANALOGY:
+- Like a company phone book with several indexes at the back:
+  by employee number, by login, by name, by email - all pointing at the same entry
+- The receptionist who says "No Jnae here... did you mean Jane?" is the suggester
+
+INDEX LAYOUT:
+┌────────────────────┬───────────────────────────┬──────────────────────────────┐
+│       Index        │           Key             │           Value              │
+├────────────────────┼───────────────────────────┼──────────────────────────────┤
+│ users              │ id (u32)                  │ User (the only full copy)    │
+│ by_username        │ username, lowercased      │ id                           │
+│ by_display_name    │ display name, case-folded │ id                           │
+│ by_email           │ email, lowercased         │ id                           │
+└────────────────────┴───────────────────────────┴──────────────────────────────┘
+
+KEY CONCEPTS:
+- Secondary indexes store ids, not copies, so there is one source of truth
+- All uniqueness checks run BEFORE anything is written - a failed insert
+  leaves the directory untouched
+- Suggestions use Levenshtein edit distance (insert / delete / substitute one char)
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct User {
+    id: u32,
+    username: String,
+    display_name: String,
+    email: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum DirectoryError {
+    DuplicateId(u32),
+    DuplicateUsername(String),
+    DuplicateDisplayName(String),
+    DuplicateEmail(String),
+    InvalidUsername(String),
+    InvalidEmail(String),
+}
+
+impl fmt::Display for DirectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectoryError::DuplicateId(id) => write!(f, "id {} is already taken", id),
+            DirectoryError::DuplicateUsername(u) => write!(f, "username '{}' is already taken", u),
+            DirectoryError::DuplicateDisplayName(d) => {
+                write!(f, "display name '{}' is already taken", d)
+            }
+            DirectoryError::DuplicateEmail(e) => write!(f, "email '{}' is already registered", e),
+            DirectoryError::InvalidUsername(u) => write!(
+                f,
+                "username '{}' must be 1-32 letters, digits, '.', '_' or '-'",
+                u
+            ),
+            DirectoryError::InvalidEmail(e) => write!(f, "'{}' is not a valid email", e),
+        }
+    }
+}
+
+// "  Jane   DOE " -> "jane doe": lowercase and collapse runs of whitespace
+fn fold(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn valid_username(username: &str) -> bool {
+    (1..=32).contains(&username.chars().count())
+        && username
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+// Deliberately simple: one '@', something before it, a dot somewhere after it
+fn valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+// Classic dynamic-programming edit distance, keeping only one row in memory
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0]; // row[j - 1] from the previous iteration
+        row[0] = i + 1;
+        for (j, &cb) in b_chars.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b_chars.len()]
+}
+
+// ============================================================================
+// DIRECTORY
+// ============================================================================
+
+#[derive(Default)]
+struct UserDirectory {
+    users: BTreeMap<u32, User>,
+    by_username: HashMap<String, u32>,
+    by_display_name: HashMap<String, u32>,
+    by_email: HashMap<String, u32>,
+}
+
+impl UserDirectory {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    fn insert(&mut self, user: User) -> Result<u32, DirectoryError> {
+        // Validate and check every constraint first, then write all indexes
+        if !valid_username(&user.username) {
+            return Err(DirectoryError::InvalidUsername(user.username));
+        }
+        if !valid_email(&user.email) {
+            return Err(DirectoryError::InvalidEmail(user.email));
+        }
+
+        let username_key = user.username.to_lowercase();
+        let display_key = fold(&user.display_name);
+        let email_key = user.email.to_lowercase();
+
+        if self.users.contains_key(&user.id) {
+            return Err(DirectoryError::DuplicateId(user.id));
+        }
+        if self.by_username.contains_key(&username_key) {
+            return Err(DirectoryError::DuplicateUsername(user.username));
+        }
+        if self.by_display_name.contains_key(&display_key) {
+            return Err(DirectoryError::DuplicateDisplayName(user.display_name));
+        }
+        if self.by_email.contains_key(&email_key) {
+            return Err(DirectoryError::DuplicateEmail(user.email));
+        }
+
+        let id = user.id;
+        self.by_username.insert(username_key, id);
+        self.by_display_name.insert(display_key, id);
+        self.by_email.insert(email_key, id);
+        self.users.insert(id, user);
+        Ok(id)
+    }
+
+    fn remove(&mut self, id: u32) -> Option<User> {
+        let user = self.users.remove(&id)?;
+        self.by_username.remove(&user.username.to_lowercase());
+        self.by_display_name.remove(&fold(&user.display_name));
+        self.by_email.remove(&user.email.to_lowercase());
+        Some(user)
+    }
+
+    fn by_id(&self, id: u32) -> Option<&User> {
+        self.users.get(&id)
+    }
+
+    fn by_username(&self, username: &str) -> Option<&User> {
+        self.by_username
+            .get(&username.to_lowercase())
+            .and_then(|id| self.users.get(id))
+    }
+
+    fn by_display_name(&self, name: &str) -> Option<&User> {
+        self.by_display_name
+            .get(&fold(name))
+            .and_then(|id| self.users.get(id))
+    }
+
+    fn by_email(&self, email: &str) -> Option<&User> {
+        self.by_email
+            .get(&email.trim().to_lowercase())
+            .and_then(|id| self.users.get(id))
+    }
+
+    // Try every text key in turn - what a search box would do
+    fn lookup(&self, query: &str) -> Option<&User> {
+        self.by_username(query.trim())
+            .or_else(|| self.by_display_name(query))
+            .or_else(|| self.by_email(query))
+    }
+
+    // Usernames and display names within `max_distance` edits, closest first
+    fn suggest(&self, query: &str, max_distance: usize) -> Vec<&User> {
+        let query = fold(query);
+        let mut scored: Vec<(usize, &User)> = self
+            .users
+            .values()
+            .filter_map(|user| {
+                let distance = levenshtein(&query, &user.username.to_lowercase())
+                    .min(levenshtein(&query, &fold(&user.display_name)));
+                (distance <= max_distance).then_some((distance, user))
+            })
+            .collect();
+        scored.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.username.cmp(&b.1.username)));
+        scored.into_iter().map(|(_, user)| user).collect()
+    }
+}
+
+// ============================================================================
+// map-combinator.rs ON TOP OF THE DIRECTORY
+// ============================================================================
+
+// Same signature shape as the original: name in, Option<id> out.
+// Ids above i32::MAX can't be expressed in that shape, so they come back as None
+// rather than silently wrapping to a negative (wrong) id.
+fn find_user(directory: &UserDirectory, name: String) -> Option<i32> {
+    directory
+        .lookup(&name)
+        .and_then(|user| i32::try_from(user.id).ok())
+}
+
+// The struct map-combinator.rs builds with .map()
+#[derive(Debug)]
+struct FoundUser {
+    user_id: i32,
+    name: String,
+}
+
+fn user(id: u32, username: &str, display_name: &str, email: &str) -> User {
+    User {
+        id,
+        username: username.to_string(),
+        display_name: display_name.to_string(),
+        email: email.to_string(),
+    }
+}
+
+fn main() {
+    println!("📇 === USER DIRECTORY ===");
+
+    let mut directory = UserDirectory::new();
+    for u in [
+        user(1, "john", "John Smith", "john@example.com"),
+        user(2, "jane", "Jane Doe", "jane.doe@example.com"),
+        user(3, "jdoe", "Jonathan Doe", "jon@example.org"),
+        user(4, "amira", "Amira Haddad", "amira@example.net"),
+    ] {
+        directory.insert(u).unwrap();
+    }
+    println!("Loaded {} users", directory.len());
+
+    // ========================================================================
+    // 1. map-combinator.rs EXAMPLES, NOW BACKED BY THE DIRECTORY
+    // ========================================================================
+    println!("\n🗺️  === map-combinator.rs ON THE DIRECTORY ===");
+
+    let name1 = "John".to_owned();
+    let user1 = find_user(&directory, name1.clone()).map(|user_id| FoundUser {
+        user_id,
+        name: name1,
+    });
+    println!("find_user(\"John\") -> {:?}", user1);
+    assert_eq!(
+        user1.map(|u| (u.user_id, u.name)),
+        Some((1, "John".to_string()))
+    );
+
+    let user2 = find_user(&directory, "Unknown".to_owned()).map(|user_id| FoundUser {
+        user_id,
+        name: "Unknown".to_owned(),
+    });
+    println!("find_user(\"Unknown\") -> {:?}", user2);
+    assert!(user2.is_none());
+
+    let formatted = find_user(&directory, "Jane".to_owned())
+        .map(|user_id| user_id * 10)
+        .map(|big_id| format!("ID-{}", big_id));
+    println!("Chained map: {:?}", formatted);
+    assert_eq!(formatted, Some("ID-20".to_string()));
+
+    let mut large = UserDirectory::new();
+    large
+        .insert(user(3_000_000_000, "big", "Big Id", "big@example.com"))
+        .unwrap();
+    assert_eq!(find_user(&large, "big".to_owned()), None); // Not -1294967296
+
+    // ========================================================================
+    // 2. MULTI-KEY LOOKUP
+    // ========================================================================
+    println!("\n🔑 === MULTI-KEY LOOKUP ===");
+
+    let queries = [
+        "jane",                 // username
+        "JANE",                 // username, any case
+        "  jane   DOE ",        // display name, case and spacing folded
+        "Jane.Doe@Example.com", // email, case-insensitive
+        "nobody",
+    ];
+    for query in queries {
+        let found = directory.lookup(query).map(|u| u.id);
+        println!("  lookup({:?}) -> {:?}", query, found);
+    }
+    assert!(
+        queries[..4]
+            .iter()
+            .all(|q| directory.lookup(q).map(|u| u.id) == Some(2))
+    );
+    assert_eq!(
+        directory.by_id(3).map(|u| u.username.as_str()),
+        Some("jdoe")
+    );
+    assert_eq!(directory.by_email("JON@example.org").map(|u| u.id), Some(3));
+    assert!(directory.by_username("Jane Doe").is_none()); // Display name is not a username
+
+    // ========================================================================
+    // 3. UNIQUENESS AND VALIDATION
+    // ========================================================================
+    println!("\n🚫 === UNIQUENESS AND VALIDATION ===");
+
+    let attempts = [
+        user(1, "johnny", "Johnny B", "johnny@example.com"),
+        user(5, "JOHN", "Another John", "john2@example.com"),
+        user(5, "jd", "jane doe", "jd@example.com"),
+        user(5, "jsmith", "J Smith", "JOHN@example.com"),
+        user(5, "has space", "Spacey", "space@example.com"),
+        user(5, "noemail", "No Email", "noemail.example.com"),
+    ];
+    let before = directory.len();
+    for attempt in attempts {
+        println!("  ❌ {}", directory.insert(attempt).unwrap_err());
+    }
+    assert_eq!(directory.len(), before); // Nothing was partially written
+    assert_eq!(
+        directory.insert(user(5, "jd", "JANE   doe", "jd@example.com")),
+        Err(DirectoryError::DuplicateDisplayName(
+            "JANE   doe".to_string()
+        ))
+    );
+
+    // Removing a user frees every key they held
+    let removed = directory.remove(4).unwrap();
+    println!("  Removed {:?}", removed.display_name);
+    assert!(directory.lookup("amira@example.net").is_none());
+    assert_eq!(
+        directory.insert(user(40, "amira", "Amira Haddad", "amira@example.net")),
+        Ok(40)
+    );
+
+    // ========================================================================
+    // 4. "DID YOU MEAN ...?"
+    // ========================================================================
+    println!("\n💡 === DID YOU MEAN? ===");
+
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("jane", "jane"), 0);
+
+    for query in ["jnae", "jon", "Jane Deo", "zzz"] {
+        let suggestions: Vec<&str> = directory
+            .suggest(query, 2)
+            .iter()
+            .map(|u| u.username.as_str())
+            .collect();
+        match directory.lookup(query) {
+            Some(u) => println!("  {:?}: found {}", query, u.username),
+            None if suggestions.is_empty() => println!("  {:?}: no such user", query),
+            None => println!(
+                "  {:?}: not found - did you mean {}?",
+                query,
+                suggestions.join(" or ")
+            ),
+        }
+    }
+    assert_eq!(directory.suggest("jnae", 2)[0].username, "jane");
+    assert_eq!(directory.suggest("Jane Deo", 2)[0].id, 2);
+    assert!(directory.suggest("zzz", 2).is_empty());
+
+    println!("\n📋 === USER DIRECTORY SUMMARY ===");
+    println!("✅ One BTreeMap owns the users, HashMap indexes point at ids");
+    println!("✅ Lookups return Option, so .map() chains work as before");
+    println!("✅ Inserts validate everything first and name the clashing key");
+    println!("✅ Case folding makes 'JANE', 'jane' and '  Jane  ' the same key");
+    println!("✅ Edit distance turns a miss into a helpful suggestion");
+}